@@ -53,7 +53,7 @@ expert = MetaExpert(
     # Daily loss limit in auto-detected settlement currency (e.g., USDT for linear, BTC for inverse, auto-determined)
     size_type="risk_based",  # Position sizing: 'fixed_base', 'fixed_quote', 'percent_equity', 'risk_based'
     size_value=1.5,
-    # Size value: fixed_base (e.g., 0.01 BTC), fixed_quote (e.g., 1000 USDT), percent_equity (e.g., 0.01 = 1%), risk_based (e.g., 1.5% risk per trade)
+    # Size value: fixed_base (e.g., 0.01 BTC), fixed_quote (e.g., 1000 USDT), percent_equity (e.g., 10.0% of the equity as margin), risk_based (e.g., 1.5% risk per trade)
     max_position_size_quote=50000.0,  # Max position size in quote currency
     # --- Trade Parameters ---
     stop_loss_pct=2.0,  # Stop-Loss % from entry
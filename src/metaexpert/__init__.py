@@ -1,11 +1,27 @@
 """MetaExpert: A Python-based Expert Trading System."""
 
-from datetime import datetime
+import json
+import os
+import signal
+import threading
+from collections.abc import Callable
+from dataclasses import fields, replace
+from datetime import UTC, datetime, timedelta
+from functools import partial
 from pathlib import Path
 from types import ModuleType
-from typing import cast
+from typing import Any, cast
 
 # from metaexpert.cli.argument_parser import Namespace, parse_arguments
+from metaexpert.backtest import (
+    BacktestEngine,
+    BacktestResult,
+    FeeSchedule,
+    FillModel,
+    FixedSlippage,
+    SimulatedBroker,
+    write_report,
+)
 from metaexpert.config import (
     BACKTEST_END_DATE,
     BACKTEST_START_DATE,
@@ -14,6 +30,19 @@ from metaexpert.config import (
     DEFAULT_MARKET_TYPE,
     DEFAULT_POSITION_MODE,
     DEFAULT_TRADE_MODE,
+    DEINIT_REASON_ERROR,
+    DEINIT_REASON_FINISHED,
+    DEINIT_REASON_SIGNAL,
+    DEINIT_REASON_USER_STOP,
+    ENV_BACKTEST_END,
+    ENV_BACKTEST_START,
+    ENV_FILL_MODEL,
+    ENV_FUNDING_FILE,
+    ENV_INITIAL_CAPITAL,
+    ENV_PARAMETERS,
+    ENV_REPORT_FILE,
+    ENV_TRADE_MODE,
+    HEARTBEAT_INTERVAL,
     INITIAL_CAPITAL,
     LOG_CONSOLE_LOGGING,
     LOG_ERROR_FILE,
@@ -23,10 +52,49 @@ from metaexpert.config import (
     LOG_LEVEL_TYPE,
     LOG_STRUCTURED_LOGGING,
     LOG_TRADE_FILE,
+    MAINTENANCE_MARGIN_RATE,
+    PROCESS_STATUS_RUNNING,
+    PROCESS_STATUS_STOPPED,
+    PROCESS_STATUS_STOPPING,
+    RISK_DIRECTORY,
+)
+from metaexpert.core import (
+    Bar,
+    Broker,
+    Candle,
+    ContractType,
+    Events,
+    EventType,
+    Expert,
+    Instrument,
+    MarketType,
+    MetaExpertError,
+    Order,
+    OrderBook,
+    OrderSide,
+    PositionSide,
+    Timeframe,
+    TradeMode,
 )
-from metaexpert.core import Events, EventType, TradeMode
+from metaexpert.data import BarSource, CSVFundingSource, DataLoader, FundingSource
 from metaexpert.exchanges import MetaExchange
 from metaexpert.logger import MetaLogger, get_logger
+from metaexpert.paper import PaperBroker
+from metaexpert.process import ProcessMonitor
+from metaexpert.risk import (
+    EntryContext,
+    EntryFilter,
+    EntryFilters,
+    PositionSizer,
+    ProtectionManager,
+    RiskManager,
+    allowed_days_filter,
+    max_spread_filter,
+    min_volume_filter,
+    trade_hours_filter,
+)
+from metaexpert.stream import BookStream, MarketStream, StreamDispatcher, UserStream
+from metaexpert.utils.time import to_utc
 
 
 class MetaExpert(Events):
@@ -115,13 +183,41 @@ class MetaExpert(Events):
             position_mode=position_mode,
         )
 
+        # Venue used by the strategy: the exchange itself or a simulated broker
+        self.broker: Broker = self.client
+
+        # Historical candles: the exchange with an on-disk cache, or local files
+        self.data: BarSource = DataLoader(self.client)
+
         self.trade_mode: TradeMode | None = None
         self.backtest_start: str | datetime | None = None
         self.backtest_end: str | datetime | None = None
         self.initial_capital: float | None = None
+        self.fill_model: FillModel | str | None = None
+        self.funding_source: FundingSource | None = None
+        self._engine: BacktestEngine | None = None
+
+        # Inputs set by the optimizer, overriding `on_init` and `get_input()`
+        self.inputs: dict[str, Any] = json.loads(os.getenv(ENV_PARAMETERS) or "{}")
         self._module: ModuleType | None = None
         self._filename: str | None = None
         self._running: bool = False
+        self._stop_event: threading.Event = threading.Event()
+        self._stop_reason: str = DEINIT_REASON_USER_STOP
+        self._streams: list[MarketStream | BookStream | UserStream] = []
+        self._dispatcher: StreamDispatcher | None = None
+
+        # Pre-trade filters of the new entries, with the custom ones
+        self.entry_filters: EntryFilters = EntryFilters()
+
+        # Drawdown and daily loss limits, configured by `on_init`
+        self.risk_manager: RiskManager = RiskManager()
+
+        # Stop-loss, take-profit, trailing stop and breakeven of the positions
+        self.protections: ProtectionManager = ProtectionManager(self.client)
+
+        # State file and heartbeat, when started by `metaexpert run --detach`
+        self._monitor: ProcessMonitor = ProcessMonitor()
 
         # Log initialization
         self.logger.info("Starting expert on %s", exchange)
@@ -145,6 +241,9 @@ class MetaExpert(Events):
         backtest_start: str | datetime = BACKTEST_START_DATE,
         backtest_end: str | datetime = BACKTEST_END_DATE,
         initial_capital: float = INITIAL_CAPITAL,
+        data_source: BarSource | None = None,
+        fill_model: FillModel | str | None = None,
+        funding_source: FundingSource | str | None = None,
     ) -> None:
         """Run the expert trading system.
 
@@ -154,12 +253,37 @@ class MetaExpert(Events):
            backtest_start (str | datetime): Start date for backtesting.
            backtest_end (str | datetime): End date for backtesting.
            initial_capital (float): Initial capital for paper trading or backtesting.
+           data_source (BarSource | None): Source of historical candles (e.g. a `CSVSource`
+               for offline backtests), the exchange with an on-disk cache by default.
+           fill_model (FillModel | str | None): Execution model of backtests and paper trading, or its
+               options (see `FillModel.parse`), the exchange fees and `slippage_pct` by default.
+           funding_source (FundingSource | str | None): Funding rates of the perpetual contracts
+               in paper trading and backtests, or the path of a CSV file of them (see
+               `CSVFundingSource`), no funding by default.
         """
+        # The command line interface overrides the arguments through the environment
+        trade_mode = os.getenv(ENV_TRADE_MODE, trade_mode)
+        backtest_start = os.getenv(ENV_BACKTEST_START, backtest_start)
+        backtest_end = os.getenv(ENV_BACKTEST_END, backtest_end)
+        initial_capital = float(os.getenv(ENV_INITIAL_CAPITAL, initial_capital))
+        fill_model = os.getenv(ENV_FILL_MODEL) or fill_model
+        funding_source = os.getenv(ENV_FUNDING_FILE) or funding_source
+
         self.trade_mode = TradeMode.get_trade_mode_from(trade_mode)
+        if data_source is not None:
+            self.data = data_source
         self.backtest_start = backtest_start
         self.backtest_end = backtest_end
         self.initial_capital = initial_capital
+        self.fill_model = fill_model
+        self.funding_source = (
+            CSVFundingSource(funding_source)
+            if isinstance(funding_source, str)
+            else funding_source
+        )
         self._running = True
+        self._stop_event.clear()
+        self._stop_reason = DEINIT_REASON_USER_STOP
 
         self.logger.info(
             "Starting trading bot in %s mode",
@@ -173,20 +297,103 @@ class MetaExpert(Events):
                 self._filename = Path(self._module.__file__).stem
 
             # Initialize the expert
+            self._apply_inputs()
             EventType.ON_INIT.run()
+            self._resolve_timeframes()
+            self._install_entry_filters()
+            self._install_risk_manager()
             self.logger.info("Expert initialized successfully")
+            self._monitor.update(
+                status=PROCESS_STATUS_RUNNING,
+                trade_mode=self.trade_mode.get_name(),
+                exchange=self.client.exchange,
+                symbol=",".join(self.symbols),
+                timeframe=",".join(item.get_name() for item in self.timeframes),
+            )
+
+            if self.trade_mode is TradeMode.BACKTEST:
+                self._run_backtest()
+                self._stop_reason = DEINIT_REASON_FINISHED
+                return
+
+            if self.trade_mode is TradeMode.PAPER:
+                self.broker = PaperBroker(
+                    self.client,
+                    initial_capital,
+                    leverage=self.leverage,
+                    fill_model=self._get_fill_model(),
+                    **self._get_futures_options(),
+                )
+                self.logger.info(
+                    "Paper trading with initial capital %s", initial_capital
+                )
+            self.broker.entry_check = self._check_entry
+            self._install_protections()
 
             # Register the expert with the process
-            if self.symbol is None:
+            if not self.symbols:
                 raise ValueError("Cannot get websocket URL without a symbol.")
-            if self.timeframe is None:
+            if not self.timeframes:
                 raise ValueError("Cannot get websocket URL without a timeframe.")
 
-            ws_url = self.client.get_websocket_url(
-                self.symbol, self.timeframe.get_name()
+            # One connection per distinct URL, subscribed to all its channels
+            subscriptions: dict[str, list[dict]] = {}
+            for symbol in self.symbols:
+                for item in self.timeframes:
+                    timeframe = item.get_name()
+                    ws_url = self.client.get_websocket_url(symbol, timeframe)
+                    subscriptions.setdefault(ws_url, []).extend(
+                        self.client.get_subscribe_messages(symbol, timeframe)
+                    )
+
+            dispatcher = StreamDispatcher(
+                self.broker,
+                self.timeframe,
+                self.data,
+                on_price=self.protections.update,
             )
-            self.logger.info("Websocket URL: {ws_url}", ws_url=ws_url)
-            EventType.processing(ws_url)
+            self._dispatcher = dispatcher
+            for index, (ws_url, messages) in enumerate(subscriptions.items()):
+                self.logger.info("Websocket URL: %s", ws_url)
+                self._streams.append(
+                    MarketStream(
+                        ws_url,
+                        self.client.parse_message,
+                        dispatcher.dispatch,
+                        subscriptions=messages,
+                        name=f"market-{index}" if index else "market",
+                        ping_message=self.client.get_ping_message(),
+                        on_reconnect=partial(
+                            dispatcher.backfill, self.symbols, self.timeframes
+                        ),
+                    )
+                )
+
+            # L2 order books, when a handler or the spread filter uses them
+            uses_book = "max_spread" in self.entry_filters
+            if EventType.ON_BOOK.value["callback"] or uses_book:
+                self._streams.extend(self._create_book_streams(dispatcher))
+
+            # Order, position and balance updates of the account
+            if self.trade_mode is TradeMode.LIVE:
+                user_url = self.client.get_user_stream_url()
+                if user_url is not None:
+                    self._streams.append(
+                        UserStream(self.client, dispatcher.dispatch_user, url=user_url)
+                    )
+
+            # Candle-aligned schedulers fetching the closed bars missed by the streams
+            bars = [
+                Bar(item.get_name(), dispatcher.check_bars, (self.symbols, item))
+                for item in self.timeframes
+            ]
+            EventType.processing(*self._streams, bars=bars)
+
+            # Keep the process alive until it is stopped, beating the heart
+            self._install_signal_handlers()
+            while not self._stop_event.wait(HEARTBEAT_INTERVAL):
+                self._monitor.update()
+                self._check_risk()
 
         except KeyboardInterrupt:
             # Handle keyboard interrupt
@@ -194,16 +401,611 @@ class MetaExpert(Events):
         except (ConnectionError, TimeoutError) as e:
             # Handle network-related errors
             self.logger.error("Network error occurred: %s", e)
+            self._stop_reason = DEINIT_REASON_ERROR
         except ValueError as e:
             # Handle data validation errors
             self.logger.error("Data validation error: %s", e)
+            self._stop_reason = DEINIT_REASON_ERROR
         except RuntimeError as e:
             # Handle runtime-specific errors
             self.logger.error("Runtime error: %s", e)
+            self._stop_reason = DEINIT_REASON_ERROR
         finally:
             self._running = False
-            EventType.ON_DEINIT.run()
-            self.logger.info("Expert shutdown complete")
+            for stream in self._streams:
+                stream.stop()
+            self._streams = []
+            self._monitor.update(status=PROCESS_STATUS_STOPPING)
+            EventType.ON_DEINIT.emit(self._stop_reason)
+            self._monitor.update(
+                status=PROCESS_STATUS_STOPPED, stop_reason=self._stop_reason
+            )
+            self.logger.info("Expert shutdown complete (%s)", self._stop_reason)
+
+    def stop(self, reason: str = DEINIT_REASON_USER_STOP) -> None:
+        """Stop the running expert: `on_deinit` is called with the reason.
+
+        Args:
+            reason (str): Deinitialization reason (e.g. "user_stop", "error").
+        """
+        self._stop_reason = reason
+        self._stop_event.set()
+
+    def _install_signal_handlers(self) -> None:
+        """Stop the expert gracefully on SIGTERM (`metaexpert stop`) and SIGINT (Ctrl+C)."""
+        if threading.current_thread() is not threading.main_thread():
+            return
+
+        def handle_signal(signum: int, _frame: object) -> None:
+            if signum == signal.SIGINT:
+                reason = DEINIT_REASON_USER_STOP
+            else:
+                reason = self._monitor.get_stop_reason() or DEINIT_REASON_SIGNAL
+            self.logger.info(
+                "Received %s, stopping: %s", signal.Signals(signum).name, reason
+            )
+            self.stop(reason)
+
+        signal.signal(signal.SIGTERM, handle_signal)
+        signal.signal(signal.SIGINT, handle_signal)
+
+    def _create_book_streams(self, dispatcher: StreamDispatcher) -> list[BookStream]:
+        """Create the depth streams of the symbols, one connection per distinct URL."""
+        subscriptions: dict[str, list[dict]] = {}
+        for symbol in self.symbols:
+            book_url = self.client.get_book_websocket_url(symbol)
+            if book_url is None:
+                self.logger.warning(
+                    "Order book stream is not supported for %s", self.client.exchange
+                )
+                return []
+            subscriptions.setdefault(book_url, []).extend(
+                self.client.get_book_subscribe_messages(symbol)
+            )
+
+        streams: list[BookStream] = []
+        for index, (book_url, messages) in enumerate(subscriptions.items()):
+            self.logger.info("Order book URL: %s", book_url)
+            streams.append(
+                BookStream(
+                    book_url,
+                    self.client,
+                    dispatcher.dispatch_book,
+                    subscriptions=messages,
+                    name=f"book-{index}" if index else "book",
+                )
+            )
+        return streams
+
+    def entry_filter(self, func: EntryFilter) -> EntryFilter:
+        """Decorator registering a custom entry filter, run before every new entry.
+
+        The filter receives an `EntryContext` and returns the reason blocking
+        the entry, or None to allow it.
+
+        Args:
+            func (EntryFilter): Filter, registered under its function name.
+
+        Returns:
+            EntryFilter: The filter, unchanged.
+        """
+        return self.entry_filters.add(func)
+
+    def _apply_inputs(self) -> None:
+        """Override the `on_init` parameters with the inputs of the same name."""
+        names = {item.name for item in fields(Expert)}
+        for name, value in self.inputs.items():
+            if name not in names or not hasattr(self, name):
+                continue
+            current = getattr(self, name)
+            if not isinstance(current, bool | int | float | str):
+                raise ValueError(f"Parameter {name} cannot be set as an input")
+            value = self._convert_input(name, value, current)
+            setattr(self, name, value)
+            self.logger.info("Input %s = %s", name, value)
+
+    @staticmethod
+    def _convert_input(name: str, value: Any, default: Any) -> Any:
+        """Convert an input to the type of its default without losing information.
+
+        Booleans are only read from booleans or "true"/"false", and integers
+        from integral numbers, so that e.g. "false" or 2.5 are not taken as
+        `True` or 2.
+
+        Raises:
+            ValueError: If the value is not a valid value of that type.
+        """
+        if default is None or type(value) is type(default):
+            return value
+        if isinstance(default, bool):
+            if isinstance(value, str) and value.strip().lower() in ("true", "false"):
+                return value.strip().lower() == "true"
+        elif isinstance(default, int):
+            if isinstance(value, float) and value.is_integer():
+                return int(value)
+            if isinstance(value, str) and value.strip().lstrip("+-").isdigit():
+                return int(value)
+        elif isinstance(default, float):
+            if isinstance(value, int) and not isinstance(value, bool):
+                return float(value)
+            if isinstance(value, str):
+                try:
+                    return float(value)
+                except ValueError:
+                    pass
+        elif isinstance(default, str):
+            if isinstance(value, int | float) and not isinstance(value, bool):
+                return str(value)
+        raise ValueError(f"Invalid value of input {name}: {value!r}")
+
+    def _install_entry_filters(self) -> None:
+        """Register the built-in entry filters enabled by the expert parameters."""
+        for name in ("trade_hours", "allowed_days", "max_spread", "min_volume"):
+            self.entry_filters.remove(name)
+
+        if self.trade_hours:
+            self.entry_filters.add(trade_hours_filter(self.trade_hours), "trade_hours")
+        if self.allowed_days:
+            self.entry_filters.add(
+                allowed_days_filter(self.allowed_days), "allowed_days"
+            )
+        if self.max_spread_pct > 0:
+            self.entry_filters.add(max_spread_filter(self.max_spread_pct), "max_spread")
+        if self.min_volume > 0:
+            self.entry_filters.add(min_volume_filter(self.min_volume), "min_volume")
+
+    def _install_risk_manager(self) -> None:
+        """Create the risk manager of the limits set by `on_init`.
+
+        In live trading its state is kept across restarts, so that the daily
+        loss of the day and the peak equity are not forgotten.
+        """
+        state_file = None
+        if self.trade_mode is TradeMode.LIVE:
+            name = f"{self._filename or 'expert'}-{self.strategy_id}.json"
+            state_file = Path(RISK_DIRECTORY) / name
+        self.risk_manager = RiskManager(
+            self.max_drawdown_pct, self.daily_loss_limit, state_file=state_file
+        )
+
+    def _install_protections(self) -> None:
+        """Create the protection manager of the broker with the levels set by `on_init`."""
+        self.protections = ProtectionManager(
+            self.broker,
+            stop_loss_pct=self.stop_loss_pct,
+            take_profit_pct=self.take_profit_pct,
+            trailing_stop_pct=self.trailing_stop_pct,
+            trailing_activation_pct=self.trailing_activation_pct,
+            breakeven_pct=self.breakeven_pct,
+        )
+        self.broker.protection_update = self.protections.set_levels
+        if self.protections.is_enabled:
+            self.logger.info(
+                "Protecting the positions with %s orders",
+                "native" if self.protections.is_native else "simulated",
+            )
+
+    def _check_risk(self) -> None:
+        """Check the risk limits against the current equity of the account."""
+        try:
+            instrument = self.get_instrument()
+            equity = self._get_equity(
+                self.symbol or "",
+                instrument.settlement_asset if instrument is not None else None,
+            )
+        except (MetaExpertError, OSError, RuntimeError, NotImplementedError) as e:
+            self.logger.warning("Failed to get the equity for the risk limits: %s", e)
+            return
+        self._update_risk(self._get_time(), equity)
+
+    def _update_risk(self, time: datetime, equity: float) -> None:
+        """Record the equity in the risk manager and react to a breached limit."""
+        breach = self.risk_manager.update(equity, time)
+        if breach is None:
+            return
+
+        EventType.ON_ERROR.emit(breach)
+        if self.flatten_on_breach:
+            self._flatten()
+
+    def _flatten(self) -> None:
+        """Cancel the open orders and close all the positions of the expert."""
+        self.logger.warning("Closing all positions after a risk limit breach")
+        for symbol in self.symbols:
+            try:
+                self.broker.cancel_all_orders(symbol)
+                self.broker.close_all_positions(symbol)
+            except (MetaExpertError, OSError, RuntimeError) as e:
+                self.logger.error("Failed to close the positions of %s: %s", symbol, e)
+
+    def _get_time(self) -> datetime:
+        """Current time, the simulated one in backtests."""
+        if self.trade_mode is TradeMode.BACKTEST and isinstance(
+            self.broker, SimulatedBroker
+        ):
+            return self.broker.now
+        return datetime.now(UTC)
+
+    def _check_entry(
+        self, symbol: str, side: OrderSide, quantity: float
+    ) -> str | None:
+        """Run the risk limits and the entry filters on a new entry of the strategy."""
+        breach = self.risk_manager.breach
+        if breach is not None:
+            self.logger.warning(
+                "Entry blocked by the risk limits: %s %s %s, %s",
+                side.get_name(),
+                quantity,
+                symbol,
+                breach,
+            )
+            return breach
+        if not len(self.entry_filters):
+            return None
+
+        now = self._get_time()
+        get_book: Callable[[], OrderBook | None] | None = None
+        if self._dispatcher is not None:
+            get_book = partial(self._dispatcher.books.get, symbol)
+
+        context = EntryContext(
+            symbol,
+            side,
+            quantity,
+            now,
+            order_book=get_book,
+            volume_24h=partial(self._get_volume_24h, symbol),
+        )
+        return self.entry_filters.check(context)
+
+    def _get_volume_24h(self, symbol: str) -> float | None:
+        """Quote volume of a symbol over the last 24 hours of primary candles."""
+        count = max(1, timedelta(days=1) // self.timeframe.get_delta())
+        try:
+            candles = self.get_history(count, symbol)
+        except (MetaExpertError, OSError, RuntimeError) as e:
+            self.logger.warning("Failed to get the 24h volume of %s: %s", symbol, e)
+            return None
+        if not candles:
+            return None
+        return sum(candle.close * candle.volume for candle in candles)
+
+    def _resolve_timeframes(self) -> None:
+        """Bind the default `on_bar` handlers to the primary timeframe, stream the others."""
+        for func in EventType.ON_BAR.value["callback"]:
+            name = getattr(func, "timeframe", None)
+            if name is None:
+                func.timeframe = self.timeframe.get_name()
+                continue
+
+            timeframe = Timeframe.get_timeframe_from(name)
+            if timeframe not in self.timeframes:
+                self.timeframes.append(timeframe)
+
+    def _run_backtest(self) -> None:
+        """Replay the historical candles of the backtest period through the expert."""
+        if not self.symbols or not self.timeframes:
+            raise ValueError("Cannot run a backtest without a symbol and a timeframe.")
+        if self.backtest_start is None or self.backtest_end is None:
+            raise ValueError("Cannot run a backtest without a start and an end date.")
+
+        start = to_utc(self.backtest_start)
+        end = to_utc(self.backtest_end)
+        candles: list[Candle] = []
+        for timeframe in self.timeframes:
+            # Also fetch the lookback bars so that get_history() works from the first bar
+            history = timeframe.get_delta() * (self.lookback_bars + self.warmup_bars)
+            for symbol in self.symbols:
+                candles.extend(self.data.load(symbol, timeframe, start - history, end))
+
+        self.broker = SimulatedBroker(
+            self.initial_capital or INITIAL_CAPITAL,
+            leverage=self.leverage,
+            position_mode=self.client.position_mode,
+            margin_mode=self.client.margin_mode,
+            fill_model=self._get_fill_model(),
+            contract_type=self.client.contract_type,
+            **self._get_futures_options(),
+        )
+        self.broker.entry_check = self._check_entry
+        self._install_protections()
+        self._engine = BacktestEngine(
+            self.broker,
+            candles,
+            start=start,
+            end=end,
+            timeframe=self.timeframe,
+            on_equity=self._update_risk,
+            on_price=self.protections.update,
+        )
+        result = self._engine.run()
+        self.logger.info("Backtest result: %s", result.to_dict())
+
+        report_file = os.getenv(ENV_REPORT_FILE)
+        if report_file:
+            path = write_report(result, report_file)
+            self.logger.info("Backtest report written to %s", path)
+
+    def _get_fill_model(self) -> FillModel:
+        """Fill model of the simulated broker: the given one, or its options over the defaults.
+
+        The fees default to the flat exchange fee if one was set, else to the
+        base fee schedule of the exchange; the slippage to `slippage_pct`.
+        """
+        if isinstance(self.fill_model, FillModel):
+            return self.fill_model
+
+        if self.client.fee:
+            fees = FeeSchedule(self.client.fee, self.client.fee)
+        else:
+            fees = FeeSchedule.get_fee_schedule(
+                self.client.exchange, self.client.market_type.get_name()
+            )
+        return FillModel.parse(
+            self.fill_model, slippage=FixedSlippage(self.slippage_pct), fees=fees
+        )
+
+    def _get_futures_options(self) -> dict[str, Any]:
+        """Funding and liquidation of the simulated broker, on futures markets only."""
+        if self.client.market_type is not MarketType.FUTURES:
+            return {}
+        return {
+            "maintenance_margin_rate": MAINTENANCE_MARGIN_RATE,
+            "funding_source": self.funding_source,
+        }
+
+    def get_input(self, name: str, default: Any) -> Any:
+        """Get a custom input of the strategy, such as an indicator period.
+
+        The optimizer sets the inputs of each pass; otherwise the default
+        is returned.
+
+        Args:
+            name (str): Name of the input.
+            default (Any): Value used without override, also giving its type.
+
+        Returns:
+            Any: The input value.
+
+        Raises:
+            ValueError: If the input is not a valid value of the type of the default.
+        """
+        if name not in self.inputs:
+            return default
+        return self._convert_input(name, self.inputs[name], default)
+
+    def get_history(
+        self,
+        count: int | None = None,
+        symbol: str | None = None,
+        timeframe: str | None = None,
+    ) -> list[Candle]:
+        """Get the last closed candles, up to the current bar in backtest mode.
+
+        Args:
+            count (int | None): Number of candles, `lookback_bars` by default.
+            symbol (str | None): Trading symbol, the init symbol by default.
+            timeframe (str | None): Timeframe, the init timeframe by default.
+
+        Returns:
+            list[Candle]: Candles, oldest first.
+        """
+        symbol = symbol or self.symbol
+        if symbol is None:
+            raise ValueError("Cannot get history without a symbol.")
+
+        end = (
+            self.broker.now
+            if self.trade_mode is TradeMode.BACKTEST
+            and isinstance(self.broker, SimulatedBroker)
+            else None
+        )
+        return self.data.load_last(
+            symbol,
+            timeframe or self.timeframe,
+            count or self.lookback_bars,
+            end,
+        )
+
+    @property
+    def backtest_result(self) -> BacktestResult | None:
+        """Result of the last backtest pass, available from `on_backtest_pass`."""
+        return self._engine.result if self._engine is not None else None
+
+    @property
+    def position_sizer(self) -> PositionSizer:
+        """Position sizer configured by the sizing parameters of `on_init`."""
+        return PositionSizer(
+            size_type=self.size_type,
+            size_value=self.size_value,
+            max_position_size_quote=self.max_position_size_quote,
+            stop_loss_pct=self.stop_loss_pct,
+            # Spot positions are not leveraged
+            leverage=self.leverage
+            if self.client.market_type is MarketType.FUTURES
+            else 1,
+        )
+
+    def get_position_size(
+        self,
+        symbol: str | None = None,
+        *,
+        price: float | None = None,
+        stop_price: float | None = None,
+    ) -> float:
+        """Compute the quantity of a new entry with the sizing parameters of `on_init`.
+
+        Args:
+            symbol (str | None): Trading symbol, the init symbol by default.
+            price (float | None): Entry price, the last price by default.
+            stop_price (float | None): Stop-loss price, `stop_loss_pct` away by default.
+
+        Returns:
+            float: Quantity in base currency (contracts for futures), 0 if too small.
+        """
+        symbol = symbol or self.symbol
+        if symbol is None:
+            raise ValueError("Cannot size a position without a symbol.")
+
+        price = price or self._get_last_price(symbol)
+        if price is None:
+            raise ValueError(f"Cannot size a position without a price for {symbol}.")
+
+        sizer = self.position_sizer
+        instrument = self.get_instrument(symbol)
+        if instrument is None:
+            return sizer.get_quantity(
+                self._get_equity(symbol),
+                price,
+                stop_price=stop_price,
+                contract_type=self.client.contract_type,
+            )
+
+        if 0 < instrument.max_leverage < sizer.leverage:
+            sizer = replace(sizer, leverage=instrument.max_leverage)
+        return sizer.get_quantity(
+            self._get_equity(symbol, instrument.settlement_asset),
+            price,
+            stop_price=stop_price,
+            lot_step=instrument.lot_step,
+            min_quantity=instrument.min_quantity,
+            min_notional=instrument.min_notional,
+            contract_size=instrument.contract_size,
+            contract_type=(
+                ContractType.INVERSE if instrument.is_inverse else ContractType.LINEAR
+            ),
+        )
+
+    def get_instrument(self, symbol: str | None = None) -> Instrument | None:
+        """Get the trading rules of a symbol: tick size, lot step, minimums, leverage.
+
+        Args:
+            symbol (str | None): Trading symbol, the init symbol by default.
+
+        Returns:
+            Instrument | None: The instrument, None if unknown (backtests, exchanges not publishing them).
+
+        Raises:
+            UnsupportedPairError: If the exchange does not list the symbol.
+        """
+        symbol = symbol or self.symbol
+        if symbol is None:
+            raise ValueError("Cannot get an instrument without a symbol.")
+
+        instruments = (
+            self.broker.instruments
+            if isinstance(self.broker, SimulatedBroker)
+            else self.client.instruments
+        )
+        return instruments.get(symbol) if instruments is not None else None
+
+    def buy(
+        self,
+        symbol: str | None = None,
+        *,
+        price: float | None = None,
+        stop_price: float | None = None,
+    ) -> Order | None:
+        """Open or increase a long position sized by `get_position_size`.
+
+        Args:
+            symbol (str | None): Trading symbol, the init symbol by default.
+            price (float | None): Limit price, a market order by default.
+            stop_price (float | None): Stop-loss price used for risk-based sizing.
+
+        Returns:
+            Order | None: The entry order, None if the size is too small or a filter blocked it.
+        """
+        return self._open(PositionSide.LONG, symbol, price, stop_price)
+
+    def sell(
+        self,
+        symbol: str | None = None,
+        *,
+        price: float | None = None,
+        stop_price: float | None = None,
+    ) -> Order | None:
+        """Open or increase a short position sized by `get_position_size`.
+
+        Args:
+            symbol (str | None): Trading symbol, the init symbol by default.
+            price (float | None): Limit price, a market order by default.
+            stop_price (float | None): Stop-loss price used for risk-based sizing.
+
+        Returns:
+            Order | None: The entry order, None if the size is too small or a filter blocked it.
+        """
+        return self._open(PositionSide.SHORT, symbol, price, stop_price)
+
+    def _open(
+        self,
+        side: PositionSide,
+        symbol: str | None,
+        price: float | None,
+        stop_price: float | None,
+    ) -> Order | None:
+        """Open a position sized by the sizing parameters."""
+        symbol = symbol or self.symbol
+        if symbol is None:
+            raise ValueError("Cannot open a position without a symbol.")
+
+        quantity = self.get_position_size(symbol, price=price, stop_price=stop_price)
+        if quantity <= 0:
+            self.logger.warning(
+                "Position size of %s is below the minimum, no %s entry",
+                symbol,
+                side.get_name(),
+            )
+            return None
+        return self.broker.open_position(symbol, side, quantity, price=price)
+
+    def _get_equity(self, symbol: str, asset: str | None = None) -> float:
+        """Equity of the account in a settlement asset, the quote currency of a symbol by default.
+
+        In live trading it is the balance plus the unrealized PnL of the
+        positions known to the position book.
+        """
+        if isinstance(self.broker, SimulatedBroker):
+            return self.broker.equity
+
+        unrealized_pnl = 0.0
+        if isinstance(self.broker, MetaExchange):
+            unrealized_pnl = sum(
+                position.unrealized_pnl
+                for position in self.broker.position_book.get_positions()
+            )
+
+        balance = self.broker.get_balance()
+        if not isinstance(balance, dict):
+            return float(balance) + unrealized_pnl
+        if asset is None:
+            # The longest asset suffix of the symbol is its quote currency
+            assets = sorted(
+                (item for item in balance if symbol.upper().endswith(item.upper())),
+                key=len,
+            )
+            asset = assets[-1] if assets else None
+        return float(balance.get(asset, 0.0)) + unrealized_pnl
+
+    def _get_last_price(self, symbol: str) -> float | None:
+        """Last known price of a symbol: traded, mid of the book or last close."""
+        if isinstance(self.broker, SimulatedBroker):
+            price = self.broker.get_last_price(symbol)
+            if price is not None:
+                return price
+        if self._dispatcher is not None and symbol in self._dispatcher.books:
+            mid_price = self._dispatcher.books[symbol].mid_price
+            if mid_price is not None:
+                return mid_price
+        candles = self.get_history(1, symbol)
+        return candles[-1].close if candles else None
 
     # from metaexpert.exchanges.binance import balance
     # balance = import_module("metaexpert.exchanges.binance").get_balance
+
+
+def main() -> None:
+    """Entry point of the `metaexpert` command line interface."""
+    from metaexpert.cli import main as cli_main
+
+    cli_main()
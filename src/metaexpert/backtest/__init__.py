@@ -0,0 +1,57 @@
+"""Backtesting components of the MetaExpert library."""
+
+from .broker import SimulatedBroker
+from .engine import BacktestEngine
+from .fill_model import (
+    FeeSchedule,
+    FillModel,
+    FixedSlippage,
+    SlippageModel,
+    SpreadSlippage,
+    VolatilitySlippage,
+)
+from .metrics import Metrics, RoundTrip, get_metrics, get_round_trips
+from .optimizer import OptimizationPass, Optimizer, Parameter, run_backtest
+from .report import (
+    write_optimization_report,
+    write_report,
+    write_walk_forward_report,
+)
+from .result import BacktestResult
+from .walk_forward import (
+    ParameterStability,
+    WalkForward,
+    WalkForwardResult,
+    WalkForwardStep,
+    WalkForwardWindow,
+    get_windows,
+)
+
+__all__ = [
+    "BacktestEngine",
+    "BacktestResult",
+    "FeeSchedule",
+    "FillModel",
+    "FixedSlippage",
+    "Metrics",
+    "OptimizationPass",
+    "Optimizer",
+    "Parameter",
+    "ParameterStability",
+    "RoundTrip",
+    "SimulatedBroker",
+    "SlippageModel",
+    "SpreadSlippage",
+    "VolatilitySlippage",
+    "WalkForward",
+    "WalkForwardResult",
+    "WalkForwardStep",
+    "WalkForwardWindow",
+    "get_metrics",
+    "get_round_trips",
+    "get_windows",
+    "run_backtest",
+    "write_optimization_report",
+    "write_report",
+    "write_walk_forward_report",
+]
@@ -0,0 +1,543 @@
+"""Simulated broker."""
+
+from datetime import UTC, datetime
+from itertools import count
+from typing import Any
+
+from metaexpert.config import DEFAULT_TIME_IN_FORCE
+from metaexpert.core import (
+    Broker,
+    Candle,
+    ContractType,
+    EventType,
+    InstrumentRegistry,
+    InsufficientFundsError,
+    InvalidOrderError,
+    LiquidationError,
+    MarginMode,
+    Order,
+    OrderNotFoundError,
+    OrderSide,
+    OrderStatus,
+    OrderType,
+    Position,
+    PositionBook,
+    PositionMode,
+    PositionSide,
+    TimeInForce,
+)
+from metaexpert.data import FundingSource
+from metaexpert.logger import MetaLogger as Logger, get_logger
+
+from .fill_model import FeeSchedule, FillModel, FixedSlippage
+
+
+class SimulatedBroker(Broker):
+    """In-memory broker that executes orders against candles.
+
+    Orders are matched when the next candle is processed, so a strategy that
+    reacts to a closed bar is filled within the following bar and never sees
+    its own fill price in advance. Fills update an in-memory account seeded
+    with the initial capital and emit the same `on_order`, `on_transaction`
+    and `on_position` events as live trading.
+
+    The fill model decides the fill prices, slippage, fees and quantities;
+    without one, every fill pays the flat `fee` and market fills the fixed
+    `slippage_pct`.
+
+    Futures accounts pay or receive the funding of their positions at the
+    timestamps of the funding source, at the rate and mark price it gives
+    (the price of the market otherwise). With a maintenance margin rate,
+    the liquidation price of every position is kept up to date from its
+    own margin (isolated) or the free balance of the account (cross); a
+    position reaching it is closed at that price, in the order the fill
+    model reaches it among the other orders of the candle, and loses its
+    remaining maintenance margin on top of the taker fee. Inverse accounts
+    settle in the base coin, their positions being sized in the quote
+    currency.
+    """
+
+    supports_protective_orders = True
+
+    def __init__(
+        self,
+        initial_capital: float,
+        *,
+        fee: float = 0.0,
+        slippage_pct: float = 0.0,
+        leverage: int = 1,
+        position_mode: PositionMode = PositionMode.ONEWAY,
+        margin_mode: MarginMode = MarginMode.ISOLATED,
+        currency: str = "USDT",
+        instruments: InstrumentRegistry | None = None,
+        fill_model: FillModel | None = None,
+        contract_type: ContractType = ContractType.LINEAR,
+        maintenance_margin_rate: float | None = None,
+        funding_source: FundingSource | None = None,
+    ) -> None:
+        """Initialize the simulated broker.
+
+        Args:
+            initial_capital (float): Starting balance in the settlement currency.
+            fee (float): Fee rate charged on the traded notional (0.001 = 0.1%).
+            slippage_pct (float): Adverse slippage applied to market and stop fills, in percent.
+            leverage (int): Default leverage for new positions.
+            position_mode (PositionMode): Hedge or one-way position mode.
+            margin_mode (MarginMode): Default margin mode for new positions.
+            currency (str): Settlement currency of the account.
+            instruments (InstrumentRegistry | None): Trading rules the orders are rounded to, if known.
+            fill_model (FillModel | None): Execution model, replacing `fee` and `slippage_pct`.
+            contract_type (ContractType): Linear (quote-margined) or inverse (coin-margined) contracts.
+            maintenance_margin_rate (float | None): Maintenance margin as a share of the
+                position value (0.005 = 0.5%), None to never liquidate.
+            funding_source (FundingSource | None): Funding rates of the perpetual contracts, if any.
+        """
+        self.logger: Logger = get_logger("SimulatedBroker")
+        self.initial_capital: float = initial_capital
+        self.balance: float = initial_capital
+        self.fill_model: FillModel = fill_model or FillModel(
+            slippage=FixedSlippage(slippage_pct), fees=FeeSchedule(fee, fee)
+        )
+        self._fee: float = self.fill_model.fees.taker
+        self.leverage: int = leverage
+        self.margin_mode: MarginMode = margin_mode
+        self.currency: str = currency
+        self.instruments: InstrumentRegistry | None = instruments
+        self.contract_type: ContractType = contract_type
+        self.maintenance_margin_rate: float | None = maintenance_margin_rate
+        self.funding_source: FundingSource | None = funding_source
+        self.position_book: PositionBook = PositionBook(position_mode, contract_type)
+        self.trades: list[dict[str, Any]] = []
+        self.funding_payments: list[dict[str, Any]] = []
+        self.now: datetime = datetime.now(UTC)
+        self._orders: dict[str, Order] = {}
+        self._order_ids = count(1)
+        self._last_prices: dict[str, float] = {}
+        self._leverages: dict[str, int] = {}
+        self._margin_modes: dict[str, MarginMode] = {}
+        self._funding_times: dict[str, datetime] = {}
+
+    @property
+    def equity(self) -> float:
+        """Balance plus the unrealized PnL of the open positions."""
+        return self.balance + sum(
+            position.unrealized_pnl for position in self.position_book.get_positions()
+        )
+
+    @property
+    def used_margin(self) -> float:
+        """Margin locked by the open positions."""
+        return sum(position.margin for position in self.position_book.get_positions())
+
+    @property
+    def funding(self) -> float:
+        """Net funding received by the positions, negative if paid."""
+        return sum(payment["payment"] for payment in self.funding_payments)
+
+    def get_last_price(self, symbol: str) -> float | None:
+        """Last known price of a symbol."""
+        return self._last_prices.get(symbol)
+
+    # MARKET DATA
+    def process_candle(self, candle: Candle) -> None:
+        """Match the open orders of the candle symbol, then mark positions to its close.
+
+        The funding due since the previous candle is settled first. The
+        orders fill in the order the fill model reaches them within the
+        candle, up to the volume it allows; immediate-or-cancel and
+        fill-or-kill orders do not rest beyond their first candle. The
+        positions reaching their liquidation price are liquidated along.
+        """
+        self.now = candle.close_time
+        self._apply_funding(candle)
+        executions = []
+        for order in self.get_open_orders(candle.symbol):
+            execution = self.fill_model.match(order, candle)
+            if execution is not None:
+                executions.append((execution, order, False))
+            elif order.time_in_force is not TimeInForce.GTC:
+                self._close_order(order, OrderStatus.CANCELED)
+        for order in self._get_liquidation_orders(candle.symbol):
+            execution = self.fill_model.match(order, candle)
+            if execution is not None:
+                executions.append((execution, order, True))
+
+        volume = self.fill_model.get_volume(candle)
+        executions.sort(key=lambda item: item[0].time)
+        for execution, order, is_liquidation in executions:
+            if is_liquidation:
+                self._liquidate(order, execution.price)
+                continue
+            if not order.is_open:
+                continue
+            quantity = order.remaining_quantity
+            if volume is not None:
+                quantity = min(quantity, volume)
+            if order.time_in_force is TimeInForce.FOK and (
+                quantity < order.remaining_quantity - 1e-12
+            ):
+                self._close_order(order, OrderStatus.CANCELED)
+                continue
+            if quantity > 0:
+                filled = order.filled_quantity
+                price = self.fill_model.get_price(order, execution.price, candle)
+                self._fill(order, price, quantity, is_maker=execution.is_maker)
+                if volume is not None:
+                    volume -= order.filled_quantity - filled
+            if order.is_open and order.time_in_force is not TimeInForce.GTC:
+                self._close_order(order, OrderStatus.CANCELED)
+
+        self._last_prices[candle.symbol] = candle.close
+        self.position_book.update_mark_price(candle.symbol, candle.close)
+        self._update_liquidation_prices()
+
+    def _apply_funding(self, candle: Candle) -> None:
+        """Settle the funding of the positions of the candle symbol due before its close."""
+        if self.funding_source is None:
+            return
+
+        symbol = candle.symbol
+        start = self._funding_times.get(symbol, candle.open_time)
+        self._funding_times[symbol] = candle.close_time
+        for funding_rate in self.funding_source.load(symbol, start, candle.close_time):
+            price = funding_rate.mark_price or candle.open
+            for position in self.position_book.get_positions(symbol):
+                payment = (
+                    -position.side.get_sign()
+                    * position.get_value(price)
+                    * funding_rate.rate
+                )
+                self.balance += payment
+                self.funding_payments.append(
+                    {
+                        "symbol": symbol,
+                        "side": position.side.get_name(),
+                        "size": position.size,
+                        "rate": funding_rate.rate,
+                        "price": price,
+                        "payment": payment,
+                        "time": funding_rate.time,
+                    }
+                )
+                self.logger.debug(
+                    "Funding of %s %s at rate %s: %s",
+                    position.side.get_name(),
+                    symbol,
+                    funding_rate.rate,
+                    payment,
+                )
+
+    def _get_liquidation_orders(self, symbol: str) -> list[Order]:
+        """Stop orders closing the positions of a symbol at their liquidation price."""
+        return [
+            self.create_order(
+                symbol,
+                position.side.get_open_side().opposite(),
+                OrderType.STOP,
+                position.size,
+                stop_price=position.liquidation_price,
+                reduce_only=True,
+            )
+            for position in self.position_book.get_positions(symbol)
+            if position.liquidation_price is not None
+        ]
+
+    def _liquidate(self, order: Order, price: float) -> None:
+        """Force the close of a position reaching its liquidation price."""
+        position = self._get_reduced_position(order)
+        if position is None:
+            return
+
+        order.quantity = position.size
+        order.id = str(next(self._order_ids))
+        order.client_order_id = f"liquidation-{order.id}"
+        order.created_at = self.now
+        self._orders[order.id] = order
+        side = position.side.get_name()
+        self._fill(order, price, is_liquidation=True)
+        self.logger.warning(
+            "Liquidated %s %s %s @ %s", side, order.quantity, order.symbol, price
+        )
+        EventType.ON_ERROR.emit(LiquidationError(order.symbol, side, price))
+
+    def _update_liquidation_prices(self) -> None:
+        """Recompute the liquidation price of every open position."""
+        if self.maintenance_margin_rate is None:
+            return
+        for position in self.position_book.get_positions():
+            position.liquidation_price = self._get_liquidation_price(position)
+
+    def _get_liquidation_price(self, position: Position) -> float | None:
+        """Liquidation price of a position from the margin backing it."""
+        rate = self.maintenance_margin_rate or 0.0
+        if position.margin_mode is not MarginMode.CROSS:
+            return position.get_liquidation_price(position.margin, rate)
+
+        # Cross: the balance, less the isolated margins and the maintenance
+        # margins of the other cross positions, with their unrealized PnL.
+        margin = self.balance
+        for other in self.position_book.get_positions():
+            if other is position:
+                continue
+            if other.margin_mode is MarginMode.CROSS:
+                value = other.get_value(other.mark_price or other.entry_price)
+                margin += other.unrealized_pnl - value * rate
+            else:
+                margin -= other.margin
+        return position.get_liquidation_price(margin, rate)
+
+    def _get_value(self, quantity: float, price: float) -> float:
+        """Value of a quantity at a price, in the settlement currency."""
+        if self.contract_type is ContractType.INVERSE:
+            return quantity / price if price > 0 else 0.0
+        return quantity * price
+
+    def _fill(
+        self,
+        order: Order,
+        price: float,
+        quantity: float | None = None,
+        *,
+        is_maker: bool = False,
+        is_liquidation: bool = False,
+    ) -> None:
+        """Execute (part of) an order at its final price and update the account.
+
+        A liquidation also charges the remaining maintenance margin of the
+        position on top of the fee.
+        """
+        quantity = order.remaining_quantity if quantity is None else quantity
+        if order.reduce_only:
+            position = self._get_reduced_position(order)
+            if position is None:
+                self._close_order(order, OrderStatus.CANCELED)
+                return
+            quantity = min(quantity, position.size)
+
+        symbol = order.symbol
+        value = self._get_value(quantity, price)
+        fee = self.fill_model.get_fee(value, is_maker=is_maker)
+        if is_liquidation:
+            fee += value * (self.maintenance_margin_rate or 0.0)
+        position, realized = self.position_book.apply_fill(
+            symbol,
+            order.side,
+            quantity,
+            price,
+            reduce_only=order.reduce_only,
+            leverage=self._leverages.get(symbol, self.leverage),
+            margin_mode=self._margin_modes.get(symbol, self.margin_mode),
+        )
+        self.balance += realized - fee
+
+        filled = order.filled_quantity + quantity
+        order.average_price = (
+            (order.average_price or 0.0) * order.filled_quantity + price * quantity
+        ) / filled
+        order.filled_quantity = filled
+        order.status = (
+            OrderStatus.FILLED
+            if order.remaining_quantity <= 1e-12
+            else OrderStatus.PARTIALLY_FILLED
+        )
+        order.updated_at = self.now
+
+        trade = {
+            "order_id": order.id,
+            "symbol": symbol,
+            "side": order.side.get_name(),
+            "quantity": quantity,
+            "price": price,
+            "fee": fee,
+            "realized_pnl": realized,
+            "liquidation": is_liquidation,
+            # Only hedged positions need it, a one-way one follows the fill side
+            "position_side": (
+                position.side.get_name()
+                if self.position_book.position_mode is PositionMode.HEDGE
+                else None
+            ),
+            "time": self.now,
+        }
+        self.trades.append(trade)
+        self.logger.debug(
+            "Filled order %s: %s %s %s @ %s",
+            order.id,
+            order.side.get_name(),
+            quantity,
+            symbol,
+            price,
+        )
+
+        self._update_liquidation_prices()
+        EventType.ON_ORDER.emit(order.to_dict())
+        EventType.ON_TRANSACTION.emit(order.to_dict(), trade)
+        EventType.ON_POSITION.emit(position.to_dict())
+
+    def _get_reduced_position(self, order: Order) -> Position | None:
+        """Position that a reduce-only order would reduce, if any."""
+        side = PositionSide.get_position_side_from(order.side.opposite().get_name())
+        position = self.position_book.get_position(order.symbol, side)
+        return position if position is not None and position.is_open else None
+
+    def _close_order(self, order: Order, status: OrderStatus) -> Order:
+        """Move an open order to a final status and notify the handlers."""
+        order.status = status
+        order.updated_at = self.now
+        EventType.ON_ORDER.emit(order.to_dict())
+        return order
+
+    def _check_margin(self, order: Order) -> None:
+        """Reject an opening order that the free margin cannot cover."""
+        if order.reduce_only:
+            return
+
+        price = order.price or order.stop_price or self._last_prices.get(order.symbol)
+        if price is None:
+            return
+
+        leverage = max(self._leverages.get(order.symbol, self.leverage), 1)
+        value = self._get_value(order.quantity, price)
+        required = value / leverage + value * self.fee
+        available = self.equity - self.used_margin
+        if required > available:
+            raise InsufficientFundsError(available, required, self.currency)
+
+    # ACCOUNT
+    def get_balance(self) -> dict | float:
+        """Get the account balance in the settlement currency."""
+        return self.balance
+
+    def get_account(self) -> dict:
+        """Get the simulated account state."""
+        return {
+            "currency": self.currency,
+            "balance": self.balance,
+            "equity": self.equity,
+            "used_margin": self.used_margin,
+            "free_margin": self.equity - self.used_margin,
+            "funding": self.funding,
+            "timestamp": self.now,
+        }
+
+    # POSITION
+    def get_positions(self, symbol: str | None = None) -> list[Position]:
+        """Get the open simulated positions."""
+        return self.position_book.get_positions(symbol)
+
+    def modify_position(
+        self,
+        symbol: str,
+        *,
+        leverage: int | None = None,
+        margin_mode: str | MarginMode | None = None,
+    ) -> None:
+        """Change the leverage and/or margin mode used for new positions of a symbol."""
+        if leverage is not None:
+            self._leverages[symbol] = leverage
+        if margin_mode is not None:
+            self._margin_modes[symbol] = (
+                margin_mode
+                if isinstance(margin_mode, MarginMode)
+                else MarginMode.get_margin_mode_from(margin_mode)
+            )
+
+    # ORDER
+    def place_order(
+        self,
+        symbol: str,
+        side: str | OrderSide,
+        order_type: str | OrderType,
+        quantity: float,
+        *,
+        price: float | None = None,
+        stop_price: float | None = None,
+        time_in_force: str | TimeInForce = DEFAULT_TIME_IN_FORCE,
+        reduce_only: bool = False,
+        post_only: bool = False,
+        client_order_id: str | None = None,
+    ) -> Order:
+        """Accept an order; it is matched when the next candle is processed."""
+        order = self.create_order(
+            symbol,
+            side,
+            order_type,
+            quantity,
+            price=price,
+            stop_price=stop_price,
+            time_in_force=time_in_force,
+            reduce_only=reduce_only,
+            post_only=post_only,
+            client_order_id=client_order_id,
+        )
+        if self.instruments is not None:
+            order = self.instruments.prepare_order(order)
+        self._check_margin(order)
+
+        order.id = str(next(self._order_ids))
+        order.created_at = self.now
+        self._orders[order.id] = order
+        EventType.ON_ORDER.emit(order.to_dict())
+        return order
+
+    def cancel_order(self, symbol: str, order_id: str) -> Order:
+        """Cancel an open simulated order."""
+        order = self.get_order(symbol, order_id)
+        if not order.is_open:
+            raise InvalidOrderError(
+                order.to_dict(),
+                f"Cannot cancel order in status '{order.status.get_name()}'",
+            )
+        return self._close_order(order, OrderStatus.CANCELED)
+
+    def cancel_all_orders(self, symbol: str) -> list[Order]:
+        """Cancel all open simulated orders of a symbol."""
+        return [
+            self._close_order(order, OrderStatus.CANCELED)
+            for order in self.get_open_orders(symbol)
+        ]
+
+    def amend_order(
+        self,
+        symbol: str,
+        order_id: str,
+        *,
+        quantity: float | None = None,
+        price: float | None = None,
+        stop_price: float | None = None,
+    ) -> Order:
+        """Amend an open simulated order in place."""
+        order = self.get_order(symbol, order_id)
+        if not order.is_open:
+            raise InvalidOrderError(
+                order.to_dict(),
+                f"Cannot amend order in status '{order.status.get_name()}'",
+            )
+
+        if quantity is not None:
+            order.quantity = quantity
+        if price is not None:
+            order.price = price
+        if stop_price is not None:
+            order.stop_price = stop_price
+        if self.instruments is not None:
+            self.instruments.prepare_order(order)
+        order.validate()
+        order.updated_at = self.now
+        EventType.ON_ORDER.emit(order.to_dict())
+        return order
+
+    def get_order(self, symbol: str, order_id: str) -> Order:
+        """Get a simulated order by ID."""
+        order = self._orders.get(order_id)
+        if order is None or order.symbol != symbol:
+            raise OrderNotFoundError(order_id)
+        return order
+
+    def get_open_orders(self, symbol: str | None = None) -> list[Order]:
+        """Get the open simulated orders, oldest first."""
+        return [
+            order
+            for order in self._orders.values()
+            if order.is_open and (symbol is None or order.symbol == symbol)
+        ]
@@ -0,0 +1,138 @@
+"""Backtesting engine."""
+
+from collections.abc import Callable, Iterable
+from datetime import datetime
+from typing import Any
+
+from metaexpert.config import DEFAULT_FITNESS_METRIC
+from metaexpert.core import Candle, EventType, Timeframe
+from metaexpert.logger import MetaLogger as Logger, get_logger
+
+from .broker import SimulatedBroker
+from .result import BacktestResult
+
+
+class BacktestEngine:
+    """Event-driven backtesting engine.
+
+    Replays historical candles in chronological order through the same
+    `on_tick` and `on_bar` handlers used for live trading. Every candle is
+    first offered to the simulated broker to fill the orders placed on the
+    previous bars, then dispatched to the handlers as a closed bar.
+
+    Candles of several symbols and timeframes may be mixed: they are replayed
+    in the order they close. Only the candles of the execution timeframe are
+    matched against the orders, call `on_tick` and sample the equity curve;
+    the candles of the other timeframes only call their `on_bar` handlers.
+
+    The result of the pass is set in `result` before `on_backtest_pass`, so
+    that the handlers can read its metrics. `on_backtest` returns the fitness
+    of the pass, either a number or the name of a metric such as "sharpe";
+    the net profit is used when it is not defined.
+    """
+
+    def __init__(
+        self,
+        broker: SimulatedBroker,
+        candles: Iterable[Candle],
+        *,
+        start: datetime | None = None,
+        end: datetime | None = None,
+        timeframe: Timeframe | None = None,
+        on_equity: Callable[[datetime, float], None] | None = None,
+        on_price: Callable[[str, float], None] | None = None,
+    ) -> None:
+        """Initialize the backtesting engine.
+
+        Args:
+            broker (SimulatedBroker): Broker that executes the strategy orders.
+            candles (Iterable[Candle]): Historical candles, of one or more symbols.
+            start (datetime | None): Skip candles opened before this time.
+            end (datetime | None): Skip candles opened at or after this time.
+            timeframe (Timeframe | None): Execution timeframe, the shortest one by default.
+            on_equity (Callable | None): Called with the time and the equity after every execution bar.
+            on_price (Callable | None): Called with the symbol and the close of every execution bar, before the handlers.
+        """
+        self.broker: SimulatedBroker = broker
+        self.candles: list[Candle] = list(candles)
+        self.start: datetime | None = start
+        self.end: datetime | None = end
+        self.timeframe: Timeframe | None = timeframe or min(
+            (candle.timeframe for candle in self.candles),
+            key=lambda item: item.get_seconds(),
+            default=None,
+        )
+        self.on_equity: Callable[[datetime, float], None] | None = on_equity
+        self.on_price: Callable[[str, float], None] | None = on_price
+        self.result: BacktestResult | None = None  # Set before `on_backtest_pass`
+        self.logger: Logger = get_logger("BacktestEngine")
+
+    def run(self) -> BacktestResult:
+        """Run the backtest and return its result."""
+        # At the same close time, the execution candles come first so that
+        # the orders are filled before the bars of the longer timeframes
+        replay = sorted(
+            (
+                candle
+                for candle in self.candles
+                if candle.is_closed
+                and (self.start is None or candle.open_time >= self.start)
+                and (self.end is None or candle.open_time < self.end)
+            ),
+            key=lambda c: (c.close_time, c.timeframe is not self.timeframe),
+        )
+        candles = [candle for candle in replay if candle.timeframe is self.timeframe]
+        self.logger.info("Backtesting %d bars", len(candles))
+
+        EventType.ON_BACKTEST_INIT.emit()
+
+        equity_curve: list[tuple[datetime, float]] = []
+        for candle in replay:
+            rates = candle.to_dict()
+            if candle.timeframe is self.timeframe:
+                self.broker.process_candle(candle)
+                if self.on_price is not None:
+                    self.on_price(candle.symbol, candle.close)
+                EventType.ON_TICK.emit(rates)
+                EventType.ON_BAR.emit(rates, timeframe=candle.timeframe.get_name())
+                equity_curve.append((candle.close_time, self.broker.equity))
+                if self.on_equity is not None:
+                    self.on_equity(candle.close_time, self.broker.equity)
+            else:
+                EventType.ON_BAR.emit(rates, timeframe=candle.timeframe.get_name())
+
+        result = BacktestResult(
+            initial_capital=self.broker.initial_capital,
+            final_equity=self.broker.equity,
+            start=candles[0].open_time if candles else self.start,
+            end=candles[-1].close_time if candles else self.end,
+            bars=len(candles),
+            trades=list(self.broker.trades),
+            equity_curve=equity_curve,
+            funding=self.broker.funding,
+        )
+        self.result = result
+
+        EventType.ON_BACKTEST_PASS.emit()
+
+        fitness = EventType.ON_BACKTEST.emit()
+        result.fitness = self._get_fitness(result, fitness[0] if fitness else None)
+
+        EventType.ON_BACKTEST_DEINIT.emit()
+
+        self.logger.info(
+            "Backtest finished: net profit %.2f (%.2f%%), %d trades",
+            result.net_profit,
+            result.return_pct,
+            result.metrics.trades,
+        )
+        return result
+
+    @staticmethod
+    def _get_fitness(result: BacktestResult, value: Any) -> float:
+        """Fitness of a pass: the value returned by `on_backtest`, or the metric it names."""
+        if value is None:
+            value = DEFAULT_FITNESS_METRIC
+        if isinstance(value, str):
+            return result.metrics.get(value)
+        return float(value)
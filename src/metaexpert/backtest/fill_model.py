@@ -0,0 +1,331 @@
+"""Fill model of the simulated broker."""
+
+from abc import ABC, abstractmethod
+from dataclasses import dataclass
+from datetime import timedelta
+from typing import Any
+
+from metaexpert.config import (
+    DEFAULT_INTRABAR_PATH,
+    FEE_SCHEDULES,
+    SLIPPAGE_MODEL_FIXED,
+    SLIPPAGE_MODEL_SPREAD,
+    SLIPPAGE_MODEL_VOLATILITY,
+)
+from metaexpert.core import Candle, IntrabarPath, Order, OrderSide, OrderType
+
+
+class SlippageModel(ABC):
+    """Adverse price move of the fills executed at market."""
+
+    @abstractmethod
+    def get_slippage(self, candle: Candle) -> float:
+        """Slippage of a fill within a candle, as a fraction of the price (0.001 = 0.1%)."""
+
+
+class FixedSlippage(SlippageModel):
+    """Same slippage for every fill."""
+
+    def __init__(self, pct: float = 0.0) -> None:
+        """Initialize the model.
+
+        Args:
+            pct (float): Slippage in percent of the price.
+        """
+        self.pct: float = pct
+
+    def get_slippage(self, candle: Candle) -> float:
+        """Slippage of a fill within a candle, as a fraction of the price."""
+        return self.pct / 100
+
+
+class VolatilitySlippage(SlippageModel):
+    """Slippage growing with the range of the candle."""
+
+    def __init__(self, factor: float = 0.1) -> None:
+        """Initialize the model.
+
+        Args:
+            factor (float): Share of the candle range lost by a fill (0.1 = 10%).
+        """
+        self.factor: float = factor
+
+    def get_slippage(self, candle: Candle) -> float:
+        """Slippage of a fill within a candle, as a fraction of the price."""
+        if candle.open <= 0:
+            return 0.0
+        return self.factor * (candle.high - candle.low) / candle.open
+
+
+class SpreadSlippage(SlippageModel):
+    """Half of the bid/ask spread, paid by every order crossing it."""
+
+    def __init__(self, spread_pct: float = 0.0) -> None:
+        """Initialize the model.
+
+        Args:
+            spread_pct (float): Spread in percent of the price, unless the candle
+                carries its own in `extra["spread_pct"]`.
+        """
+        self.spread_pct: float = spread_pct
+
+    def get_slippage(self, candle: Candle) -> float:
+        """Slippage of a fill within a candle, as a fraction of the price."""
+        return float(candle.extra.get("spread_pct", self.spread_pct)) / 2 / 100
+
+
+# Slippage model classes by name, the value of the `slippage` fill model option
+SLIPPAGE_MODELS: dict[str, type[SlippageModel]] = {
+    SLIPPAGE_MODEL_FIXED: FixedSlippage,
+    SLIPPAGE_MODEL_VOLATILITY: VolatilitySlippage,
+    SLIPPAGE_MODEL_SPREAD: SpreadSlippage,
+}
+
+
+@dataclass(frozen=True)
+class FeeSchedule:
+    """Maker and taker fee rates (0.001 = 0.1%)."""
+
+    maker: float = 0.0
+    taker: float = 0.0
+
+    @classmethod
+    def get_fee_schedule(cls, exchange: str, market_type: str) -> "FeeSchedule":
+        """Base fee rates of an exchange market, zero if unknown."""
+        rates = FEE_SCHEDULES.get(exchange.lower(), {}).get(market_type.lower())
+        return cls(*rates) if rates else cls()
+
+
+@dataclass(frozen=True)
+class Execution:
+    """Fill of an order found by the fill model within a candle."""
+
+    price: float  # Before slippage
+    time: float  # Position on the intrabar path, from 0 (open) to 3 (close)
+    is_maker: bool  # Whether the order rested on the book before it was filled
+
+
+class FillModel:
+    """Execution assumptions of the simulated broker.
+
+    Only the open, high, low and close of a candle are known, so the model
+    assumes the path the price followed between them (`IntrabarPath`): the
+    orders are triggered in the order the path reaches their prices, which
+    decides between a stop-loss and a take-profit hit by the same candle.
+    An order reached at the start of its path fills at that price (a gap
+    through its level), otherwise at its level.
+
+    Market orders and triggered stop and take-profit orders pay the
+    slippage and the taker fee; limit orders resting before they are
+    reached pay the maker fee. With `volume_pct`, a candle fills at most
+    that share of its volume across the orders of its symbol, the rest of
+    an order staying open for the next candles. With `latency`, an order
+    reaches the market that long after it was placed: within a candle, its
+    path starts at the interpolated price of that time.
+    """
+
+    def __init__(
+        self,
+        *,
+        path: IntrabarPath | None = None,
+        slippage: SlippageModel | None = None,
+        fees: FeeSchedule | None = None,
+        volume_pct: float = 0.0,
+        latency: timedelta = timedelta(0),
+    ) -> None:
+        """Initialize the fill model.
+
+        Args:
+            path (IntrabarPath | None): Assumed order of the prices within a candle, `nearest` by default.
+            slippage (SlippageModel | None): Slippage of the fills at market, none by default.
+            fees (FeeSchedule | None): Maker and taker fee rates, none by default.
+            volume_pct (float): Largest share of a candle volume filled, in percent (0 = unlimited).
+            latency (timedelta): Delay between placing an order and its arrival on the market.
+        """
+        self.path: IntrabarPath = path or IntrabarPath.get_intrabar_path_from(
+            DEFAULT_INTRABAR_PATH
+        )
+        self.slippage: SlippageModel = slippage or FixedSlippage()
+        self.fees: FeeSchedule = fees or FeeSchedule()
+        self.volume_pct: float = volume_pct
+        self.latency: timedelta = latency
+
+    @classmethod
+    def parse(cls, text: str | None, **defaults: Any) -> "FillModel":
+        """Create a fill model from comma-separated options.
+
+        For example `path=ohlc,slippage=volatility:0.2,maker=0.0002,taker=0.0005,volume_pct=10,latency_ms=250`.
+        The slippage is `fixed:<pct>`, `volatility:<factor>` or `spread:<pct>`, the
+        argument defaulting to that of the model (e.g. `volatility` alone is 0.1).
+
+        Args:
+            text (str | None): Options, the defaults alone if empty.
+            **defaults: Constructor arguments of the options not given.
+
+        Raises:
+            ValueError: If an option is invalid.
+        """
+        options = dict(defaults)
+        fees = options.get("fees") or FeeSchedule()
+        for item in (text or "").split(","):
+            if not item.strip():
+                continue
+            name, separator, value = (part.strip() for part in item.partition("="))
+            if not separator or not value:
+                raise ValueError(f"Invalid fill model option: {item!r}")
+            try:
+                match name:
+                    case "path":
+                        options["path"] = IntrabarPath.get_intrabar_path_from(value)
+                    case "slippage":
+                        model, _, argument = value.partition(":")
+                        if model not in SLIPPAGE_MODELS:
+                            raise ValueError(f"Unknown slippage model: {model}")
+                        # Without an argument the model keeps its default
+                        options["slippage"] = (
+                            SLIPPAGE_MODELS[model](float(argument))
+                            if argument
+                            else SLIPPAGE_MODELS[model]()
+                        )
+                    case "maker":
+                        fees = FeeSchedule(float(value), fees.taker)
+                    case "taker":
+                        fees = FeeSchedule(fees.maker, float(value))
+                    case "volume_pct":
+                        options["volume_pct"] = float(value)
+                    case "latency_ms":
+                        options["latency"] = timedelta(milliseconds=float(value))
+                    case _:
+                        raise ValueError(f"Unknown fill model option: {name}")
+            except ValueError as e:
+                raise ValueError(f"Invalid fill model option {item!r}: {e}") from e
+        options["fees"] = fees
+        return cls(**options)
+
+    def match(self, order: Order, candle: Candle) -> Execution | None:
+        """Find the fill of an order within a candle, None if the candle does not fill it."""
+        points = self._get_points(candle)
+        start = self._get_arrival(order, candle)
+        if start is None:
+            return None
+
+        match order.type:
+            case OrderType.MARKET:
+                return Execution(_get_price(points, start), start, is_maker=False)
+            case OrderType.LIMIT:
+                return self._match_limit(order.side, order.price, points, start)
+            case OrderType.STOP:
+                return self._match_stop(order.side, order.stop_price, points, start)
+            case OrderType.STOP_LIMIT:
+                trigger = self._match_stop(order.side, order.stop_price, points, start)
+                if trigger is None:
+                    return None
+                return self._match_limit(order.side, order.price, points, trigger.time)
+            case OrderType.TAKE_PROFIT:
+                # Take-profit triggers in the favourable direction, like a limit.
+                execution = self._match_limit(
+                    order.side, order.stop_price, points, start
+                )
+                if execution is None:
+                    return None
+                return Execution(execution.price, execution.time, is_maker=False)
+            case _:
+                return None
+
+    def get_price(self, order: Order, price: float, candle: Candle) -> float:
+        """Worsen the fill price of orders executed at market (all but limit orders)."""
+        if order.type.requires_price():
+            return price
+        sign = 1 if order.side is OrderSide.BUY else -1
+        return price * (1 + sign * self.slippage.get_slippage(candle))
+
+    def get_fee(self, notional: float, *, is_maker: bool) -> float:
+        """Fee of a fill of the given notional."""
+        return notional * (self.fees.maker if is_maker else self.fees.taker)
+
+    def get_volume(self, candle: Candle) -> float | None:
+        """Largest quantity a candle fills, None if unlimited."""
+        if self.volume_pct <= 0 or candle.volume <= 0:
+            return None
+        return candle.volume * self.volume_pct / 100
+
+    def _get_points(self, candle: Candle) -> list[float]:
+        """Prices of the candle in the assumed order."""
+        high_first = [candle.open, candle.high, candle.low, candle.close]
+        low_first = [candle.open, candle.low, candle.high, candle.close]
+        match self.path:
+            case IntrabarPath.OHLC:
+                return high_first
+            case IntrabarPath.OLHC:
+                return low_first
+            case _:
+                if candle.high - candle.open <= candle.open - candle.low:
+                    return high_first
+                return low_first
+
+    def _get_arrival(self, order: Order, candle: Candle) -> float | None:
+        """Position on the path at which the order reaches the market, None if after the candle."""
+        if not self.latency or order.created_at is None:
+            return 0.0
+        arrival = order.created_at + self.latency
+        duration = candle.close_time - candle.open_time
+        if arrival <= candle.open_time or duration <= timedelta(0):
+            return 0.0
+        if arrival >= candle.close_time:
+            return None
+        return (arrival - candle.open_time) / duration * 3
+
+    @staticmethod
+    def _match_limit(
+        side: OrderSide, price: float | None, points: list[float], start: float
+    ) -> Execution | None:
+        """A buy limit fills at or below its price, a sell limit at or above."""
+        if price is None:
+            return None
+        reached = _reach(points, start, price, is_above=side is OrderSide.SELL)
+        if reached is None:
+            return None
+        time, fill_price = reached
+        return Execution(fill_price, time, is_maker=time > start)
+
+    @staticmethod
+    def _match_stop(
+        side: OrderSide, stop: float | None, points: list[float], start: float
+    ) -> Execution | None:
+        """A buy stop triggers at or above its price, a sell stop at or below."""
+        if stop is None:
+            return None
+        reached = _reach(points, start, stop, is_above=side is OrderSide.BUY)
+        if reached is None:
+            return None
+        return Execution(reached[1], reached[0], is_maker=False)
+
+
+def _get_price(points: list[float], time: float) -> float:
+    """Price at a position of the path, interpolated between its points."""
+    index = min(int(time), len(points) - 2)
+    fraction = time - index
+    return points[index] + (points[index + 1] - points[index]) * fraction
+
+
+def _reach(
+    points: list[float], start: float, level: float, *, is_above: bool
+) -> tuple[float, float] | None:
+    """First position and price at which the path reaches a level, from `start` on.
+
+    With `is_above` the price must be at or above the level, otherwise at or
+    below it; already there at `start`, the order fills at the price of `start`.
+    """
+
+    def is_reached(price: float) -> bool:
+        return price >= level if is_above else price <= level
+
+    time, price = start, _get_price(points, start)
+    if is_reached(price):
+        return time, price
+    for index in range(int(start) + 1, len(points)):
+        following = points[index]
+        if is_reached(following):
+            return time + (index - time) * (level - price) / (following - price), level
+        time, price = float(index), following
+    return None
@@ -0,0 +1,329 @@
+"""Performance metrics of a backtest."""
+
+import math
+from dataclasses import asdict, dataclass, fields
+from datetime import datetime, timedelta
+from statistics import mean, median, stdev
+from typing import Any
+
+# Length of a year of continuous trading, used to annualize the returns
+YEAR: timedelta = timedelta(days=365.25)
+
+
+@dataclass(frozen=True)
+class RoundTrip:
+    """Position opened and closed again, rebuilt from the fills."""
+
+    symbol: str
+    side: str  # 'long' or 'short'
+    quantity: float
+    entry_time: datetime
+    exit_time: datetime
+    entry_price: float
+    exit_price: float
+    pnl: float  # Realized PnL net of the entry and exit fees
+
+    @property
+    def return_pct(self) -> float:
+        """PnL in percent of the entry notional."""
+        notional = self.quantity * self.entry_price
+        return self.pnl / notional * 100 if notional else 0.0
+
+    @property
+    def duration(self) -> timedelta:
+        """Time the position was held."""
+        return self.exit_time - self.entry_time
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> "RoundTrip":
+        """Create a round trip from its dictionary, as written in the JSON report."""
+        values = {item.name: data[item.name] for item in fields(cls)}
+        for name in ("entry_time", "exit_time"):
+            values[name] = datetime.fromisoformat(values[name])
+        return cls(**values)
+
+    def to_dict(self) -> dict[str, Any]:
+        """Convert the round trip to a dictionary."""
+        return asdict(self) | {
+            "entry_time": self.entry_time.isoformat(),
+            "exit_time": self.exit_time.isoformat(),
+            "return_pct": self.return_pct,
+        }
+
+
+@dataclass(frozen=True)
+class Metrics:
+    """Performance of a backtest pass.
+
+    Percentages are in percent (12.5 = 12.5%). The ratios are annualized
+    from the interval of the equity samples, assuming trading around the
+    clock, with a zero risk-free rate.
+    """
+
+    net_profit: float = 0.0
+    return_pct: float = 0.0
+    cagr_pct: float = 0.0
+    sharpe: float = 0.0
+    sortino: float = 0.0
+    calmar: float = 0.0
+    max_drawdown: float = 0.0  # In settlement currency
+    max_drawdown_pct: float = 0.0
+    max_drawdown_days: float = 0.0  # Longest time below a previous peak
+    trades: int = 0  # Closed round trips
+    win_rate_pct: float = 0.0
+    profit_factor: float = 0.0  # Infinite without a losing trade
+    expectancy: float = 0.0  # Average PnL of a round trip
+    exposure_pct: float = 0.0  # Share of the period with an open position
+
+    @classmethod
+    def get_names(cls) -> list[str]:
+        """Names of the metrics."""
+        return [item.name for item in fields(cls)]
+
+    def get(self, name: str) -> float:
+        """Get a metric by name.
+
+        Raises:
+            ValueError: If the metric does not exist.
+        """
+        if name not in self.get_names():
+            raise ValueError(
+                f"Unknown metric: {name}, expected one of {', '.join(self.get_names())}"
+            )
+        return float(getattr(self, name))
+
+    def to_dict(self) -> dict[str, Any]:
+        """Convert the metrics to a dictionary."""
+        return asdict(self)
+
+
+def get_round_trips(fills: list[dict[str, Any]]) -> list[RoundTrip]:
+    """Rebuild the closed positions from the fills of a simulated broker.
+
+    A round trip starts when a symbol goes from flat to a position and ends
+    when it is flat again; a fill reversing the position ends one and starts
+    the next. The long and short positions of a hedged symbol, told apart
+    by the `position_side` of their fills, are separate round trips. A
+    position still open at the end is not included.
+
+    Args:
+        fills (list[dict]): Fills in time order, as recorded by `SimulatedBroker.trades`.
+
+    Returns:
+        list[RoundTrip]: The closed round trips, by exit time.
+    """
+    trips: list[RoundTrip] = []
+    books: dict[tuple[str, str | None], _Book] = {}
+    for fill in fills:
+        symbol = fill["symbol"]
+        quantity = fill["quantity"]
+        sign = 1 if fill["side"] == "buy" else -1
+        position_side = fill.get("position_side")
+        key = (symbol, position_side)
+        book = books.get(key)
+        if book is None or book.is_flat:
+            book_sign = sign
+            if position_side is not None:
+                book_sign = 1 if position_side == "long" else -1
+            book = books[key] = _Book(symbol, book_sign, fill["time"])
+
+        if sign == book.sign:
+            book.open(quantity, fill["price"], fill["fee"])
+            continue
+
+        closed = min(quantity, book.size)
+        share = closed / quantity
+        book.close(closed, fill["price"], fill["realized_pnl"], fill["fee"] * share)
+        if book.is_flat:
+            trips.append(book.get_round_trip(fill["time"]))
+            remainder = quantity - closed
+            if remainder > 1e-12:
+                book = books[key] = _Book(symbol, sign, fill["time"])
+                book.open(remainder, fill["price"], fill["fee"] * (1 - share))
+    return trips
+
+
+def get_metrics(
+    initial_capital: float,
+    equity_curve: list[tuple[datetime, float]],
+    round_trips: list[RoundTrip],
+    *,
+    start: datetime | None = None,
+    end: datetime | None = None,
+) -> Metrics:
+    """Compute the performance metrics of a backtest pass.
+
+    Args:
+        initial_capital (float): Starting balance.
+        equity_curve (list[tuple[datetime, float]]): Equity sampled after every execution bar.
+        round_trips (list[RoundTrip]): Closed positions of the pass.
+        start (datetime | None): Start of the period, the first sample by default.
+        end (datetime | None): End of the period, the last sample by default.
+
+    Returns:
+        Metrics: The metrics, zero where undefined (no samples, no trades).
+    """
+    if not equity_curve:
+        return Metrics()
+
+    start = start or equity_curve[0][0]
+    end = end or equity_curve[-1][0]
+    final_equity = equity_curve[-1][1]
+    net_profit = final_equity - initial_capital
+    period = end - start
+
+    cagr = 0.0
+    if initial_capital > 0 and final_equity > 0 and period > timedelta(0):
+        cagr = (final_equity / initial_capital) ** (YEAR / period) - 1
+
+    max_drawdown, max_drawdown_pct, max_drawdown_days = _get_drawdown(
+        initial_capital, equity_curve, start
+    )
+    sharpe, sortino = _get_ratios(initial_capital, equity_curve)
+
+    pnls = [trip.pnl for trip in round_trips]
+    winners = [pnl for pnl in pnls if pnl > 0]
+    gross_profit = sum(winners)
+    gross_loss = -sum(pnl for pnl in pnls if pnl < 0)
+    if gross_loss > 0:
+        profit_factor = gross_profit / gross_loss
+    else:
+        profit_factor = math.inf if gross_profit > 0 else 0.0
+
+    return Metrics(
+        net_profit=net_profit,
+        return_pct=net_profit / initial_capital * 100 if initial_capital else 0.0,
+        cagr_pct=cagr * 100,
+        sharpe=sharpe,
+        sortino=sortino,
+        calmar=cagr / (max_drawdown_pct / 100) if max_drawdown_pct else 0.0,
+        max_drawdown=max_drawdown,
+        max_drawdown_pct=max_drawdown_pct,
+        max_drawdown_days=max_drawdown_days,
+        trades=len(round_trips),
+        win_rate_pct=len(winners) / len(pnls) * 100 if pnls else 0.0,
+        profit_factor=profit_factor,
+        expectancy=mean(pnls) if pnls else 0.0,
+        exposure_pct=_get_exposure(round_trips, start, end) * 100,
+    )
+
+
+def _get_drawdown(
+    initial_capital: float,
+    equity_curve: list[tuple[datetime, float]],
+    start: datetime,
+) -> tuple[float, float, float]:
+    """Deepest drawdown from a peak, in currency and percent, and longest one in days."""
+    peak, peak_time = initial_capital, start
+    max_drawdown = max_drawdown_pct = 0.0
+    longest = timedelta(0)
+    for time, equity in equity_curve:
+        if equity >= peak:
+            peak, peak_time = equity, time
+            continue
+        max_drawdown = max(max_drawdown, peak - equity)
+        if peak > 0:
+            max_drawdown_pct = max(max_drawdown_pct, (peak - equity) / peak * 100)
+        longest = max(longest, time - peak_time)
+    return max_drawdown, max_drawdown_pct, longest / timedelta(days=1)
+
+
+def _get_ratios(
+    initial_capital: float, equity_curve: list[tuple[datetime, float]]
+) -> tuple[float, float]:
+    """Annualized Sharpe and Sortino ratios of the returns between the equity samples."""
+    if len(equity_curve) < 2:
+        return 0.0, 0.0
+
+    equities = [initial_capital] + [equity for _, equity in equity_curve]
+    returns = [
+        current / previous - 1 if previous > 0 else 0.0
+        for previous, current in zip(equities, equities[1:], strict=False)
+    ]
+    interval = median(
+        later - earlier
+        for (earlier, _), (later, _) in zip(
+            equity_curve, equity_curve[1:], strict=False
+        )
+    )
+    if interval <= timedelta(0):
+        return 0.0, 0.0
+
+    scale = math.sqrt(YEAR / interval)
+    average = mean(returns)
+    deviation = stdev(returns)
+    downside = math.sqrt(mean(min(item, 0.0) ** 2 for item in returns))
+    sharpe = average / deviation * scale if deviation > 0 else 0.0
+    sortino = average / downside * scale if downside > 0 else 0.0
+    return sharpe, sortino
+
+
+def _get_exposure(
+    round_trips: list[RoundTrip], start: datetime, end: datetime
+) -> float:
+    """Share of the period covered by at least one open position."""
+    if end <= start:
+        return 0.0
+
+    covered = timedelta(0)
+    current: tuple[datetime, datetime] | None = None
+    for trip in sorted(round_trips, key=lambda item: item.entry_time):
+        entry, exit_ = max(trip.entry_time, start), min(trip.exit_time, end)
+        if exit_ <= entry:
+            continue
+        if current is None or entry > current[1]:
+            if current is not None:
+                covered += current[1] - current[0]
+            current = (entry, exit_)
+        else:
+            current = (current[0], max(current[1], exit_))
+    if current is not None:
+        covered += current[1] - current[0]
+    return covered / (end - start)
+
+
+class _Book:
+    """Position of a symbol being rebuilt from its fills."""
+
+    def __init__(self, symbol: str, sign: int, time: datetime) -> None:
+        self.symbol: str = symbol
+        self.sign: int = sign
+        self.entry_time: datetime = time
+        self.size: float = 0.0
+        self.quantity: float = 0.0
+        self.entry_value: float = 0.0
+        self.exit_quantity: float = 0.0
+        self.exit_value: float = 0.0
+        self.pnl: float = 0.0
+
+    @property
+    def is_flat(self) -> bool:
+        """Whether the position was closed."""
+        return self.size <= 1e-12
+
+    def open(self, quantity: float, price: float, fee: float) -> None:
+        """Add an entry fill."""
+        self.size += quantity
+        self.quantity += quantity
+        self.entry_value += quantity * price
+        self.pnl -= fee
+
+    def close(self, quantity: float, price: float, realized: float, fee: float) -> None:
+        """Add an exit fill."""
+        self.size -= quantity
+        self.exit_quantity += quantity
+        self.exit_value += quantity * price
+        self.pnl += realized - fee
+
+    def get_round_trip(self, time: datetime) -> RoundTrip:
+        """Round trip of the closed position."""
+        return RoundTrip(
+            symbol=self.symbol,
+            side="long" if self.sign > 0 else "short",
+            quantity=self.quantity,
+            entry_time=self.entry_time,
+            exit_time=time,
+            entry_price=self.entry_value / self.quantity,
+            exit_price=self.exit_value / self.exit_quantity,
+            pnl=self.pnl,
+        )
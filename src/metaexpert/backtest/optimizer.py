@@ -0,0 +1,413 @@
+"""Parameter optimization over backtest passes."""
+
+import itertools
+import json
+import math
+import os
+import random
+import subprocess
+import sys
+import tempfile
+from collections.abc import Iterable
+from concurrent.futures import ThreadPoolExecutor
+from dataclasses import dataclass, field
+from datetime import datetime
+from decimal import Decimal
+from pathlib import Path
+from typing import Any
+
+from metaexpert.config import (
+    ENV_BACKTEST_END,
+    ENV_BACKTEST_START,
+    ENV_INITIAL_CAPITAL,
+    ENV_PARAMETERS,
+    ENV_REPORT_FILE,
+    ENV_TRADE_MODE,
+    GENETIC_MUTATION_RATE,
+    GENETIC_POPULATION,
+    OPTIMIZATION_PASSES,
+    TRADE_MODE_BACKTEST,
+)
+from metaexpert.core import OptimizationMethod
+from metaexpert.logger import MetaLogger as Logger, get_logger
+
+from .metrics import RoundTrip
+
+
+@dataclass(frozen=True)
+class Parameter:
+    """Input of the expert and the values to try."""
+
+    name: str
+    values: tuple[Any, ...]
+
+    @classmethod
+    def parse(cls, text: str) -> "Parameter":
+        """Parse a parameter definition.
+
+        Either a range `name=start:stop:step` (stop included) or a list of
+        values `name=a|b|c`. Numbers and booleans are converted.
+
+        Raises:
+            ValueError: If the definition is invalid.
+        """
+        name, separator, spec = text.partition("=")
+        name, spec = name.strip(), spec.strip()
+        if not separator or not name or not spec:
+            raise ValueError(
+                f"Invalid parameter: {text!r}, expected name=start:stop:step"
+            )
+
+        if ":" not in spec:
+            return cls(name, tuple(_parse_value(item) for item in spec.split("|")))
+
+        parts = spec.split(":")
+        if len(parts) != 3:
+            raise ValueError(
+                f"Invalid range of {name}: {spec!r}, expected start:stop:step"
+            )
+        start, stop, step = (Decimal(item.strip()) for item in parts)
+        if step <= 0 or stop < start:
+            raise ValueError(f"Invalid range of {name}: {spec!r}")
+        count = int((stop - start) / step) + 1
+        is_int = all(item == item.to_integral_value() for item in (start, step))
+        values = (start + index * step for index in range(count))
+        return cls(
+            name, tuple(int(item) if is_int else float(item) for item in values)
+        )
+
+
+@dataclass
+class OptimizationPass:
+    """Backtest of one combination of the parameters.
+
+    `equity_curve` and `round_trips` are only read from the report when
+    requested, see `run_backtest()`.
+    """
+
+    parameters: dict[str, Any]
+    fitness: float | None = None
+    metrics: dict[str, Any] = field(default_factory=dict)
+    error: str | None = None
+    equity_curve: list[tuple[datetime, float]] = field(default_factory=list)
+    round_trips: list[RoundTrip] = field(default_factory=list)
+
+    def to_dict(self) -> dict[str, Any]:
+        """Row of the results table."""
+        return (
+            self.parameters
+            | {"fitness": self.fitness}
+            | self.metrics
+            | {"error": self.error}
+        )
+
+
+class Optimizer:
+    """Search the inputs of an expert maximizing the fitness of its backtests.
+
+    Every pass runs the expert script in its own process, in backtest mode,
+    with the combination of the parameters in the `METAEXPERT_PARAMETERS`
+    environment variable: they override the `on_init` inputs of the same
+    name and are returned by `expert.get_input()`. The fitness of a pass is
+    the value returned by `on_backtest`.
+
+    The grid search tries every combination; the random search draws up to
+    `passes` of them; the genetic search evolves generations of `population`
+    combinations by tournament selection, uniform crossover and mutation,
+    until `passes` combinations were tried; every tried combination takes
+    part in the selection, so the best ones are never lost. The first pass
+    runs alone, so that it fills the candle cache read by the others.
+    """
+
+    def __init__(
+        self,
+        script: str | Path,
+        parameters: list[Parameter],
+        *,
+        method: OptimizationMethod = OptimizationMethod.GRID,
+        passes: int = OPTIMIZATION_PASSES,
+        workers: int | None = None,
+        start: str | None = None,
+        end: str | None = None,
+        initial_capital: float | None = None,
+        env: dict[str, str] | None = None,
+        population: int = GENETIC_POPULATION,
+        mutation_rate: float = GENETIC_MUTATION_RATE,
+        seed: int | None = None,
+    ) -> None:
+        """Initialize the optimizer.
+
+        Args:
+            script (str | Path): Expert script.
+            parameters (list[Parameter]): Parameters to optimize.
+            method (OptimizationMethod): Search method.
+            passes (int): Maximum number of passes of the random and genetic searches.
+            workers (int | None): Passes run in parallel, the number of CPUs by default.
+            start (str | None): Start of the backtests, the one of the script by default.
+            end (str | None): End of the backtests, the one of the script by default.
+            initial_capital (float | None): Initial capital, the one of the script by default.
+            env (dict[str, str] | None): Environment of the passes, the current one by default.
+            population (int): Combinations per generation of the genetic search.
+            mutation_rate (float): Probability that the genetic search mutates a parameter.
+            seed (int | None): Seed of the random and genetic searches.
+        """
+        if not parameters:
+            raise ValueError("At least one parameter to optimize is required")
+        self.script: Path = Path(script).resolve()
+        self.parameters: list[Parameter] = parameters
+        self.method: OptimizationMethod = method
+        self.passes: int = passes
+        self.workers: int = workers or os.cpu_count() or 1
+        self.start: str | None = start
+        self.end: str | None = end
+        self.initial_capital: float | None = initial_capital
+        self.env: dict[str, str] = dict(os.environ) if env is None else env
+        self.population: int = population
+        self.mutation_rate: float = mutation_rate
+        self.logger: Logger = get_logger("Optimizer")
+        self._random: random.Random = random.Random(seed)
+        self._results: dict[tuple[Any, ...], OptimizationPass] = {}
+
+    @property
+    def combinations(self) -> int:
+        """Number of combinations of the parameter values."""
+        count = 1
+        for parameter in self.parameters:
+            count *= len(parameter.values)
+        return count
+
+    def run(self) -> list[OptimizationPass]:
+        """Run the search and return the passes, best fitness first."""
+        self._results = {}
+        self.logger.info(
+            "Optimizing %s with a %s search over %d combinations",
+            ", ".join(parameter.name for parameter in self.parameters),
+            self.method.get_name(),
+            self.combinations,
+        )
+        match self.method:
+            case OptimizationMethod.GRID:
+                self._evaluate(
+                    itertools.product(*(item.values for item in self.parameters))
+                )
+            case OptimizationMethod.RANDOM:
+                self._evaluate(self._sample(min(self.passes, self.combinations)))
+            case OptimizationMethod.GENETIC:
+                self._evolve()
+        return self.rank(list(self._results.values()))
+
+    @staticmethod
+    def rank(passes: list[OptimizationPass]) -> list[OptimizationPass]:
+        """Sort the passes by fitness, the failed ones last."""
+        return sorted(passes, key=_get_rank_key)
+
+    def run_pass(self, parameters: dict[str, Any]) -> OptimizationPass:
+        """Backtest the expert with a combination of the parameters."""
+        return run_backtest(
+            self.script,
+            parameters,
+            start=self.start,
+            end=self.end,
+            initial_capital=self.initial_capital,
+            env=self.env,
+        )
+
+    def _evaluate(self, combinations: Iterable[tuple[Any, ...]]) -> None:
+        """Backtest the combinations not tried yet, in parallel."""
+        pending = [
+            item for item in dict.fromkeys(combinations) if item not in self._results
+        ]
+        if not self._results and pending:
+            self._store(pending[0], self._run(pending[0]))
+            pending = pending[1:]
+
+        with ThreadPoolExecutor(max_workers=self.workers) as executor:
+            for key, result in zip(
+                pending, executor.map(self._run, pending), strict=True
+            ):
+                self._store(key, result)
+
+    def _run(self, key: tuple[Any, ...]) -> OptimizationPass:
+        """Run the pass of a combination given as a tuple of values."""
+        return self.run_pass(
+            {
+                parameter.name: value
+                for parameter, value in zip(self.parameters, key, strict=True)
+            }
+        )
+
+    def _store(self, key: tuple[Any, ...], result: OptimizationPass) -> None:
+        """Keep the result of a pass."""
+        self._results[key] = result
+        number = len(self._results)
+        if result.error is not None:
+            self.logger.warning(
+                "Pass %d %s failed: %s", number, result.parameters, result.error
+            )
+        else:
+            self.logger.info(
+                "Pass %d %s: fitness %s", number, result.parameters, result.fitness
+            )
+
+    def _sample(self, count: int) -> list[tuple[Any, ...]]:
+        """Distinct random combinations."""
+        sizes = [len(parameter.values) for parameter in self.parameters]
+        combinations = []
+        for index in self._random.sample(range(self.combinations), count):
+            values = []
+            for parameter, size in zip(self.parameters, sizes, strict=True):
+                index, position = divmod(index, size)
+                values.append(parameter.values[position])
+            combinations.append(tuple(values))
+        return combinations
+
+    def _evolve(self) -> None:
+        """Genetic search: evolve the generations until the passes are used up."""
+        budget = min(self.passes, self.combinations)
+        generation = self._sample(min(self.population, budget))
+        while generation:
+            self._evaluate(generation)
+            remaining = budget - len(self._results)
+            if remaining <= 0:
+                return
+
+            ranked = sorted(
+                self._results, key=lambda key: _get_rank_key(self._results[key])
+            )
+            children: list[tuple[Any, ...]] = []
+            for _ in range(self.population * 10):
+                if len(children) >= min(self.population, remaining):
+                    break
+                child = self._mutate(
+                    self._crossover(self._select(ranked), self._select(ranked))
+                )
+                if child not in self._results and child not in children:
+                    children.append(child)
+            generation = children
+
+    def _select(self, ranked: list[tuple[Any, ...]]) -> tuple[Any, ...]:
+        """Tournament selection: the best of three random tried combinations."""
+        contenders = self._random.sample(range(len(ranked)), min(3, len(ranked)))
+        return ranked[min(contenders)]
+
+    def _crossover(
+        self, first: tuple[Any, ...], second: tuple[Any, ...]
+    ) -> tuple[Any, ...]:
+        """Uniform crossover: every value comes from either parent."""
+        return tuple(
+            a if self._random.random() < 0.5 else b
+            for a, b in zip(first, second, strict=True)
+        )
+
+    def _mutate(self, key: tuple[Any, ...]) -> tuple[Any, ...]:
+        """Replace some values by random ones."""
+        return tuple(
+            self._random.choice(parameter.values)
+            if self._random.random() < self.mutation_rate
+            else value
+            for parameter, value in zip(self.parameters, key, strict=True)
+        )
+
+
+def run_backtest(
+    script: str | Path,
+    parameters: dict[str, Any] | None = None,
+    *,
+    start: str | None = None,
+    end: str | None = None,
+    initial_capital: float | None = None,
+    env: dict[str, str] | None = None,
+    details: bool = False,
+) -> OptimizationPass:
+    """Backtest an expert script in its own process.
+
+    Args:
+        script (str | Path): Expert script.
+        parameters (dict[str, Any] | None): Inputs overriding the ones of the script.
+        start (str | None): Start of the backtest, the one of the script by default.
+        end (str | None): End of the backtest, the one of the script by default.
+        initial_capital (float | None): Initial capital, the one of the script by default.
+        env (dict[str, str] | None): Environment of the process, the current one by default.
+        details (bool): Also read the equity curve and the round trips of the backtest.
+
+    Returns:
+        OptimizationPass: The fitness and metrics of the backtest, or its error.
+    """
+    script = Path(script).resolve()
+    parameters = parameters or {}
+    with tempfile.TemporaryDirectory() as directory:
+        report = Path(directory) / "report.json"
+        overrides = {
+            ENV_TRADE_MODE: TRADE_MODE_BACKTEST,
+            ENV_PARAMETERS: json.dumps(parameters),
+            ENV_REPORT_FILE: str(report),
+        }
+        if start:
+            overrides[ENV_BACKTEST_START] = start
+        if end:
+            overrides[ENV_BACKTEST_END] = end
+        if initial_capital is not None:
+            overrides[ENV_INITIAL_CAPITAL] = str(initial_capital)
+
+        completed = subprocess.run(
+            [sys.executable, str(script)],
+            cwd=script.parent,
+            env=(dict(os.environ) if env is None else env) | overrides,
+            capture_output=True,
+            text=True,
+            check=False,
+        )
+        if completed.returncode != 0 or not report.is_file():
+            lines = (completed.stderr or completed.stdout).strip().splitlines()
+            return OptimizationPass(
+                parameters,
+                error=lines[-1] if lines else f"exit code {completed.returncode}",
+            )
+        content = json.loads(report.read_text(encoding="utf-8"))
+
+    summary = _from_json(content["summary"])
+    fitness = summary.pop("fitness", None)
+    result = OptimizationPass(
+        parameters,
+        fitness=float(fitness) if fitness is not None else None,
+        metrics=summary,
+    )
+    if details:
+        result.equity_curve = [
+            (datetime.fromisoformat(time), equity)
+            for time, equity in content.get("equity_curve", [])
+        ]
+        result.round_trips = [
+            RoundTrip.from_dict(item) for item in content.get("round_trips", [])
+        ]
+    return result
+
+
+def _from_json(values: dict[str, Any]) -> dict[str, Any]:
+    """Read back the infinite values of a report, written as "inf" or "-inf"."""
+    return {
+        key: float(value) if value in ("inf", "-inf") else value
+        for key, value in values.items()
+    }
+
+
+def _get_rank_key(result: OptimizationPass) -> tuple[bool, float]:
+    """Sort key of a pass: best fitness first, failed passes last.
+
+    A pass without a fitness (e.g. NaN) ranks after the passes with one.
+    """
+    fitness = -math.inf if result.fitness is None else result.fitness
+    return result.error is not None, -fitness
+
+
+def _parse_value(text: str) -> Any:
+    """Convert a value of a parameter list to a number or a boolean if possible."""
+    text = text.strip()
+    if text.lower() in ("true", "false"):
+        return text.lower() == "true"
+    for convert in (int, float):
+        try:
+            return convert(text)
+        except ValueError:
+            continue
+    return text
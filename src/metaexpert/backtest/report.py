@@ -0,0 +1,294 @@
+"""Backtest report."""
+
+import csv
+import json
+import math
+from html import escape
+from pathlib import Path
+from typing import Any
+
+from metaexpert.config import REPORT_FORMAT_CSV, REPORT_FORMAT_HTML, REPORT_FORMAT_JSON
+
+from .metrics import RoundTrip
+from .optimizer import OptimizationPass
+from .result import BacktestResult
+from .walk_forward import WalkForwardResult
+
+# Size of the equity and drawdown charts of the HTML report (pixels)
+CHART_WIDTH: int = 900
+CHART_HEIGHT: int = 240
+
+# Style sheet of the HTML reports
+STYLE: str = (
+    "body{font-family:sans-serif}table{border-collapse:collapse}"
+    "th,td{border:1px solid #ccc;padding:4px 8px;text-align:left}"
+    "svg{border:1px solid #ccc}.axis{color:#666;font-size:small}"
+)
+
+
+def write_report(result: BacktestResult, path: str | Path) -> Path:
+    """Write a backtest report, in the format given by the file extension.
+
+    The JSON report holds the summary, the metrics, the round trips, the
+    fills and the equity curve. The CSV report holds the summary and the
+    metrics, with the round trips and the equity curve written next to it
+    in `<name>-trades.csv` and `<name>-equity.csv`. The HTML report is a
+    single file with the metrics, the equity and drawdown charts and the
+    trade list.
+
+    Args:
+        result (BacktestResult): Result of the backtest.
+        path (str | Path): Report file (.json, .csv or .html).
+
+    Returns:
+        Path: The written file.
+    """
+    path = Path(path)
+    path.parent.mkdir(parents=True, exist_ok=True)
+    report_format = path.suffix.lstrip(".").lower()
+
+    if report_format == REPORT_FORMAT_JSON:
+        content = {
+            "summary": _to_json(result.to_dict()),
+            "round_trips": [_to_json(trip.to_dict()) for trip in result.round_trips],
+            "fills": result.trades,
+            "equity_curve": [[time, equity] for time, equity in result.equity_curve],
+        }
+        path.write_text(json.dumps(content, indent=2, default=str), encoding="utf-8")
+    elif report_format == REPORT_FORMAT_CSV:
+        _write_csv(path, ["metric", "value"], list(result.to_dict().items()))
+        _write_csv(
+            path.with_name(f"{path.stem}-trades.csv"),
+            list(RoundTrip.__dataclass_fields__) + ["return_pct"],
+            [list(trip.to_dict().values()) for trip in result.round_trips],
+        )
+        _write_csv(
+            path.with_name(f"{path.stem}-equity.csv"),
+            ["time", "equity"],
+            [[time.isoformat(), equity] for time, equity in result.equity_curve],
+        )
+    elif report_format == REPORT_FORMAT_HTML:
+        path.write_text(_render_html(result), encoding="utf-8")
+    else:
+        raise ValueError(f"Unsupported report format: {report_format}")
+
+    return path
+
+
+def write_optimization_report(
+    passes: list[OptimizationPass],
+    path: str | Path,
+    *,
+    title: str = "Optimization report",
+) -> Path:
+    """Write the results table of an optimization, one row per pass in rank order.
+
+    Args:
+        passes (list[OptimizationPass]): Ranked passes of the optimization.
+        path (str | Path): Report file (.json, .csv or .html).
+        title (str): Title of the HTML report.
+
+    Returns:
+        Path: The written file.
+    """
+    path = Path(path)
+    path.parent.mkdir(parents=True, exist_ok=True)
+    report_format = path.suffix.lstrip(".").lower()
+    rows = [
+        {"rank": rank} | _to_json(item.to_dict())
+        for rank, item in enumerate(passes, start=1)
+    ]
+
+    if report_format == REPORT_FORMAT_JSON:
+        path.write_text(json.dumps(rows, indent=2, default=str), encoding="utf-8")
+    elif report_format == REPORT_FORMAT_CSV:
+        columns = list(dict.fromkeys(key for row in rows for key in row))
+        _write_csv(
+            path, columns, [[row.get(column) for column in columns] for row in rows]
+        )
+    elif report_format == REPORT_FORMAT_HTML:
+        path.write_text(
+            "<!DOCTYPE html>\n<html><head><meta charset='utf-8'>"
+            f"<title>{escape(title)}</title><style>{STYLE}</style></head>"
+            f"<body><h1>{escape(title)}</h1>"
+            + _render_table(rows)
+            + "</body></html>\n",
+            encoding="utf-8",
+        )
+    else:
+        raise ValueError(f"Unsupported report format: {report_format}")
+
+    return path
+
+
+def write_walk_forward_report(result: WalkForwardResult, path: str | Path) -> Path:
+    """Write a walk-forward report, in the format given by the file extension.
+
+    The JSON report holds the summary, the windows, the parameter stability
+    and the stitched out-of-sample equity curve. The CSV report holds the
+    summary, with the windows, the stability and the equity curve written
+    next to it in `<name>-windows.csv`, `<name>-stability.csv` and
+    `<name>-equity.csv`. The HTML report is a single file with all of them.
+
+    Args:
+        result (WalkForwardResult): Result of the walk-forward analysis.
+        path (str | Path): Report file (.json, .csv or .html).
+
+    Returns:
+        Path: The written file.
+    """
+    path = Path(path)
+    path.parent.mkdir(parents=True, exist_ok=True)
+    report_format = path.suffix.lstrip(".").lower()
+    windows = [
+        {"window": number} | _to_json(step.to_dict())
+        for number, step in enumerate(result.steps, start=1)
+    ]
+    stability = [item.to_dict() for item in result.stability]
+
+    if report_format == REPORT_FORMAT_JSON:
+        content = {
+            "summary": _to_json(result.to_dict()),
+            "windows": windows,
+            "stability": stability,
+            "equity_curve": [[time, equity] for time, equity in result.equity_curve],
+        }
+        path.write_text(json.dumps(content, indent=2, default=str), encoding="utf-8")
+    elif report_format == REPORT_FORMAT_CSV:
+        _write_csv(path, ["metric", "value"], list(result.to_dict().items()))
+        columns = list(dict.fromkeys(key for row in windows for key in row))
+        _write_csv(
+            path.with_name(f"{path.stem}-windows.csv"),
+            columns,
+            [[row.get(column) for column in columns] for row in windows],
+        )
+        _write_csv(
+            path.with_name(f"{path.stem}-stability.csv"),
+            ["parameter", "values", "mode", "mode_pct", "mean", "stdev"],
+            [list(row.values()) for row in stability],
+        )
+        _write_csv(
+            path.with_name(f"{path.stem}-equity.csv"),
+            ["time", "equity"],
+            [[time.isoformat(), equity] for time, equity in result.equity_curve],
+        )
+    elif report_format == REPORT_FORMAT_HTML:
+        summary = "".join(
+            f"<tr><th>{escape(str(key))}</th><td>{escape(_format(value))}</td></tr>"
+            for key, value in result.to_dict().items()
+        )
+        path.write_text(
+            "<!DOCTYPE html>\n<html><head><meta charset='utf-8'>"
+            "<title>Walk-forward report</title>"
+            f"<style>{STYLE}</style></head><body><h1>Walk-forward report</h1>"
+            f"<h2>Out-of-sample summary</h2><table>{summary}</table>"
+            + _render_chart(
+                [equity for _, equity in result.equity_curve],
+                "#1f77b4",
+                "Stitched out-of-sample equity",
+            )
+            + "<h2>Windows</h2>"
+            + _render_table(windows)
+            + "<h2>Parameter stability</h2>"
+            + _render_table(stability)
+            + "</body></html>\n",
+            encoding="utf-8",
+        )
+    else:
+        raise ValueError(f"Unsupported report format: {report_format}")
+
+    return path
+
+
+def _to_json(values: dict[str, Any]) -> dict[str, Any]:
+    """Write the values JSON cannot represent: infinities as "inf" or "-inf", NaN as null."""
+    return {key: _to_json_value(value) for key, value in values.items()}
+
+
+def _to_json_value(value: Any) -> Any:
+    """One value of `_to_json`."""
+    if not isinstance(value, float) or math.isfinite(value):
+        return value
+    return None if math.isnan(value) else str(value)
+
+
+def _write_csv(path: Path, header: list[str], rows: list[list[Any]]) -> None:
+    """Write one CSV table."""
+    with path.open("w", newline="", encoding="utf-8") as file:
+        writer = csv.writer(file)
+        writer.writerow(header)
+        writer.writerows(rows)
+
+
+def _format(value: Any) -> str:
+    """Format a report value for the HTML tables."""
+    if isinstance(value, float):
+        return f"{value:,.4f}".rstrip("0").rstrip(".") if math.isfinite(value) else "∞"
+    return "" if value is None else str(value)
+
+
+def _render_table(rows: list[dict[str, Any]]) -> str:
+    """Render a list of records as an HTML table."""
+    if not rows:
+        return "<p>None</p>"
+    columns = list(dict.fromkeys(key for row in rows for key in row))
+    header = "".join(f"<th>{escape(column)}</th>" for column in columns)
+    body = "".join(
+        "<tr>"
+        + "".join(
+            f"<td>{escape(_format(row.get(column)))}</td>" for column in columns
+        )
+        + "</tr>"
+        for row in rows
+    )
+    return f"<table><tr>{header}</tr>{body}</table>"
+
+
+def _render_chart(values: list[float], color: str, title: str) -> str:
+    """Render a series as an inline SVG line chart."""
+    if len(values) < 2:
+        return ""
+    low, high = min(values), max(values)
+    span = high - low or 1.0
+    step = CHART_WIDTH / (len(values) - 1)
+    points = " ".join(
+        f"{index * step:.1f},{CHART_HEIGHT - (value - low) / span * CHART_HEIGHT:.1f}"
+        for index, value in enumerate(values)
+    )
+    return (
+        f"<h3>{escape(title)}</h3>"
+        f"<svg viewBox='0 0 {CHART_WIDTH} {CHART_HEIGHT}' width='{CHART_WIDTH}' "
+        f"height='{CHART_HEIGHT}' preserveAspectRatio='none'>"
+        f"<polyline fill='none' stroke='{color}' stroke-width='1.5' points='{points}'/>"
+        "</svg>"
+        f"<p class='axis'>{escape(_format(low))} – {escape(_format(high))}</p>"
+    )
+
+
+def _render_html(result: BacktestResult) -> str:
+    """Render a self-contained HTML report."""
+    summary = "".join(
+        f"<tr><th>{escape(str(key))}</th><td>{escape(_format(value))}</td></tr>"
+        for key, value in result.to_dict().items()
+    )
+    equities = [equity for _, equity in result.equity_curve]
+    peak = result.initial_capital
+    drawdowns = []
+    for equity in equities:
+        peak = max(peak, equity)
+        drawdowns.append(-(peak - equity) / peak * 100 if peak > 0 else 0.0)
+
+    return (
+        "<!DOCTYPE html>\n<html><head><meta charset='utf-8'>"
+        "<title>Backtest report</title>"
+        f"<style>{STYLE}</style></head><body><h1>Backtest report</h1>"
+        f"<h2>Summary</h2><table>{summary}</table>"
+        "<h2>Charts</h2>"
+        + _render_chart(equities, "#1f77b4", "Equity")
+        + _render_chart(drawdowns, "#d62728", "Drawdown (%)")
+        + "<h2>Trades</h2>"
+        + _render_table([trip.to_dict() for trip in result.round_trips])
+        + "<h2>Fills</h2>"
+        + _render_table(result.trades)
+        + "</body></html>\n"
+    )
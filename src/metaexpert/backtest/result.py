@@ -0,0 +1,75 @@
+"""Backtest result."""
+
+from dataclasses import dataclass, field
+from datetime import datetime
+from functools import cached_property
+from typing import Any
+
+from .metrics import Metrics, RoundTrip, get_metrics, get_round_trips
+
+
+@dataclass
+class BacktestResult:
+    """Outcome of a backtest run.
+
+    `trades` holds the fills of the simulated broker; `round_trips` and
+    `metrics` are derived from them and from the equity curve on first use.
+    `funding` is the net funding received by the futures positions.
+    """
+
+    initial_capital: float
+    final_equity: float
+    start: datetime | None = None
+    end: datetime | None = None
+    bars: int = 0
+    fitness: float | None = None
+    trades: list[dict[str, Any]] = field(default_factory=list)
+    equity_curve: list[tuple[datetime, float]] = field(default_factory=list)
+    funding: float = 0.0
+
+    @property
+    def net_profit(self) -> float:
+        """Final equity minus the initial capital."""
+        return self.final_equity - self.initial_capital
+
+    @property
+    def return_pct(self) -> float:
+        """Net profit as a percentage of the initial capital."""
+        if self.initial_capital == 0:
+            return 0.0
+        return self.net_profit / self.initial_capital * 100
+
+    @property
+    def liquidations(self) -> int:
+        """Number of fills that liquidated a position."""
+        return sum(1 for trade in self.trades if trade.get("liquidation"))
+
+    @cached_property
+    def round_trips(self) -> list[RoundTrip]:
+        """Positions opened and closed during the run."""
+        return get_round_trips(self.trades)
+
+    @cached_property
+    def metrics(self) -> Metrics:
+        """Performance metrics of the run."""
+        return get_metrics(
+            self.initial_capital,
+            self.equity_curve,
+            self.round_trips,
+            start=self.start,
+            end=self.end,
+        )
+
+    def to_dict(self) -> dict[str, Any]:
+        """Return a plain dictionary summary of the run, with its metrics."""
+        return {
+            "initial_capital": self.initial_capital,
+            "final_equity": self.final_equity,
+            "start": self.start.isoformat() if self.start else None,
+            "end": self.end.isoformat() if self.end else None,
+            "bars": self.bars,
+            "fills": len(self.trades),
+            "funding": self.funding,
+            "liquidations": self.liquidations,
+            "fitness": self.fitness,
+        } | self.metrics.to_dict()
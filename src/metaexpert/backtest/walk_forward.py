@@ -0,0 +1,420 @@
+"""Walk-forward analysis: optimization validated out of sample."""
+
+import os
+from collections import Counter
+from dataclasses import dataclass, field, replace
+from datetime import datetime, timedelta
+from functools import cached_property
+from pathlib import Path
+from statistics import mean, pstdev
+from typing import Any
+
+from metaexpert.config import (
+    GENETIC_MUTATION_RATE,
+    GENETIC_POPULATION,
+    OPTIMIZATION_PASSES,
+    WALK_FORWARD_OUT_OF_SAMPLE_PCT,
+    WALK_FORWARD_WINDOWS,
+)
+from metaexpert.core import OptimizationMethod
+from metaexpert.logger import MetaLogger as Logger, get_logger
+from metaexpert.utils.time import to_utc
+
+from .metrics import Metrics, RoundTrip, get_metrics
+from .optimizer import OptimizationPass, Optimizer, Parameter, run_backtest
+
+
+@dataclass(frozen=True)
+class WalkForwardWindow:
+    """In-sample period and the out-of-sample period following it."""
+
+    in_sample_start: datetime
+    in_sample_end: datetime
+    out_of_sample_start: datetime
+    out_of_sample_end: datetime
+
+    def to_dict(self) -> dict[str, Any]:
+        """Convert the window to a dictionary."""
+        return {
+            "in_sample_start": self.in_sample_start.isoformat(),
+            "in_sample_end": self.in_sample_end.isoformat(),
+            "out_of_sample_start": self.out_of_sample_start.isoformat(),
+            "out_of_sample_end": self.out_of_sample_end.isoformat(),
+        }
+
+
+@dataclass
+class WalkForwardStep:
+    """Optimization of an in-sample period and the test of its best parameters."""
+
+    window: WalkForwardWindow
+    in_sample: OptimizationPass | None = None  # Best pass, None if every pass failed
+    out_of_sample: OptimizationPass | None = None
+
+    @property
+    def parameters(self) -> dict[str, Any]:
+        """Parameters selected in sample, empty if every pass failed."""
+        return self.in_sample.parameters if self.in_sample else {}
+
+    def to_dict(self) -> dict[str, Any]:
+        """Row of the windows table."""
+        out_of_sample = self.out_of_sample or OptimizationPass({})
+        error = out_of_sample.error
+        if self.in_sample is None:
+            error = "Every in-sample pass failed"
+        return (
+            self.window.to_dict()
+            | self.parameters
+            | {
+                "in_sample_fitness": self.in_sample.fitness if self.in_sample else None,
+                "out_of_sample_fitness": out_of_sample.fitness,
+                "return_pct": out_of_sample.metrics.get("return_pct"),
+                "max_drawdown_pct": out_of_sample.metrics.get("max_drawdown_pct"),
+                "trades": out_of_sample.metrics.get("trades"),
+                "error": error,
+            }
+        )
+
+
+@dataclass(frozen=True)
+class ParameterStability:
+    """Values of a parameter selected across the walk-forward windows.
+
+    Stable parameters are selected again and again; values jumping from
+    one window to the next hint at an overfit optimum.
+    """
+
+    name: str
+    values: tuple[Any, ...]
+
+    @property
+    def mode(self) -> Any:
+        """Most often selected value."""
+        return Counter(self.values).most_common(1)[0][0] if self.values else None
+
+    @property
+    def mode_pct(self) -> float:
+        """Share of the windows selecting the most frequent value, in percent."""
+        if not self.values:
+            return 0.0
+        return Counter(self.values).most_common(1)[0][1] / len(self.values) * 100
+
+    @property
+    def mean(self) -> float | None:
+        """Average of a numeric parameter."""
+        numbers = self._get_numbers()
+        return mean(numbers) if numbers else None
+
+    @property
+    def stdev(self) -> float | None:
+        """Standard deviation of a numeric parameter."""
+        numbers = self._get_numbers()
+        return pstdev(numbers) if numbers else None
+
+    def to_dict(self) -> dict[str, Any]:
+        """Row of the stability table."""
+        return {
+            "parameter": self.name,
+            "values": list(self.values),
+            "mode": self.mode,
+            "mode_pct": self.mode_pct,
+            "mean": self.mean,
+            "stdev": self.stdev,
+        }
+
+    def _get_numbers(self) -> list[float]:
+        """Values as numbers, empty if any of them is not numeric."""
+        if not self.values or not all(
+            isinstance(item, int | float) and not isinstance(item, bool)
+            for item in self.values
+        ):
+            return []
+        return [float(item) for item in self.values]
+
+
+@dataclass
+class WalkForwardResult:
+    """Outcome of a walk-forward analysis.
+
+    The equity curve stitches the out-of-sample backtests together: each
+    one is scaled to start from the final equity of the previous one, as
+    if the expert had traded the out-of-sample periods in a row with the
+    parameters selected before each of them.
+    """
+
+    steps: list[WalkForwardStep]
+    initial_capital: float
+    equity_curve: list[tuple[datetime, float]] = field(default_factory=list)
+    round_trips: list[RoundTrip] = field(default_factory=list)
+
+    @property
+    def final_equity(self) -> float:
+        """Equity at the end of the last out-of-sample period."""
+        return self.equity_curve[-1][1] if self.equity_curve else self.initial_capital
+
+    @cached_property
+    def metrics(self) -> Metrics:
+        """Performance metrics of the stitched out-of-sample periods."""
+        if not self.steps:
+            return Metrics()
+        return get_metrics(
+            self.initial_capital,
+            self.equity_curve,
+            self.round_trips,
+            start=self.steps[0].window.out_of_sample_start,
+            end=self.steps[-1].window.out_of_sample_end,
+        )
+
+    @cached_property
+    def stability(self) -> list[ParameterStability]:
+        """Values selected for each parameter across the windows."""
+        names = list(
+            dict.fromkeys(name for step in self.steps for name in step.parameters)
+        )
+        return [
+            ParameterStability(
+                name,
+                tuple(
+                    step.parameters[name]
+                    for step in self.steps
+                    if name in step.parameters
+                ),
+            )
+            for name in names
+        ]
+
+    def to_dict(self) -> dict[str, Any]:
+        """Return a plain dictionary summary of the analysis, with its metrics."""
+        return {
+            "initial_capital": self.initial_capital,
+            "final_equity": self.final_equity,
+            "windows": len(self.steps),
+            "failed_windows": sum(
+                step.out_of_sample is None or step.out_of_sample.error is not None
+                for step in self.steps
+            ),
+        } | self.metrics.to_dict()
+
+
+def get_windows(
+    start: str | datetime,
+    end: str | datetime,
+    *,
+    windows: int = WALK_FORWARD_WINDOWS,
+    out_of_sample_pct: float = WALK_FORWARD_OUT_OF_SAMPLE_PCT,
+    anchored: bool = False,
+) -> list[WalkForwardWindow]:
+    """Split a date range into walk-forward windows.
+
+    The out-of-sample periods follow each other up to the end of the range,
+    each one right after its in-sample period. Rolling in-sample periods
+    keep the same length; anchored ones all begin at the start of the range.
+
+    Args:
+        start (str | datetime): Start of the range.
+        end (str | datetime): End of the range, excluded.
+        windows (int): Number of windows.
+        out_of_sample_pct (float): Out-of-sample share of a rolling window, in percent.
+        anchored (bool): Whether the in-sample periods begin at the start of the range.
+
+    Returns:
+        list[WalkForwardWindow]: The windows, in time order.
+
+    Raises:
+        ValueError: If the range or the split is invalid.
+    """
+    start, end = to_utc(start), to_utc(end)
+    if end <= start:
+        raise ValueError(f"Invalid walk-forward range: {start} to {end}")
+    if windows < 1:
+        raise ValueError(f"Invalid number of walk-forward windows: {windows}")
+    if not 0 < out_of_sample_pct < 100:
+        raise ValueError(f"Invalid out-of-sample share: {out_of_sample_pct}%")
+
+    ratio = out_of_sample_pct / 100
+    step = (end - start) / (windows + (1 - ratio) / ratio)
+    step = timedelta(seconds=int(step.total_seconds()))
+    if step <= timedelta(0):
+        raise ValueError(f"The walk-forward range is too short for {windows} windows")
+    in_sample = end - start - step * windows
+
+    result = []
+    for index in range(windows):
+        out_of_sample_start = start + in_sample + step * index
+        result.append(
+            WalkForwardWindow(
+                in_sample_start=start if anchored else out_of_sample_start - in_sample,
+                in_sample_end=out_of_sample_start,
+                out_of_sample_start=out_of_sample_start,
+                out_of_sample_end=out_of_sample_start + step,
+            )
+        )
+    return result
+
+
+class WalkForward:
+    """Walk-forward analysis of the inputs of an expert.
+
+    Every in-sample period is optimized with an `Optimizer`, then the best
+    parameters are backtested on the out-of-sample period that follows,
+    which the optimization never saw. The out-of-sample backtests are
+    stitched into one equity curve, and the parameters selected by the
+    windows are compared to measure their stability.
+    """
+
+    def __init__(
+        self,
+        script: str | Path,
+        parameters: list[Parameter],
+        *,
+        start: str | datetime,
+        end: str | datetime,
+        windows: int = WALK_FORWARD_WINDOWS,
+        out_of_sample_pct: float = WALK_FORWARD_OUT_OF_SAMPLE_PCT,
+        anchored: bool = False,
+        method: OptimizationMethod = OptimizationMethod.GRID,
+        passes: int = OPTIMIZATION_PASSES,
+        workers: int | None = None,
+        initial_capital: float | None = None,
+        env: dict[str, str] | None = None,
+        population: int = GENETIC_POPULATION,
+        mutation_rate: float = GENETIC_MUTATION_RATE,
+        seed: int | None = None,
+    ) -> None:
+        """Initialize the analysis.
+
+        Args:
+            script (str | Path): Expert script.
+            parameters (list[Parameter]): Parameters to optimize.
+            start (str | datetime): Start of the analysed range.
+            end (str | datetime): End of the analysed range, excluded.
+            windows (int): Number of windows.
+            out_of_sample_pct (float): Out-of-sample share of a rolling window, in percent.
+            anchored (bool): Whether the in-sample periods begin at the start of the range.
+            method (OptimizationMethod): Search method of the optimizations.
+            passes (int): Maximum number of passes of the random and genetic searches.
+            workers (int | None): Passes run in parallel, the number of CPUs by default.
+            initial_capital (float | None): Initial capital, the one of the script by default.
+            env (dict[str, str] | None): Environment of the passes, the current one by default.
+            population (int): Combinations per generation of the genetic search.
+            mutation_rate (float): Probability that the genetic search mutates a parameter.
+            seed (int | None): Seed of the random and genetic searches.
+
+        Raises:
+            ValueError: If the windows or the parameters are invalid.
+        """
+        if not parameters:
+            raise ValueError("At least one parameter to optimize is required")
+        self.script: Path = Path(script).resolve()
+        self.parameters: list[Parameter] = parameters
+        self.windows: list[WalkForwardWindow] = get_windows(
+            start,
+            end,
+            windows=windows,
+            out_of_sample_pct=out_of_sample_pct,
+            anchored=anchored,
+        )
+        self.method: OptimizationMethod = method
+        self.passes: int = passes
+        self.workers: int | None = workers
+        self.initial_capital: float | None = initial_capital
+        self.env: dict[str, str] = dict(os.environ) if env is None else env
+        self.population: int = population
+        self.mutation_rate: float = mutation_rate
+        self.seed: int | None = seed
+        self.logger: Logger = get_logger("WalkForward")
+
+    def run(self) -> WalkForwardResult:
+        """Optimize and test every window, in time order."""
+        steps = []
+        for number, window in enumerate(self.windows, start=1):
+            self.logger.info(
+                "Window %d/%d: optimizing %s to %s",
+                number,
+                len(self.windows),
+                window.in_sample_start.isoformat(),
+                window.in_sample_end.isoformat(),
+            )
+            ranked = self.get_optimizer(window).run()
+            if not ranked or ranked[0].error is not None:
+                self.logger.warning("Window %d: every in-sample pass failed", number)
+                steps.append(WalkForwardStep(window))
+                continue
+
+            best = ranked[0]
+            out_of_sample = self.run_pass(best.parameters, window)
+            self.logger.info(
+                "Window %d: %s, in-sample fitness %s, out-of-sample fitness %s",
+                number,
+                best.parameters,
+                best.fitness,
+                out_of_sample.fitness,
+            )
+            steps.append(WalkForwardStep(window, best, out_of_sample))
+        return self._stitch(steps)
+
+    def get_optimizer(self, window: WalkForwardWindow) -> Optimizer:
+        """Optimizer of the in-sample period of a window."""
+        return Optimizer(
+            self.script,
+            self.parameters,
+            method=self.method,
+            passes=self.passes,
+            workers=self.workers,
+            start=window.in_sample_start.isoformat(),
+            end=window.in_sample_end.isoformat(),
+            initial_capital=self.initial_capital,
+            env=self.env,
+            population=self.population,
+            mutation_rate=self.mutation_rate,
+            seed=self.seed,
+        )
+
+    def run_pass(
+        self, parameters: dict[str, Any], window: WalkForwardWindow
+    ) -> OptimizationPass:
+        """Backtest the parameters on the out-of-sample period of a window."""
+        return run_backtest(
+            self.script,
+            parameters,
+            start=window.out_of_sample_start.isoformat(),
+            end=window.out_of_sample_end.isoformat(),
+            initial_capital=self.initial_capital,
+            env=self.env,
+            details=True,
+        )
+
+    def _stitch(self, steps: list[WalkForwardStep]) -> WalkForwardResult:
+        """Chain the out-of-sample backtests into one equity curve.
+
+        A failed out-of-sample backtest leaves the equity unchanged over
+        its period.
+        """
+        tested = [
+            step.out_of_sample
+            for step in steps
+            if step.out_of_sample and step.out_of_sample.error is None
+        ]
+        initial_capital = self.initial_capital
+        if initial_capital is None:
+            initial_capital = (
+                float(tested[0].metrics.get("initial_capital", 0.0)) if tested else 0.0
+            )
+
+        result = WalkForwardResult(steps, initial_capital)
+        equity = initial_capital
+        for item in tested:
+            start_equity = float(item.metrics.get("initial_capital") or 0.0)
+            if start_equity <= 0:
+                continue
+            scale = equity / start_equity
+            result.equity_curve.extend(
+                (time, value * scale) for time, value in item.equity_curve
+            )
+            result.round_trips.extend(
+                replace(trip, quantity=trip.quantity * scale, pnl=trip.pnl * scale)
+                for trip in item.round_trips
+            )
+            final_equity = float(item.metrics.get("final_equity", start_equity))
+            equity = final_equity * scale
+        return result
@@ -0,0 +1,36 @@
+"""Command line interface of the MetaExpert library."""
+
+import typer
+
+app = typer.Typer(
+    name="metaexpert",
+    help="MetaExpert command line interface.",
+    no_args_is_help=True,
+)
+
+
+def register_commands() -> None:
+    """Register all CLI commands."""
+    from metaexpert.cli.commands import (
+        backtest,
+        list,
+        new,
+        run,
+        status,
+        stop,
+        version,
+    )
+
+    app.command(name="new")(new.cmd_new)
+    app.command(name="run")(run.cmd_run)
+    app.command(name="stop")(stop.cmd_stop)
+    app.command(name="status")(status.cmd_status)
+    app.command(name="list")(list.cmd_list)
+    app.command(name="backtest")(backtest.cmd_backtest)
+    app.command(name="version")(version.cmd_version)
+
+
+def main() -> None:
+    """Run the command line interface."""
+    register_commands()
+    app()
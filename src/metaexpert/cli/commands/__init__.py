@@ -0,0 +1 @@
+"""Commands of the MetaExpert command line interface."""
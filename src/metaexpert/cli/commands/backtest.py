@@ -0,0 +1,321 @@
+"""Command `backtest`: backtest a trading strategy."""
+
+from concurrent.futures import ThreadPoolExecutor
+from datetime import datetime
+from pathlib import Path
+from typing import Annotated
+
+import typer
+
+from metaexpert.backtest import (
+    FillModel,
+    OptimizationPass,
+    Optimizer,
+    Parameter,
+    WalkForward,
+    run_backtest,
+    write_optimization_report,
+    write_walk_forward_report,
+)
+from metaexpert.cli.core.output import OutputFormatter
+from metaexpert.cli.core.process import build_env, resolve_script, run_script
+from metaexpert.config import (
+    DEFAULT_OPTIMIZATION_METHOD,
+    DEFAULT_REPORT_FORMAT,
+    ENV_BACKTEST_END,
+    ENV_BACKTEST_START,
+    ENV_FILL_MODEL,
+    ENV_FUNDING_FILE,
+    ENV_INITIAL_CAPITAL,
+    ENV_REPORT_FILE,
+    ENV_TRADE_MODE,
+    INITIAL_CAPITAL,
+    OPTIMIZATION_PASSES,
+    REPORT_DIRECTORY,
+    REPORT_FORMAT_CSV,
+    REPORT_FORMAT_HTML,
+    REPORT_FORMAT_JSON,
+    TRADE_MODE_BACKTEST,
+    WALK_FORWARD_OUT_OF_SAMPLE_PCT,
+    WALK_FORWARD_WINDOWS,
+)
+from metaexpert.core import OptimizationMethod
+
+
+def cmd_backtest(
+    expert_paths: Annotated[
+        list[Path],
+        typer.Argument(help="Path to expert file (several with --compare)."),
+    ],
+    start_date: Annotated[
+        str | None, typer.Option("--start-date", "-s", help="Start date (YYYY-MM-DD).")
+    ] = None,
+    end_date: Annotated[
+        str | None, typer.Option("--end-date", "-e", help="End date (YYYY-MM-DD).")
+    ] = None,
+    capital: Annotated[
+        float, typer.Option("--capital", "-c", help="Initial capital.")
+    ] = INITIAL_CAPITAL,
+    optimize: Annotated[
+        bool, typer.Option("--optimize", "-o", help="Optimize parameters.")
+    ] = False,
+    optimize_params: Annotated[
+        str | None,
+        typer.Option(
+            "--optimize-params",
+            help="Parameters to optimize (comma-separated name=start:stop:step or name=a|b|c).",
+        ),
+    ] = None,
+    method: Annotated[
+        str,
+        typer.Option(
+            "--method", "-m", help="Optimization method (grid, random, genetic)."
+        ),
+    ] = DEFAULT_OPTIMIZATION_METHOD,
+    passes: Annotated[
+        int,
+        typer.Option(
+            "--passes", help="Maximum passes of the random and genetic searches."
+        ),
+    ] = OPTIMIZATION_PASSES,
+    workers: Annotated[
+        int | None,
+        typer.Option(
+            "--workers", "-w", help="Passes run in parallel (CPU count by default)."
+        ),
+    ] = None,
+    walk_forward: Annotated[
+        bool,
+        typer.Option(
+            "--walk-forward", help="Optimize in sample and test out of sample."
+        ),
+    ] = False,
+    windows: Annotated[
+        int, typer.Option("--windows", help="Number of walk-forward windows.")
+    ] = WALK_FORWARD_WINDOWS,
+    oos_pct: Annotated[
+        float,
+        typer.Option(
+            "--oos-pct", help="Out-of-sample share of a walk-forward window (%)."
+        ),
+    ] = WALK_FORWARD_OUT_OF_SAMPLE_PCT,
+    anchored: Annotated[
+        bool,
+        typer.Option(
+            "--anchored", help="Start every in-sample window at the start date."
+        ),
+    ] = False,
+    compare: Annotated[
+        bool, typer.Option("--compare", help="Compare strategies.")
+    ] = False,
+    fill_model: Annotated[
+        str | None,
+        typer.Option(
+            "--fill-model",
+            help="Fill model options (e.g. path=ohlc,slippage=volatility:0.2,volume_pct=10,latency_ms=250).",
+        ),
+    ] = None,
+    funding_file: Annotated[
+        Path | None,
+        typer.Option(
+            "--funding-file",
+            help="CSV file of the funding rates (time, rate and optional mark_price columns), {symbol} for one per symbol.",
+        ),
+    ] = None,
+    report_format: Annotated[
+        str,
+        typer.Option("--report-format", "-f", help="Report format (html, json, csv)."),
+    ] = DEFAULT_REPORT_FORMAT,
+) -> None:
+    """Backtest a trading strategy, optimize its parameters or compare strategies."""
+    optimize = optimize or walk_forward
+    output = OutputFormatter()
+    report_format = report_format.lower()
+    if report_format not in (REPORT_FORMAT_HTML, REPORT_FORMAT_JSON, REPORT_FORMAT_CSV):
+        output.error(f"Unsupported report format: {report_format}")
+        raise typer.Exit(code=1)
+    if (optimize or optimize_params) and compare:
+        output.error("--optimize and --compare cannot be combined")
+        raise typer.Exit(code=1)
+    if len(expert_paths) > 1 and not compare:
+        output.error("Several experts can only be backtested with --compare")
+        raise typer.Exit(code=1)
+
+    try:
+        scripts = [resolve_script(path) for path in expert_paths]
+    except FileNotFoundError as e:
+        output.error(str(e))
+        raise typer.Exit(code=1) from e
+    try:
+        FillModel.parse(fill_model)
+    except ValueError as e:
+        output.error(str(e))
+        raise typer.Exit(code=1) from e
+    # Every backtest process reads the fill model and funding from the environment
+    env_overrides = {ENV_FILL_MODEL: fill_model} if fill_model else {}
+    if funding_file is not None:
+        env_overrides[ENV_FUNDING_FILE] = str(funding_file.resolve())
+
+    timestamp = datetime.now().strftime("%Y%m%d_%H%M%S")
+    directory = scripts[0].parent / REPORT_DIRECTORY
+    if compare:
+        _compare(
+            output,
+            scripts,
+            directory / f"compare_{timestamp}.{report_format}",
+            start=start_date,
+            end=end_date,
+            capital=capital,
+            workers=workers,
+            overrides=env_overrides,
+        )
+        return
+    if optimize or optimize_params:
+        if not optimize_params:
+            output.error("--optimize requires --optimize-params")
+            raise typer.Exit(code=1)
+        if walk_forward and not (start_date and end_date):
+            output.error("--walk-forward requires --start-date and --end-date")
+            raise typer.Exit(code=1)
+        try:
+            parameters = [Parameter.parse(item) for item in optimize_params.split(",")]
+            options = {
+                "method": OptimizationMethod.get_optimization_method_from(method),
+                "passes": passes,
+                "workers": workers,
+                "initial_capital": capital,
+                "env": build_env(scripts[0], overrides=env_overrides),
+            }
+            if walk_forward:
+                analysis = WalkForward(
+                    scripts[0],
+                    parameters,
+                    start=start_date,
+                    end=end_date,
+                    windows=windows,
+                    out_of_sample_pct=oos_pct,
+                    anchored=anchored,
+                    **options,
+                )
+            else:
+                optimizer = Optimizer(
+                    scripts[0], parameters, start=start_date, end=end_date, **options
+                )
+        except ValueError as e:
+            output.error(str(e))
+            raise typer.Exit(code=1) from e
+
+        stem = scripts[0].stem
+        if walk_forward:
+            _walk_forward(
+                output,
+                analysis,
+                directory / f"walk_forward_{stem}_{timestamp}.{report_format}",
+            )
+        else:
+            _optimize(
+                output,
+                optimizer,
+                directory / f"optimize_{stem}_{timestamp}.{report_format}",
+            )
+        return
+
+    script_path = scripts[0]
+    report = directory / f"backtest_{script_path.stem}_{timestamp}.{report_format}"
+    overrides = env_overrides | {
+        ENV_TRADE_MODE: TRADE_MODE_BACKTEST,
+        ENV_INITIAL_CAPITAL: str(capital),
+        ENV_REPORT_FILE: str(report),
+    }
+    if start_date:
+        overrides[ENV_BACKTEST_START] = start_date
+    if end_date:
+        overrides[ENV_BACKTEST_END] = end_date
+
+    output.info(f"Backtesting {script_path}")
+    code = run_script(script_path, build_env(script_path, overrides=overrides))
+    if code != 0:
+        output.error(f"Backtest exited with code {code}")
+        raise typer.Exit(code=code)
+    if not report.exists():
+        output.error("The expert did not produce a backtest report")
+        raise typer.Exit(code=1)
+
+    output.success(f"Report written to {report}")
+
+
+def _optimize(output: OutputFormatter, optimizer: Optimizer, report: Path) -> None:
+    """Run an optimization and write its results table."""
+    output.info(
+        f"Optimizing {optimizer.script} over {optimizer.combinations} combinations"
+    )
+    results = optimizer.run()
+    write_optimization_report(results, report)
+    if not results or results[0].error is not None:
+        output.error(f"Every pass failed, see {report}")
+        raise typer.Exit(code=1)
+
+    best = results[0]
+    output.success(f"Best fitness {best.fitness} with {best.parameters}")
+    output.success(f"Results written to {report}")
+
+
+def _walk_forward(
+    output: OutputFormatter, analysis: WalkForward, report: Path
+) -> None:
+    """Run a walk-forward analysis and write its report."""
+    output.info(
+        f"Walk-forward analysis of {analysis.script} "
+        f"over {len(analysis.windows)} windows"
+    )
+    result = analysis.run()
+    write_walk_forward_report(result, report)
+    if not result.equity_curve:
+        output.error(f"Every out-of-sample backtest failed, see {report}")
+        raise typer.Exit(code=1)
+
+    metrics = result.metrics
+    output.success(
+        f"Out-of-sample return {metrics.return_pct:.2f}%, "
+        f"max drawdown {metrics.max_drawdown_pct:.2f}%, {metrics.trades} trades"
+    )
+    for item in result.stability:
+        output.info(
+            f"{item.name}: {list(item.values)}, {item.mode} selected "
+            f"in {item.mode_pct:.0f}% of the windows"
+        )
+    output.success(f"Report written to {report}")
+
+
+def _compare(
+    output: OutputFormatter,
+    scripts: list[Path],
+    report: Path,
+    *,
+    start: str | None,
+    end: str | None,
+    capital: float,
+    workers: int | None,
+    overrides: dict[str, str],
+) -> None:
+    """Backtest several experts in parallel and rank them by fitness."""
+    output.info(f"Comparing {len(scripts)} experts")
+
+    def run(script: Path) -> OptimizationPass:
+        result = run_backtest(
+            script,
+            start=start,
+            end=end,
+            initial_capital=capital,
+            env=build_env(script, overrides=overrides),
+        )
+        result.parameters = {"expert": str(script)}
+        return result
+
+    with ThreadPoolExecutor(max_workers=workers) as executor:
+        results = Optimizer.rank(list(executor.map(run, scripts)))
+    write_optimization_report(results, report, title="Strategy comparison")
+    for rank, result in enumerate(results, start=1):
+        status = result.error or f"fitness {result.fitness}"
+        output.info(f"{rank}. {result.parameters['expert']}: {status}")
+    output.success(f"Comparison written to {report}")
@@ -0,0 +1,38 @@
+"""Command `list`: list the detached trading experts."""
+
+from typing import Annotated
+
+import typer
+
+from metaexpert.cli.core.output import OutputFormatter, format_state
+from metaexpert.config import PROCESS_STATUS_DEAD, PROCESS_STATUS_STOPPED
+from metaexpert.process import Supervisor
+
+COLUMNS = ("name", "status", "pid", "trade_mode", "exchange", "symbol", "uptime")
+
+
+def cmd_list(
+    show_all: Annotated[
+        bool, typer.Option("--all", "-a", help="Include stopped experts.")
+    ] = False,
+    output_format: Annotated[
+        str, typer.Option("--format", "-f", help="Output format (table, json).")
+    ] = "table",
+) -> None:
+    """List the detached trading experts."""
+    output = OutputFormatter()
+    states = [
+        state
+        for state in Supervisor().list()
+        if show_all
+        or state.status not in (PROCESS_STATUS_STOPPED, PROCESS_STATUS_DEAD)
+    ]
+
+    if output_format == "json":
+        output.json([state.to_dict() for state in states])
+        return
+    if not states:
+        output.info("No running experts")
+        return
+
+    output.table([format_state(state) for state in states], COLUMNS)
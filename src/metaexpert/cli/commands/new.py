@@ -0,0 +1,60 @@
+"""Command `new`: create a new expert project."""
+
+from pathlib import Path
+from typing import Annotated
+
+import typer
+
+from metaexpert.cli.core.output import OutputFormatter
+from metaexpert.cli.core.templates import create_project
+
+
+def cmd_new(
+    project_name: Annotated[
+        str, typer.Argument(help="Name of the new expert project.")
+    ],
+    exchange: Annotated[
+        str, typer.Option("--exchange", "-e", help="Target exchange.")
+    ] = "binance",
+    strategy: Annotated[
+        str,
+        typer.Option(
+            "--strategy", "-s", help="Strategy type (ema, rsi, macd, template)."
+        ),
+    ] = "template",
+    market_type: Annotated[
+        str,
+        typer.Option("--market-type", help="Market type (spot, futures, options)."),
+    ] = "futures",
+    output_dir: Annotated[
+        Path | None, typer.Option("--output-dir", "-o", help="Output directory.")
+    ] = None,
+    force: Annotated[
+        bool,
+        typer.Option(
+            "--force",
+            "-f",
+            help="Overwrite the generated files of an existing directory.",
+        ),
+    ] = False,
+) -> None:
+    """Create a new expert project."""
+    output = OutputFormatter()
+    try:
+        project = create_project(
+            project_name,
+            output_dir or Path.cwd(),
+            strategy=strategy,
+            exchange=exchange,
+            market_type=market_type,
+            force=force,
+        )
+    except FileExistsError as e:
+        output.error(f"{e} (use --force to overwrite)")
+        raise typer.Exit(code=1) from e
+    except ValueError as e:
+        output.error(str(e))
+        raise typer.Exit(code=1) from e
+
+    output.success(f"Created expert project '{project_name}' in {project}")
+    output.info(f"Next: cd {project} && metaexpert run")
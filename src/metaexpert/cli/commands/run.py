@@ -0,0 +1,61 @@
+"""Command `run`: run a trading expert."""
+
+from pathlib import Path
+from typing import Annotated
+
+import typer
+
+from metaexpert.cli.core.output import OutputFormatter
+from metaexpert.cli.core.process import build_env, resolve_script, run_script
+from metaexpert.config import DEFAULT_SCRIPT
+from metaexpert.core import ProcessError
+from metaexpert.process import Supervisor
+
+
+def cmd_run(
+    project_path: Annotated[
+        Path, typer.Argument(help="Path to expert project.")
+    ] = Path("."),
+    script: Annotated[
+        str, typer.Option("--script", help="Script to run.")
+    ] = DEFAULT_SCRIPT,
+    env_file: Annotated[
+        Path | None,
+        typer.Option("--env-file", "-e", help="Environment file to use."),
+    ] = None,
+    detach: Annotated[
+        bool, typer.Option("--detach", "-d", help="Run in the background.")
+    ] = False,
+    name: Annotated[
+        str | None,
+        typer.Option(
+            "--name", "-n", help="Name of a detached expert (project name by default)."
+        ),
+    ] = None,
+) -> None:
+    """Run a trading expert."""
+    output = OutputFormatter()
+    try:
+        script_path = resolve_script(project_path, script)
+        env = build_env(script_path, env_file)
+    except FileNotFoundError as e:
+        output.error(str(e))
+        raise typer.Exit(code=1) from e
+
+    if detach:
+        try:
+            state = Supervisor().start(script_path, env, name)
+        except ProcessError as e:
+            output.error(str(e))
+            raise typer.Exit(code=1) from e
+        output.success(
+            f"Expert '{state.name}' started in the background (pid {state.pid})"
+        )
+        output.info(f"Logs: {state.log_file}")
+        return
+
+    output.info(f"Running {script_path}")
+    code = run_script(script_path, env)
+    if code != 0:
+        output.error(f"Expert exited with code {code}")
+        raise typer.Exit(code=code)
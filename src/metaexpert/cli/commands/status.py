@@ -0,0 +1,32 @@
+"""Command `status`: show the state of a detached trading expert."""
+
+from typing import Annotated
+
+import typer
+
+from metaexpert.cli.core.output import OutputFormatter, format_state
+from metaexpert.cli.core.process import resolve_expert_name
+from metaexpert.core import ProcessError
+from metaexpert.process import Supervisor
+
+
+def cmd_status(
+    name: Annotated[str, typer.Argument(help="Name or path of the expert.")],
+    output_format: Annotated[
+        str, typer.Option("--format", "-f", help="Output format (table, json).")
+    ] = "table",
+) -> None:
+    """Show the state of a detached trading expert."""
+    output = OutputFormatter()
+    try:
+        state = Supervisor().get_state(resolve_expert_name(name))
+    except ProcessError as e:
+        output.error(str(e))
+        raise typer.Exit(code=1) from e
+
+    if output_format == "json":
+        output.json(state.to_dict())
+        return
+
+    for key, value in format_state(state).items():
+        output.info(f"{key.replace('_', ' ').capitalize():<14}{value}")
@@ -0,0 +1,35 @@
+"""Command `stop`: stop a detached trading expert."""
+
+from typing import Annotated
+
+import typer
+
+from metaexpert.cli.core.output import OutputFormatter
+from metaexpert.cli.core.process import resolve_expert_name
+from metaexpert.config import DEINIT_REASON_USER_STOP, STOP_TIMEOUT
+from metaexpert.core import ProcessError
+from metaexpert.process import Supervisor
+
+
+def cmd_stop(
+    name: Annotated[str, typer.Argument(help="Name or path of the expert.")],
+    reason: Annotated[
+        str, typer.Option("--reason", "-r", help="Reason passed to on_deinit.")
+    ] = DEINIT_REASON_USER_STOP,
+    timeout: Annotated[
+        float, typer.Option("--timeout", "-t", help="Seconds to wait for the expert.")
+    ] = STOP_TIMEOUT,
+    force: Annotated[
+        bool, typer.Option("--force", "-f", help="Kill the expert after the timeout.")
+    ] = False,
+) -> None:
+    """Stop a detached trading expert."""
+    output = OutputFormatter()
+    name = resolve_expert_name(name)
+    try:
+        state = Supervisor().stop(name, reason, timeout=timeout, force=force)
+    except ProcessError as e:
+        output.error(str(e))
+        raise typer.Exit(code=1) from e
+
+    output.success(f"Expert '{name}' {state.status} ({state.stop_reason or reason})")
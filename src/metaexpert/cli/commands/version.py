@@ -0,0 +1,24 @@
+"""Command `version`: show the CLI version."""
+
+import platform
+from typing import Annotated
+
+import typer
+
+from metaexpert.__version__ import __version__
+from metaexpert.cli.core.output import OutputFormatter
+
+
+def cmd_version(
+    short: Annotated[
+        bool, typer.Option("--short", "-s", help="Show only version number.")
+    ] = False,
+) -> None:
+    """Show CLI version."""
+    output = OutputFormatter()
+    if short:
+        output.info(__version__)
+        return
+
+    output.info(f"MetaExpert {__version__}")
+    output.info(f"Python {platform.python_version()} ({platform.platform()})")
@@ -0,0 +1 @@
+"""Core components of the MetaExpert command line interface."""
@@ -0,0 +1,93 @@
+"""Console output of the command line interface."""
+
+import json
+from collections.abc import Sequence
+from datetime import UTC, datetime, timedelta
+from typing import Any
+
+import typer
+
+from metaexpert.process import ExpertState
+
+
+class OutputFormatter:
+    """Consistent console output for the CLI commands."""
+
+    def info(self, message: str) -> None:
+        """Print an informational message."""
+        typer.echo(message)
+
+    def success(self, message: str) -> None:
+        """Print a success message."""
+        typer.secho(message, fg=typer.colors.GREEN)
+
+    def warning(self, message: str) -> None:
+        """Print a warning message to stderr."""
+        typer.secho(f"Warning: {message}", fg=typer.colors.YELLOW, err=True)
+
+    def error(self, message: str) -> None:
+        """Print an error message to stderr."""
+        typer.secho(f"Error: {message}", fg=typer.colors.RED, err=True)
+
+    def json(self, data: Any) -> None:
+        """Print data as indented JSON."""
+        typer.echo(json.dumps(data, indent=2, default=str))
+
+    def table(self, rows: Sequence[dict[str, Any]], columns: Sequence[str]) -> None:
+        """Print rows as a plain-text table.
+
+        Args:
+            rows (Sequence[dict[str, Any]]): Rows to print.
+            columns (Sequence[str]): Keys of the rows to print, in order.
+        """
+        cells = [[str(row.get(column, "")) for column in columns] for row in rows]
+        widths = [
+            max([len(column), *(len(line[index]) for line in cells)])
+            for index, column in enumerate(columns)
+        ]
+        typer.secho(
+            "  ".join(
+                column.upper().ljust(width)
+                for column, width in zip(columns, widths, strict=True)
+            ),
+            bold=True,
+        )
+        for line in cells:
+            typer.echo(
+                "  ".join(
+                    cell.ljust(width) for cell, width in zip(line, widths, strict=True)
+                )
+            )
+
+
+def format_duration(duration: timedelta) -> str:
+    """Format a duration as `[<days>d ]HH:MM:SS`."""
+    seconds = max(int(duration.total_seconds()), 0)
+    days, seconds = divmod(seconds, 86400)
+    hours, seconds = divmod(seconds, 3600)
+    minutes, seconds = divmod(seconds, 60)
+    clock = f"{hours:02d}:{minutes:02d}:{seconds:02d}"
+    return f"{days}d {clock}" if days else clock
+
+
+def format_state(state: ExpertState) -> dict[str, str]:
+    """Human-readable fields of an expert state."""
+    uptime = state.uptime
+    heartbeat = (
+        f"{format_duration(datetime.now(UTC) - state.heartbeat)} ago"
+        if state.heartbeat is not None
+        else "-"
+    )
+    return {
+        "name": state.name,
+        "status": state.status,
+        "pid": str(state.pid),
+        "trade_mode": state.trade_mode or "-",
+        "exchange": state.exchange or "-",
+        "symbol": state.symbol or "-",
+        "timeframe": state.timeframe or "-",
+        "uptime": format_duration(uptime) if uptime is not None else "-",
+        "heartbeat": heartbeat,
+        "stop_reason": state.stop_reason or "-",
+        "log_file": state.log_file or "-",
+    }
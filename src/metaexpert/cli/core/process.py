@@ -0,0 +1,66 @@
+"""Expert processes."""
+
+import os
+import subprocess
+import sys
+from pathlib import Path
+
+from metaexpert.config import DEFAULT_ENV_FILE, DEFAULT_SCRIPT
+
+
+def resolve_script(path: Path, script: str = DEFAULT_SCRIPT) -> Path:
+    """Resolve the expert script of a project directory or a script path."""
+    script_path = path if path.is_file() else path / script
+    if not script_path.is_file():
+        raise FileNotFoundError(f"Expert script not found: {script_path}")
+    return script_path.resolve()
+
+
+def resolve_expert_name(name_or_path: str) -> str:
+    """Name of a detached expert, given its name or its project path."""
+    path = Path(name_or_path)
+    if path.exists():
+        return (path if path.is_dir() else path.parent).resolve().name
+    return name_or_path
+
+
+def load_env_file(path: Path) -> dict[str, str]:
+    """Read `KEY=VALUE` lines of an environment file (comments and blanks ignored)."""
+    env: dict[str, str] = {}
+    for line in path.read_text(encoding="utf-8").splitlines():
+        line = line.strip()
+        if not line or line.startswith("#") or "=" not in line:
+            continue
+        key, value = line.removeprefix("export ").split("=", 1)
+        env[key.strip()] = value.strip().strip("'\"")
+    return env
+
+
+def build_env(
+    script: Path,
+    env_file: Path | None = None,
+    overrides: dict[str, str] | None = None,
+) -> dict[str, str]:
+    """Environment of an expert process.
+
+    The project `.env` file (or `env_file`) is layered over the current
+    environment, then the overrides are applied.
+    """
+    env = dict(os.environ)
+    env_path = env_file or script.parent / DEFAULT_ENV_FILE
+    if env_path.is_file():
+        env |= load_env_file(env_path)
+    elif env_file is not None:
+        raise FileNotFoundError(f"Environment file not found: {env_file}")
+    return env | (overrides or {})
+
+
+def run_script(script: Path, env: dict[str, str]) -> int:
+    """Run an expert script in the foreground and return its exit code."""
+    try:
+        completed = subprocess.run(
+            [sys.executable, str(script)], cwd=script.parent, env=env, check=False
+        )
+    except KeyboardInterrupt:
+        return 130
+    return completed.returncode
@@ -0,0 +1,211 @@
+"""Expert project templates."""
+
+import re
+from pathlib import Path
+from string import Template
+
+from metaexpert.__version__ import __version__
+from metaexpert.config import DEFAULT_SCRIPT
+from metaexpert.core import MarketType
+
+TEMPLATE_DIRECTORY: Path = Path(__file__).parent.parent / "templates"
+EXCHANGE_DIRECTORY: Path = Path(__file__).parent.parent.parent / "exchanges"
+
+# Strategies available to `metaexpert new --strategy`
+STRATEGIES: dict[str, dict[str, str]] = {
+    "template": {
+        "description": "Trading expert template",
+        "overview": "Blank template: implement your trading logic in the event handlers of `main.py`.",
+    },
+    "ema": {
+        "description": "EMA crossover expert",
+        "overview": "Buys when the fast EMA (9) crosses above the slow EMA (21) and sells when it crosses below.",
+    },
+    "rsi": {
+        "description": "RSI reversal expert",
+        "overview": "Buys when the RSI (14) rises back above 30 and sells when it falls back below 70.",
+    },
+    "macd": {
+        "description": "MACD crossover expert",
+        "overview": "Buys when the MACD line (12, 26) crosses above its signal line (9) and sells when it crosses below.",
+    },
+}
+
+# Exchanges whose API requires a passphrase
+PASSPHRASE_EXCHANGES: set[str] = {"okx"}
+
+# Project files and the templates they are rendered from
+PROJECT_FILES: dict[str, str] = {
+    DEFAULT_SCRIPT: "main.py.tmpl",
+    "pyproject.toml": "pyproject.toml.tmpl",
+    ".env.example": "env.example.tmpl",
+    ".gitignore": "gitignore.tmpl",
+    "README.md": "README.md.tmpl",
+}
+
+# Column of the inline comments in main.py
+COMMENT_COLUMN = 36
+
+
+def get_exchanges() -> list[str]:
+    """Names of the exchanges with an adapter."""
+    return sorted(
+        path.name
+        for path in EXCHANGE_DIRECTORY.iterdir()
+        if (path / "__init__.py").is_file()
+    )
+
+
+def _pad(code: str) -> str:
+    """Spaces aligning an inline comment after a line of code."""
+    return " " * max(COMMENT_COLUMN - len(code), 1)
+
+
+def _read(name: str) -> Template:
+    """Read a template file."""
+    return Template((TEMPLATE_DIRECTORY / name).read_text(encoding="utf-8"))
+
+
+def get_context(
+    name: str,
+    *,
+    strategy: str = "template",
+    exchange: str = "binance",
+    market_type: str = "futures",
+) -> dict[str, str]:
+    """Validate the project settings and build the template variables.
+
+    Args:
+        name (str): Project name, used as strategy name and order comment.
+        strategy (str): Strategy type (ema, rsi, macd, template).
+        exchange (str): Target exchange.
+        market_type (str): Market type (spot, futures, options).
+
+    Returns:
+        dict[str, str]: Template variables.
+    """
+    strategy = strategy.lower().strip()
+    exchange = exchange.lower().strip()
+    market_type = market_type.lower().strip()
+
+    if strategy not in STRATEGIES:
+        raise ValueError(
+            f"Unknown strategy '{strategy}', expected one of: {', '.join(STRATEGIES)}"
+        )
+    if exchange not in get_exchanges():
+        raise ValueError(
+            f"Unsupported exchange '{exchange}', expected one of: {', '.join(get_exchanges())}"
+        )
+    if market_type not in [item.get_name() for item in MarketType]:
+        raise ValueError(f"Unsupported market type '{market_type}'")
+
+    env_prefix = exchange.upper()
+    has_passphrase = exchange in PASSPHRASE_EXCHANGES
+    slug = re.sub(r"[^a-z0-9]+", "_", name.lower()).strip("_") or "expert"
+    context = {
+        "project_name": name,
+        "package_name": slug.replace("_", "-"),
+        "strategy_name": name,
+        "strategy_description": STRATEGIES[strategy]["description"],
+        "strategy_overview": STRATEGIES[strategy]["overview"],
+        "comment": slug[:32],
+        "exchange": exchange,
+        "exchange_title": exchange.upper() if len(exchange) <= 4 else exchange.title(),
+        "exchange_pad": _pad(f'    exchange="{exchange}",'),
+        "market_type": market_type,
+        "market_type_pad": _pad(f'    market_type="{market_type}",'),
+        "timeframe": "1h",
+        "timeframe_pad": _pad('    timeframe="1h",'),
+        "env_prefix": env_prefix,
+        "api_passphrase": (
+            f'os.getenv("{env_prefix}_API_PASSPHRASE")' if has_passphrase else "None"
+        ),
+        "env_passphrase_line": (
+            f'{env_prefix}_API_PASSPHRASE="your_api_passphrase"\n'
+            if has_passphrase
+            else ""
+        ),
+        "allow_short": str(market_type != "spot"),
+        "metaexpert_version": __version__,
+    }
+
+    code = _read(f"strategies/{strategy}.py.tmpl").substitute(context).strip()
+    if strategy != "template":
+        helpers = _read("strategies/_trading.py.tmpl").substitute(context).strip()
+        code = f"{helpers}\n\n\n{code}"
+    context["strategy_code"] = code
+    return context
+
+
+def render_project(
+    name: str,
+    *,
+    strategy: str = "template",
+    exchange: str = "binance",
+    market_type: str = "futures",
+) -> dict[str, str]:
+    """Render the files of an expert project.
+
+    Returns:
+        dict[str, str]: Content of every project file, by file name.
+    """
+    context = get_context(
+        name, strategy=strategy, exchange=exchange, market_type=market_type
+    )
+    return {
+        file_name: _read(template).substitute(context)
+        for file_name, template in PROJECT_FILES.items()
+    }
+
+
+def create_project(
+    name: str,
+    output_dir: Path,
+    *,
+    strategy: str = "template",
+    exchange: str = "binance",
+    market_type: str = "futures",
+    force: bool = False,
+) -> Path:
+    """Create a new expert project directory.
+
+    Args:
+        name (str): Project name (also the directory name).
+        output_dir (Path): Directory in which the project is created.
+        strategy (str): Strategy type (ema, rsi, macd, template).
+        exchange (str): Target exchange.
+        market_type (str): Market type (spot, futures, options).
+        force (bool): Overwrite the generated files of an existing directory,
+            keeping the other files (.env, data, reports).
+
+    Returns:
+        Path: The project directory.
+
+    Raises:
+        ValueError: If the name is not a plain directory name.
+        FileExistsError: If the directory exists and `force` is not set.
+    """
+    _check_name(name)
+    files = render_project(
+        name, strategy=strategy, exchange=exchange, market_type=market_type
+    )
+
+    project = output_dir / name
+    if project.exists() and not force:
+        raise FileExistsError(f"Directory already exists: {project}")
+
+    project.mkdir(parents=True, exist_ok=True)
+    for file_name, content in files.items():
+        (project / file_name).write_text(content, encoding="utf-8")
+    return project
+
+
+def _check_name(name: str) -> None:
+    """Reject project names that are not a single plain path segment."""
+    if (
+        not name.strip()
+        or name in (".", "..")
+        or any(separator in name for separator in ("/", "\\"))
+        or Path(name).is_absolute()
+    ):
+        raise ValueError(f"Invalid project name '{name}': expected a directory name")
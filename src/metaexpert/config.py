@@ -1,6 +1,7 @@
 """Configuration file for Expert Trading Bot"""
 
 from datetime import datetime
+from pathlib import Path
 from typing import Literal
 
 # -----------------------------------------------------------------------------
@@ -84,6 +85,36 @@ POSITION_MODE_ONEWAY: str = "oneway"
 # Default position mode for futures trading
 DEFAULT_POSITION_MODE: str = POSITION_MODE_HEDGE
 
+# Lifetime of the cached instrument specifications (seconds)
+INSTRUMENT_CACHE_TTL: float = 3600.0
+
+# Base maker and taker fee rates per exchange and market type (0.001 = 0.1%)
+FEE_SCHEDULES: dict[str, dict[str, tuple[float, float]]] = {
+    "binance": {
+        MARKET_TYPE_SPOT: (0.001, 0.001),
+        MARKET_TYPE_FUTURES: (0.0002, 0.0005),
+    },
+    "bybit": {
+        MARKET_TYPE_SPOT: (0.001, 0.001),
+        MARKET_TYPE_FUTURES: (0.0002, 0.00055),
+    },
+    "okx": {
+        MARKET_TYPE_SPOT: (0.0008, 0.001),
+        MARKET_TYPE_FUTURES: (0.0002, 0.0005),
+    },
+    "kraken": {
+        MARKET_TYPE_SPOT: (0.0025, 0.004),
+        MARKET_TYPE_FUTURES: (0.0002, 0.0005),
+    },
+    "mexc": {
+        MARKET_TYPE_SPOT: (0.0, 0.0005),
+        MARKET_TYPE_FUTURES: (0.0, 0.0002),
+    },
+}
+
+# Maintenance margin of the simulated futures positions, as a share of their value (0.005 = 0.5%)
+MAINTENANCE_MARGIN_RATE: float = 0.005
+
 # -----------------------------------------------------------------------------
 # TRADING STRATEGY CONFIGURATION
 # -----------------------------------------------------------------------------
@@ -104,6 +135,7 @@ COMMENT: str = "my_strategy"
 LEVERAGE: int = 10
 MAX_DRAWDOWN_PCT: float = 0.2
 DAILY_LOSS_LIMIT: float = 1000.0
+FLATTEN_ON_BREACH: bool = False
 SIZE_VALUE: float = 1.5
 MAX_POSITION_SIZE_QUOTE: float = 50000.0
 
@@ -161,6 +193,32 @@ MIN_VOLUME: int = 1000000
 VOLATILITY_FILTER: bool = True
 TREND_FILTER: bool = True
 
+# -----------------------------------------------------------------------------
+# ORDER CONFIGURATION
+# -----------------------------------------------------------------------------
+
+# Order sides
+ORDER_SIDE_BUY: str = "buy"
+ORDER_SIDE_SELL: str = "sell"
+
+# Order types
+ORDER_TYPE_MARKET: str = "market"
+ORDER_TYPE_LIMIT: str = "limit"
+ORDER_TYPE_STOP: str = "stop"
+ORDER_TYPE_STOP_LIMIT: str = "stop_limit"
+ORDER_TYPE_TAKE_PROFIT: str = "take_profit"
+
+# Default order type
+DEFAULT_ORDER_TYPE: str = ORDER_TYPE_MARKET
+
+# Time in force
+TIME_IN_FORCE_GTC: str = "gtc"  # Good till cancelled
+TIME_IN_FORCE_IOC: str = "ioc"  # Immediate or cancel
+TIME_IN_FORCE_FOK: str = "fok"  # Fill or kill
+
+# Default time in force
+DEFAULT_TIME_IN_FORCE: str = TIME_IN_FORCE_GTC
+
 # -----------------------------------------------------------------------------
 # TRADING BOT OPERATION MODES
 # -----------------------------------------------------------------------------
@@ -181,3 +239,143 @@ BACKTEST_END_DATE: str = datetime.now().strftime("%Y-%m-%d")
 
 # Initial capital for backtesting or paper trading
 INITIAL_CAPITAL: float = 10000.0
+
+# Optimization methods
+OPTIMIZATION_METHOD_GRID: str = "grid"  # Every combination of the parameters
+OPTIMIZATION_METHOD_RANDOM: str = "random"  # Random combinations
+OPTIMIZATION_METHOD_GENETIC: str = "genetic"  # Genetic algorithm
+
+# Default optimization method
+DEFAULT_OPTIMIZATION_METHOD: str = OPTIMIZATION_METHOD_GRID
+
+# Maximum number of passes of the random and genetic searches
+OPTIMIZATION_PASSES: int = 100
+
+# Genetic search: individuals per generation and mutation rate of a parameter
+GENETIC_POPULATION: int = 20
+GENETIC_MUTATION_RATE: float = 0.1
+
+# Walk-forward analysis: number of windows and out-of-sample share of a window (percent)
+WALK_FORWARD_WINDOWS: int = 5
+WALK_FORWARD_OUT_OF_SAMPLE_PCT: float = 25.0
+
+# Intrabar price paths of the backtest fill model
+INTRABAR_PATH_NEAREST: str = "nearest"  # The extreme closer to the open first
+INTRABAR_PATH_OHLC: str = "ohlc"  # Open, high, low, close
+INTRABAR_PATH_OLHC: str = "olhc"  # Open, low, high, close
+
+# Default intrabar price path
+DEFAULT_INTRABAR_PATH: str = INTRABAR_PATH_NEAREST
+
+# Slippage models of the backtest fill model
+SLIPPAGE_MODEL_FIXED: str = "fixed"  # Percentage of the price
+SLIPPAGE_MODEL_VOLATILITY: str = "volatility"  # Share of the candle range
+SLIPPAGE_MODEL_SPREAD: str = "spread"  # Half of the bid/ask spread, in percent
+
+# -----------------------------------------------------------------------------
+# HISTORICAL DATA CONFIGURATION
+# -----------------------------------------------------------------------------
+
+# Directory of the local candle cache
+DATA_DIRECTORY: str = "data"
+
+# Candle cache file formats
+DATA_FORMAT_CSV: str = "csv"
+DATA_FORMAT_PARQUET: str = "parquet"  # Requires pyarrow
+
+# Default candle cache file format
+DEFAULT_DATA_FORMAT: str = DATA_FORMAT_CSV
+
+# -----------------------------------------------------------------------------
+# MARKET DATA STREAM CONFIGURATION
+# -----------------------------------------------------------------------------
+
+# Time given to the stream to deliver a closed candle before it is fetched
+# from the REST API (seconds after the candle close)
+BAR_CLOSE_DELAY: float = 3.0
+
+# Interval of the ticker (seconds)
+TICKER_INTERVAL: float = 1.0
+
+# Longest sleep of the schedulers, so that clock adjustments are caught up (seconds)
+SCHEDULER_MAX_SLEEP: float = 60.0
+
+# Delay before the first reconnection attempt, doubled on every failure up to
+# the cap, with a random jitter (seconds)
+WS_RECONNECT_DELAY: float = 1.0
+WS_MAX_RECONNECT_DELAY: float = 60.0
+
+# Protocol-level keepalive of the WebSocket connections (seconds)
+WS_PING_INTERVAL: float = 20.0
+WS_PING_TIMEOUT: float = 10.0
+
+# A connection without any message for this long is considered stale and is
+# reopened (seconds)
+WS_STALE_TIMEOUT: float = 60.0
+
+# Number of levels per side of the order books passed to the on_book handler
+ORDER_BOOK_DEPTH: int = 20
+
+# Interval between the renewals of the private stream credentials, e.g. the
+# Binance listen key expiring after 60 minutes (seconds)
+USER_STREAM_KEEPALIVE_INTERVAL: float = 1800.0
+
+# -----------------------------------------------------------------------------
+# COMMAND LINE INTERFACE CONFIGURATION
+# -----------------------------------------------------------------------------
+
+# Project files
+DEFAULT_SCRIPT: str = "main.py"  # Expert script of a project
+DEFAULT_ENV_FILE: str = ".env"  # Environment file of a project
+
+# Environment variables overriding the arguments of `MetaExpert.run()`
+ENV_TRADE_MODE: str = "METAEXPERT_TRADE_MODE"
+ENV_BACKTEST_START: str = "METAEXPERT_BACKTEST_START"
+ENV_BACKTEST_END: str = "METAEXPERT_BACKTEST_END"
+ENV_INITIAL_CAPITAL: str = "METAEXPERT_INITIAL_CAPITAL"
+ENV_REPORT_FILE: str = "METAEXPERT_REPORT_FILE"
+ENV_PARAMETERS: str = "METAEXPERT_PARAMETERS"  # JSON overrides of the inputs
+ENV_FILL_MODEL: str = "METAEXPERT_FILL_MODEL"  # Backtest fill model, see FillModel.parse
+ENV_FUNDING_FILE: str = "METAEXPERT_FUNDING_FILE"  # CSV funding rates file
+ENV_STATE_FILE: str = "METAEXPERT_STATE_FILE"  # Set by the process supervisor
+
+# Backtest report formats
+REPORT_FORMAT_HTML: str = "html"
+REPORT_FORMAT_JSON: str = "json"
+REPORT_FORMAT_CSV: str = "csv"
+
+# Default backtest report format and directory
+DEFAULT_REPORT_FORMAT: str = REPORT_FORMAT_HTML
+REPORT_DIRECTORY: str = "reports"
+
+# Metric used as the fitness of a backtest when `on_backtest` returns none
+DEFAULT_FITNESS_METRIC: str = "net_profit"
+
+# -----------------------------------------------------------------------------
+# PROCESS MANAGEMENT CONFIGURATION
+# -----------------------------------------------------------------------------
+
+# Directory of the state and log files of the detached experts
+PROCESS_DIRECTORY: str = str(Path.home() / ".metaexpert" / "experts")
+
+# Directory of the risk states of the live experts, kept across restarts
+RISK_DIRECTORY: str = str(Path.home() / ".metaexpert" / "risk")
+
+# Interval between two heartbeats of a running expert (seconds)
+HEARTBEAT_INTERVAL: float = 5.0
+
+# Time given to an expert to shut down before it is killed (seconds)
+STOP_TIMEOUT: float = 30.0
+
+# Expert process statuses
+PROCESS_STATUS_STARTING: str = "starting"
+PROCESS_STATUS_RUNNING: str = "running"
+PROCESS_STATUS_STOPPING: str = "stopping"
+PROCESS_STATUS_STOPPED: str = "stopped"
+PROCESS_STATUS_DEAD: str = "dead"  # The process exited without shutting down
+
+# Deinitialization reasons passed to `on_deinit`
+DEINIT_REASON_USER_STOP: str = "user_stop"
+DEINIT_REASON_SIGNAL: str = "signal"
+DEINIT_REASON_ERROR: str = "error"
+DEINIT_REASON_FINISHED: str = "finished"
@@ -4,6 +4,9 @@ from ._bar import Bar
 from ._event_handler import EventHandler
 from ._status import InitStatus
 from ._timer import Timer
+from .balance import Balance
+from .broker import Broker
+from .candle import Candle
 from .contract_type import ContractType
 from .event_type import EventType
 from .events import Events
@@ -17,25 +20,44 @@ from .exceptions import (
     InvalidDataError,
     InvalidOrderError,
     InvalidTimeframeError,
+    LiquidationError,
     MarketDataError,
     MetaExpertError,
     MissingConfigurationError,
     MissingDataError,
     NetworkError,
+    OrderBookOutOfSyncError,
     OrderNotFoundError,
     ProcessError,
     RateLimitError,
+    RiskLimitError,
     ShutdownError,
     TradingError,
     UnsupportedPairError,
     ValidationError,
 )
 from .expert import Expert
+from .fill import Fill
+from .funding_rate import FundingRate
+from .instrument import Instrument, InstrumentRegistry
+from .instrument_status import InstrumentStatus
+from .intrabar_path import IntrabarPath
 from .margin_mode import MarginMode
 from .market import Market
 from .market_type import MarketType
+from .optimization_method import OptimizationMethod
+from .order import Order
+from .order_book import LocalOrderBook, OrderBook, OrderBookUpdate
+from .order_side import OrderSide
+from .order_status import OrderStatus
+from .order_type import OrderType
+from .position import Position
+from .position_book import PositionBook
 from .position_mode import PositionMode
+from .position_side import PositionSide
 from .size_type import SizeType
+from .tick import Tick
+from .time_in_force import TimeInForce
 from .timeframe import Timeframe
 from .trade import Trade
 from .trade_mode import TradeMode
@@ -43,20 +65,31 @@ from .trade_mode import TradeMode
 __all__ = [
     "APIError",
     "AuthenticationError",
+    "Balance",
     "Bar",
+    "Broker",
+    "Candle",
     "ConfigurationError",
     "ContractType",
     "EventHandler",
     "EventType",
     "Events",
     "Expert",
+    "Fill",
+    "FundingRate",
     "InitStatus",
     "InitializationError",
+    "Instrument",
+    "InstrumentRegistry",
+    "InstrumentStatus",
     "InsufficientFundsError",
+    "IntrabarPath",
     "InvalidConfigurationError",
     "InvalidDataError",
     "InvalidOrderError",
     "InvalidTimeframeError",
+    "LiquidationError",
+    "LocalOrderBook",
     "MarginMode",
     "Market",
     "MarketDataError",
@@ -65,12 +98,26 @@ __all__ = [
     "MissingConfigurationError",
     "MissingDataError",
     "NetworkError",
+    "OptimizationMethod",
+    "Order",
+    "OrderBook",
+    "OrderBookOutOfSyncError",
+    "OrderBookUpdate",
     "OrderNotFoundError",
+    "OrderSide",
+    "OrderStatus",
+    "OrderType",
+    "Position",
+    "PositionBook",
     "PositionMode",
+    "PositionSide",
     "ProcessError",
     "RateLimitError",
+    "RiskLimitError",
     "ShutdownError",
     "SizeType",
+    "Tick",
+    "TimeInForce",
     "Timeframe",
     "Timer",
     "Trade",
@@ -1,44 +1,86 @@
 import asyncio
 import time
 from collections.abc import Callable
+from datetime import UTC, datetime, timedelta
 
+from metaexpert.config import BAR_CLOSE_DELAY, SCHEDULER_MAX_SLEEP
 from metaexpert.logger import MetaLogger as Logger, get_logger
+from metaexpert.utils.time import sleep_until
+
+from .timeframe import Timeframe
 
 
 class Bar:
+    """Scheduler calling its callback once per closed candle of a timeframe.
+
+    The scheduler wakes up at the candle boundaries computed from the
+    timeframe in UTC, `delay` seconds after the close. Every wake-up time is
+    computed from the wall clock, so the schedule does not drift. The callback
+    receives its arguments followed by the open time of the closed candle; if
+    the scheduler wakes up too late to see a boundary, it reports the last
+    closed candle only. The callback runs in a worker thread, as it may wait
+    on the REST API, and its errors are reported to the `on_error` handlers
+    without stopping the scheduler.
+    """
+
     def __init__(
         self,
         timeframe: str = "1h",
         callback: Callable | None = None,
-        args: tuple[dict, ...] = (),
+        args: tuple = (),
+        delay: float = BAR_CLOSE_DELAY,
     ) -> None:
         self._timeframe: str = timeframe
-        self._func: Callable = callback if callback is not None else lambda: None
-        self._args: tuple[dict, ...] = args
+        self._func: Callable = callback if callback is not None else lambda *_: None
+        self._args: tuple = args
+        self._delay: timedelta = timedelta(seconds=delay)
         self._start_time: float = 0.0
-        self._elapsed_time: float = 0.0
+        self._count: int = 0
         self._is_running: bool = False
-        self.logger: Logger = get_logger(__name__).bind(
-            component="Bar", timeframe=timeframe
-        )
+        self.logger: Logger = get_logger(f"Bar.{timeframe}")
 
     async def start(self) -> None:
         self._is_running = True
         self._start_time = time.time()
         self.logger.debug("Bar with timeframe %s started.", self._timeframe)
 
+        timeframe = Timeframe.get_timeframe_from(self._timeframe)
+        next_time = timeframe.get_next_candle_time(datetime.now(UTC))
         while self._is_running:
-            await asyncio.sleep(7)
-            self._elapsed_time += 7
-            self._func(*self._args)
+            await sleep_until(next_time + self._delay, SCHEDULER_MAX_SLEEP)
+            if not self._is_running:
+                break
+
+            # Open time of the current candle, later than expected after a late wake-up
+            now = datetime.now(UTC)
+            open_time = timeframe.get_candle_open_time(now - self._delay)
+            closed_time = open_time - timeframe.get_delta()
+            if open_time > next_time:
+                self.logger.warning(
+                    "Bar with timeframe %s woke up late: %s", self._timeframe, now
+                )
+
+            self._count += 1
+            try:
+                await asyncio.to_thread(self._func, *self._args, closed_time)
+            except Exception as e:
+                # Imported here, the event types import the scheduler
+                from .event_type import EventType
+
+                self.logger.exception(
+                    "Bar with timeframe %s failed: %s", self._timeframe, e
+                )
+                EventType.ON_ERROR.emit(e)
+            next_time = timeframe.get_next_candle_time(open_time)
 
     def stop(self) -> None:
         if self._is_running:
             self._is_running = False
             self.logger.debug(
-                "Bar with timeframe %s stopped. Total time: %.1f seconds.",
+                "Bar with timeframe %s stopped. Total bars: %d, time: %.1f seconds.",
                 self._timeframe,
-                self._elapsed_time,
+                self._count,
+                time.time() - self._start_time,
             )
 
     @property
@@ -1,32 +1,52 @@
-import asyncio
 import time
 from collections.abc import Callable
+from datetime import UTC, datetime
 
+from metaexpert.config import SCHEDULER_MAX_SLEEP, TICKER_INTERVAL
 from metaexpert.logger import MetaLogger as Logger, get_logger
+from metaexpert.utils.time import sleep_until
 
 
 class Ticker:
-    def __init__(self, callback: Callable | None = None) -> None:
+    """Scheduler calling its callback at every multiple of its interval, in UTC.
+
+    Like `Bar`, the wake-up times are computed from the wall clock, so slow
+    callbacks and oversleeping do not accumulate drift; missed ticks are
+    skipped.
+    """
+
+    def __init__(
+        self, callback: Callable | None = None, interval: float = TICKER_INTERVAL
+    ) -> None:
         self._func: Callable = callback if callback is not None else lambda: None
+        self._interval: float = interval
         self._start_time: float = 0.0
-        self._elapsed_time: float = 0.0
+        self._count: int = 0
         self._is_running: bool = False
         self.logger: Logger = get_logger("Ticker")
 
     async def start(self) -> None:
         self._is_running = True
         self._start_time = time.time()
-        self.logger.debug("Ticker started.")
+        self.logger.debug("Ticker with interval %.1f seconds started.", self._interval)
 
         while self._is_running:
-            await asyncio.sleep(7)
-            self._elapsed_time += 7
+            await sleep_until(self._get_next_time(), SCHEDULER_MAX_SLEEP)
+            if not self._is_running:
+                break
+            self._count += 1
             self._func()
 
     def stop(self) -> None:
         if self._is_running:
             self._is_running = False
             self.logger.debug(
-                "Total time: %.1f seconds.",
-                self._elapsed_time,
+                "Ticker stopped. Total ticks: %d, time: %.1f seconds.",
+                self._count,
+                time.time() - self._start_time,
             )
+
+    def _get_next_time(self) -> datetime:
+        """Next multiple of the interval since the Unix epoch."""
+        now = time.time()
+        return datetime.fromtimestamp((now // self._interval + 1) * self._interval, UTC)
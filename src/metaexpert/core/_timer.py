@@ -12,9 +12,7 @@ class Timer:
         self._start_time: float = 0.0
         self._elapsed_time: float = 0.0
         self._is_running: bool = False
-        self.logger: Logger = get_logger(__name__).bind(
-            component="Timer", interval=interval
-        )
+        self.logger: Logger = get_logger("Timer")
 
     async def start(self) -> None:
         self._is_running = True
@@ -0,0 +1,30 @@
+"""Balance"""
+
+from dataclasses import dataclass, field
+from datetime import UTC, datetime
+from typing import Any
+
+
+@dataclass(frozen=True)
+class Balance:
+    """Exchange-agnostic balance of one asset of the account."""
+
+    asset: str
+    free: float
+    locked: float = 0.0
+    updated_at: datetime = field(default_factory=lambda: datetime.now(UTC))
+
+    @property
+    def total(self) -> float:
+        """Free and locked amounts together."""
+        return self.free + self.locked
+
+    def to_dict(self) -> dict[str, Any]:
+        """Return the dictionary passed to the `on_account` handler."""
+        return {
+            "asset": self.asset,
+            "free": self.free,
+            "locked": self.locked,
+            "total": self.total,
+            "updated_at": self.updated_at.isoformat(),
+        }
@@ -0,0 +1,19 @@
+"""Broker"""
+
+from abc import ABC
+
+from .market import Market
+from .position_book import PositionBook
+from .trade import Trade
+
+
+class Broker(Trade, Market, ABC):
+    """Trading venue used by an expert.
+
+    Implemented by the exchange adapters for live trading and by the
+    simulated brokers for paper trading and backtesting, so that a strategy
+    runs unchanged in every trade mode.
+    """
+
+    # Open positions known locally, kept up to date by the fills or the user stream
+    position_book: PositionBook
@@ -0,0 +1,47 @@
+"""Candle"""
+
+from dataclasses import dataclass, field
+from datetime import datetime
+from typing import Any
+
+from .timeframe import Timeframe
+
+
+@dataclass(frozen=True)
+class Candle:
+    """Exchange-agnostic OHLCV candle.
+
+    `open_time` is the UTC start of the candle; `is_closed` is False while the
+    candle is still forming (live streams only).
+    """
+
+    symbol: str
+    timeframe: Timeframe
+    open_time: datetime
+    open: float
+    high: float
+    low: float
+    close: float
+    volume: float = 0.0
+    is_closed: bool = True
+    extra: dict[str, Any] = field(default_factory=dict, compare=False)
+
+    @property
+    def close_time(self) -> datetime:
+        """UTC time at which the candle closes."""
+        return self.open_time + self.timeframe.get_delta()
+
+    def to_dict(self) -> dict[str, Any]:
+        """Return the rates dictionary passed to the `on_bar`/`on_tick` handlers."""
+        return {
+            "symbol": self.symbol,
+            "timeframe": self.timeframe.get_name(),
+            "time": self.open_time,
+            "open": self.open,
+            "high": self.high,
+            "low": self.low,
+            "close": self.close,
+            "volume": self.volume,
+            "is_closed": self.is_closed,
+            **self.extra,
+        }
@@ -1,11 +1,13 @@
 import asyncio
 import inspect
 from asyncio import Task
+from collections.abc import Coroutine, Iterable
 from enum import Enum
 from threading import Thread
 from types import ModuleType
-from typing import Self
+from typing import Any, Self
 
+from metaexpert.core._bar import Bar
 from metaexpert.core._event_handler import EventHandler
 from metaexpert.logger import MetaLogger as Logger, get_logger
 from metaexpert.websocket import WebSocketClient
@@ -260,6 +262,45 @@ class EventType(Enum):
         )
         return has_instances
 
+    def emit(self, *args: Any, timeframe: str | None = None) -> list[Any]:
+        """Invoke the registered callbacks synchronously with the given arguments.
+
+        This is how runtimes that produce the data themselves (backtesting,
+        simulated brokers, stream dispatch) drive the event handlers. Bar
+        callbacks declared for another timeframe are skipped when `timeframe`
+        is given. Exceptions raised by a handler are logged and forwarded to
+        the `on_error` handler instead of interrupting the caller.
+
+        Args:
+            *args: Arguments passed to every callback.
+            timeframe (str | None): Timeframe of the emitted bar, if any.
+
+        Returns:
+            list[Any]: Values returned by the callbacks.
+        """
+        callback = self.value.get("callback")
+        if not isinstance(callback, list):
+            self.logger.error(
+                "Callbacks for '%s' are not a list", self.value.get("name")
+            )
+            return []
+
+        results: list[Any] = []
+        for func in callback:
+            func_timeframe = getattr(func, "timeframe", None)
+            if timeframe is not None and func_timeframe not in (None, timeframe):
+                continue
+            try:
+                results.append(func(*args))
+            except Exception as e:
+                self.logger.exception(
+                    "Callback for '%s' failed: %s", self.value.get("name"), e
+                )
+                if self is not EventType.ON_ERROR:
+                    EventType.ON_ERROR.emit(e)
+
+        return results
+
     def run(self) -> None:
         """Run the process.
 
@@ -328,38 +369,41 @@ class EventType(Enum):
         return tasks
 
     @classmethod
-    def processing(cls, ws_url: str) -> bool:
+    def processing(cls, *clients: WebSocketClient, bars: Iterable[Bar] = ()) -> bool:
         """Process the events for the trading system.
 
         This method initializes the process and starts running the tasks.
-        It checks if the process is already initialized and if not, it starts a new thread to run the tasks.
+        Every WebSocket client (market data streams) runs in its own thread,
+        the asynchronous event tasks (timers) and the bar schedulers in another one.
         It returns True if the process was successfully initialized, otherwise False.
         """
         # if not cls.ON_INIT.value.get("is_done"):
         #     return False
-        Thread(target=WebSocketClient, args=(ws_url,), daemon=True).start()
-        Thread(target=cls._run_tasks, daemon=True).start()
+        for client in clients:
+            Thread(target=client.run, name=client.name, daemon=True).start()
+        schedulers = [bar.start() for bar in bars]
+        Thread(target=cls._run_tasks, args=(schedulers,), daemon=True).start()
 
         return True
 
     @classmethod
-    def _run_tasks(cls) -> None:
+    def _run_tasks(cls, schedulers: list[Coroutine] | None = None) -> None:
         """Run the tasks for the process.
 
         This method gathers all tasks for the processes that are marked as asynchronous.
-        It runs the tasks in an asyncio event loop.
+        It runs the tasks in an asyncio event loop, along with the given schedulers.
         """
-        asyncio.run(cls._gather_tasks())
+        asyncio.run(cls._gather_tasks(schedulers or []))
 
     @classmethod
-    async def _gather_tasks(cls) -> None:
+    async def _gather_tasks(cls, schedulers: list[Coroutine]) -> None:
         """Gather all tasks for the processes.
 
         This method collects all tasks for the processes that are marked as asynchronous.
         It iterates through the processes and gathers their tasks into a single list.
         If any process fails to gather tasks, it logs an error message.
         """
-        tasks: list[Task] = []
+        tasks: list[Task] = [asyncio.create_task(item) for item in schedulers]
         for item in cls:
             if item.value.get("is_async"):
                 task = item._get_tasks()
@@ -6,6 +6,7 @@ from metaexpert.config import (
     COMMENT,
     DAILY_LOSS_LIMIT,
     DEFAULT_SIZE_TYPE,
+    FLATTEN_ON_BREACH,
     LEVERAGE,
     LOOKBACK_BARS,
     MAX_DRAWDOWN_PCT,
@@ -26,10 +27,10 @@ from metaexpert.config import (
     VOLATILITY_FILTER,
     WARMUP_BARS,
 )
-from metaexpert.core._bar import Bar
 from metaexpert.core._timer import Timer
 from metaexpert.core.event_type import EventType
 from metaexpert.core.expert import Expert
+from metaexpert.core.order_book import OrderBook
 from metaexpert.core.size_type import SizeType
 from metaexpert.core.timeframe import Timeframe
 from metaexpert.logger import MetaLogger as Logger, get_logger
@@ -49,9 +50,10 @@ class Events(Expert):
         self,
         #
         # --- Core Trading Parameters ---
-        symbol: str,
+        symbol: str | list[str],
         timeframe: str,
         *,
+        timeframes: list[str] | None = None,
         lookback_bars: int = LOOKBACK_BARS,
         warmup_bars: int = WARMUP_BARS,
         #
@@ -64,6 +66,7 @@ class Events(Expert):
         leverage: int = LEVERAGE,
         max_drawdown_pct: float = MAX_DRAWDOWN_PCT,
         daily_loss_limit: float = DAILY_LOSS_LIMIT,
+        flatten_on_breach: bool = FLATTEN_ON_BREACH,
         size_type: str = DEFAULT_SIZE_TYPE,
         size_value: float = SIZE_VALUE,
         max_position_size_quote: float = MAX_POSITION_SIZE_QUOTE,
@@ -91,8 +94,9 @@ class Events(Expert):
         """Decorator for initialization event handling.
 
         Args:
-            symbol (str): Trading symbols (e.g., "BTCUSDT", "ETHUSDT").
-            timeframe (str): Time frame for trading data (e.g., "1h", "1m").
+            symbol (str | list[str]): Trading symbol or symbols (e.g., "BTCUSDT", ["BTCUSDT", "ETHUSDT"]); the first one is the primary symbol.
+            timeframe (str): Primary time frame for trading data (e.g., "1h", "1m").
+            timeframes (list[str] | None): Secondary time frames to stream (e.g., ["4h", "1d"]). Timeframes of the `on_bar` handlers are added automatically. Defaults to None.
             lookback_bars (int): Number of historical bars to fetch for analysis. Defaults to 100.
             warmup_bars (int): Skip initial bars to initialize indicators. Defaults to 0.
             strategy_id (int): Unique ID for order tagging. Defaults to 1001.
@@ -101,8 +105,9 @@ class Events(Expert):
             leverage (int): Leverage for margin trading (ignored for spot). Defaults to 10.
             max_drawdown_pct (float): Max drawdown from peak equity (0.2 = 20%). Defaults to 0.2.
             daily_loss_limit (float): Daily loss limit in settlement currency (auto-detected). Defaults to 1000.0.
+            flatten_on_breach (bool): Close all positions when a risk limit is breached. Defaults to False.
             size_type (str): Position sizing method ('fixed_base', 'fixed_quote', 'percent_equity', 'risk_based'). Defaults to "risk_based".
-            size_value (float): Size value based on size_type (e.g., 1.5% for risk_based). Defaults to 1.5.
+            size_value (float): Size value based on size_type, a percent for percent_equity and risk_based (1.5 = 1.5%). Defaults to 1.5.
             max_position_size_quote (float): Max position size in quote currency. Defaults to 50000.0.
             stop_loss_pct (float): Stop-loss % from entry. Defaults to 2.0.
             take_profit_pct (float): Take-profit % from entry. Defaults to 4.0.
@@ -122,9 +127,18 @@ class Events(Expert):
         Returns:
             Callable: Decorated function that handles the initialization event.
         """
+        symbols = [symbol] if isinstance(symbol, str) else list(symbol)
+        if not symbols:
+            raise ValueError("At least one symbol is required")
+        symbols = list(dict.fromkeys(item.upper().strip() for item in symbols))
+        primary = Timeframe.get_timeframe_from(timeframe)
+        secondary = [Timeframe.get_timeframe_from(name) for name in timeframes or []]
+
         super().__init__(
-            symbol=symbol,
-            timeframe=Timeframe.get_timeframe_from(timeframe),
+            symbol=symbols[0],
+            timeframe=primary,
+            symbols=symbols,
+            timeframes=list(dict.fromkeys([primary, *secondary])),
             lookback_bars=lookback_bars,
             warmup_bars=warmup_bars,
             strategy_id=strategy_id,
@@ -133,6 +147,7 @@ class Events(Expert):
             leverage=leverage,
             max_drawdown_pct=max_drawdown_pct,
             daily_loss_limit=daily_loss_limit,
+            flatten_on_breach=flatten_on_breach,
             size_type=SizeType.get_size_type_from(size_type),
             size_value=size_value,
             max_position_size_quote=max_position_size_quote,
@@ -152,7 +167,11 @@ class Events(Expert):
             trend_filter=trend_filter,
         )
 
-        self.logger.info("Symbol: %s, Timeframe: %s", self.symbol, self.timeframe)
+        self.logger.info(
+            "Symbols: %s, Timeframes: %s",
+            ", ".join(self.symbols),
+            ", ".join(item.get_name() for item in self.timeframes),
+        )
 
         def outer(func: Callable[[], None]) -> Callable[[], None]:
             def inner() -> None:
@@ -201,27 +220,23 @@ class Events(Expert):
 
     @staticmethod
     def on_bar(
-        timeframe: str = "1h",
-    ) -> Callable[
-        [Callable[[dict], None]], Callable[[dict], Coroutine[Any, Any, None]]
-    ]:
+        timeframe: str | None = None,
+    ) -> Callable[[Callable[[dict], None]], Callable[[dict], None]]:
         """Decorator for bar event handling.
 
         Args:
-            timeframe (str): Time frame for the bar. Defaults to "1h".
+            timeframe (str | None): Time frame for the bar. Defaults to the init timeframe.
+                The handler receives the bars of every symbol, tagged with `symbol` and `timeframe`.
 
         Returns:
             Callable: Decorated function that handles bar events.
         """
 
-        def outer(
-            func: Callable[[dict], None],
-        ) -> Callable[[dict], Coroutine[Any, Any, None]]:
-            async def inner(rates: dict) -> None:
-                bar = Bar(timeframe=timeframe, callback=func, args=(rates,))
-                EventType.ON_BAR.push_instance(bar)
-                await bar.start()
+        def outer(func: Callable[[dict], None]) -> Callable[[dict], None]:
+            def inner(rates: dict) -> None:
+                func(rates)
 
+            inner.timeframe = timeframe  # type: ignore[attr-defined]
             return inner
 
         return outer
@@ -302,17 +317,18 @@ class Events(Expert):
         return inner
 
     @staticmethod
-    def on_book(func: Callable[[dict], None]) -> Callable[[dict], None]:
+    def on_book(func: Callable[[OrderBook], None]) -> Callable[[OrderBook], None]:
         """Decorator for book event handling.
 
         Args:
-            func (Callable): Function to handle order book changes.
+            func (Callable): Function to handle order book changes, called with
+                the top levels of the `OrderBook` after every update.
 
         Returns:
             Callable: Decorated function that handles book events.
         """
 
-        def inner(orderbook: dict) -> None:
+        def inner(orderbook: OrderBook) -> None:
             func(orderbook)
 
         return inner
@@ -382,17 +398,21 @@ class Events(Expert):
         return inner
 
     @staticmethod
-    def on_backtest(func: Callable[[], float]) -> Callable[[], float]:
-        """Decorator for backtest per-bar event handling.
+    def on_backtest(func: Callable[[], float | str]) -> Callable[[], float | str]:
+        """Decorator for the fitness of a backtest pass.
+
+        The handler returns the fitness as a number, or the name of the
+        metric to use, e.g. "sharpe" (see `metaexpert.backtest.Metrics`).
+        The net profit is used without a handler.
 
         Args:
-            func (Callable): Function to handle backtest bar events.
+            func (Callable): Function returning the fitness of the pass.
 
         Returns:
-            Callable: Decorated function that handles backtest events.
+            Callable: Decorated function that returns the fitness.
         """
 
-        def inner() -> float:
+        def inner() -> float | str:
             return func()
 
         return inner
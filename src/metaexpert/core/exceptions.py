@@ -204,6 +204,32 @@ class InvalidOrderError(TradingError):
         self.order_details = order_details
 
 
+class LiquidationError(TradingError):
+    """Raised when a position is forcibly liquidated."""
+
+    def __init__(
+        self,
+        symbol: str,
+        side: str,
+        price: float,
+        message: str | None = None,
+    ) -> None:
+        """Initialize the LiquidationError.
+
+        Args:
+            symbol: Symbol of the liquidated position
+            side: Side of the liquidated position ("long" or "short")
+            price: Liquidation price
+            message: Human-readable error message
+        """
+        if message is None:
+            message = f"Position liquidated: {side} {symbol} @ {price}"
+        super().__init__(message)
+        self.symbol = symbol
+        self.side = side
+        self.price = price
+
+
 class OrderNotFoundError(TradingError):
     """Raised when an order cannot be found."""
 
@@ -220,6 +246,22 @@ class OrderNotFoundError(TradingError):
         self.order_id = order_id
 
 
+class RiskLimitError(TradingError):
+    """Raised when a risk limit of the expert is breached."""
+
+    def __init__(self, limit: str, message: str | None = None) -> None:
+        """Initialize the RiskLimitError.
+
+        Args:
+            limit: Name of the breached limit (e.g. "max_drawdown_pct")
+            message: Human-readable error message
+        """
+        if message is None:
+            message = f"Risk limit breached: {limit}"
+        super().__init__(message)
+        self.limit = limit
+
+
 # -----------------------------------------------------------------------------
 # DATA VALIDATION EXCEPTIONS
 # -----------------------------------------------------------------------------
@@ -313,6 +355,22 @@ class InvalidTimeframeError(MarketDataError):
         self.timeframe = timeframe
 
 
+class OrderBookOutOfSyncError(MarketDataError):
+    """Raised when an order book update does not follow the local book."""
+
+    def __init__(self, symbol: str, message: str | None = None) -> None:
+        """Initialize the OrderBookOutOfSyncError.
+
+        Args:
+            symbol: The symbol of the order book
+            message: Human-readable error message
+        """
+        if message is None:
+            message = f"Order book out of sync: '{symbol}'"
+        super().__init__(message)
+        self.symbol = symbol
+
+
 # -----------------------------------------------------------------------------
 # PROCESS EXCEPTIONS
 # -----------------------------------------------------------------------------
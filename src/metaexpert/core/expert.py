@@ -9,6 +9,8 @@ class Expert:
     # --- Core Trading Parameters ---
     symbol: str
     timeframe: Timeframe
+    symbols: list[str]
+    timeframes: list[Timeframe]
     lookback_bars: int
     warmup_bars: int
     #
@@ -21,8 +23,9 @@ class Expert:
     leverage: int
     max_drawdown_pct: float
     daily_loss_limit: float
+    flatten_on_breach: bool
     size_type: SizeType
-    size_value: float
+    size_value: float  # Base or quote amount, or percent of the equity (1.5 = 1.5%)
     max_position_size_quote: float
     #
     # --- Trade Parameters ---
@@ -47,17 +50,4 @@ class Expert:
     #
     # --- Internal State ---
     # _label: str | None = None
-    # _lots_min: float = 0.0
-    # _lots_max: float = 0.0
-    # _lots_step: float = 0.0
-    # _long_time: datetime | None = None
-    # _long_positions: int = 0
-    # _long_profit: float = 0.0
-    # _long_lots: float = 0.0
-    # _short_time: datetime | None = None
-    # _short_positions: int = 0
-    # _short_profit: float = 0.0
-    # _short_lots: float = 0.0
-    # _digits: int = 0
-    # _digits_format: str | None = None
-    # _point: float = 0.0
+    # Lots, digits and point of the symbols: see `Instrument`
@@ -0,0 +1,41 @@
+"""Fill"""
+
+from dataclasses import dataclass
+from datetime import datetime
+from typing import Any
+
+from .order_side import OrderSide
+
+
+@dataclass(frozen=True)
+class Fill:
+    """Exchange-agnostic execution of (a part of) an order.
+
+    The fee is a cost: positive when charged, negative for a rebate.
+    """
+
+    order_id: str
+    symbol: str
+    side: OrderSide
+    quantity: float
+    price: float
+    time: datetime
+    fee: float = 0.0
+    fee_asset: str | None = None
+    realized_pnl: float = 0.0
+    trade_id: str | None = None
+
+    def to_dict(self) -> dict[str, Any]:
+        """Return the result dictionary passed to the `on_transaction` handler."""
+        return {
+            "order_id": self.order_id,
+            "trade_id": self.trade_id,
+            "symbol": self.symbol,
+            "side": self.side.get_name(),
+            "quantity": self.quantity,
+            "price": self.price,
+            "fee": self.fee,
+            "fee_asset": self.fee_asset,
+            "realized_pnl": self.realized_pnl,
+            "time": self.time,
+        }
@@ -0,0 +1,28 @@
+"""Funding rate"""
+
+from dataclasses import dataclass
+from datetime import datetime
+from typing import Any
+
+
+@dataclass(frozen=True)
+class FundingRate:
+    """Funding rate of a perpetual contract at one funding timestamp.
+
+    A positive rate is paid by the longs to the shorts, a negative one by the
+    shorts to the longs (0.0001 = 0.01% of the position value).
+    """
+
+    symbol: str
+    time: datetime
+    rate: float
+    mark_price: float | None = None
+
+    def to_dict(self) -> dict[str, Any]:
+        """Return a plain dictionary representation."""
+        return {
+            "symbol": self.symbol,
+            "time": self.time,
+            "rate": self.rate,
+            "mark_price": self.mark_price,
+        }
@@ -0,0 +1,206 @@
+"""Instrument"""
+
+import time
+from collections.abc import Callable
+from dataclasses import dataclass
+from threading import Lock
+from typing import Any
+
+from metaexpert.config import INSTRUMENT_CACHE_TTL
+from metaexpert.utils.rounding import round_down, round_nearest
+
+from .exceptions import InvalidOrderError, UnsupportedPairError
+from .instrument_status import InstrumentStatus
+from .order import Order
+
+
+@dataclass(frozen=True)
+class Instrument:
+    """Trading rules of a symbol, as published by the exchange.
+
+    Quantities are in base currency for spot and in contracts for futures,
+    a contract being worth `contract_size` base currency (linear) or quote
+    currency (inverse). Inverse contracts are settled in the base currency.
+    """
+
+    symbol: str
+    base_asset: str
+    quote_asset: str
+    settlement_asset: str
+    tick_size: float = 0.0  # Price increment, 0 if unrestricted
+    lot_step: float = 0.0  # Quantity increment, 0 if unrestricted
+    min_quantity: float = 0.0
+    max_quantity: float = 0.0  # 0 if unlimited
+    min_notional: float = 0.0  # In quote currency
+    max_leverage: int = 1  # 0 if unknown
+    contract_size: float = 1.0
+    status: InstrumentStatus = InstrumentStatus.TRADING
+
+    @property
+    def is_trading(self) -> bool:
+        """Whether orders are accepted for the instrument."""
+        return self.status is InstrumentStatus.TRADING
+
+    @property
+    def is_inverse(self) -> bool:
+        """Whether the instrument is settled in its base currency."""
+        return self.settlement_asset == self.base_asset
+
+    def get_notional(self, quantity: float, price: float) -> float:
+        """Value of a quantity at a price, in quote currency."""
+        if self.is_inverse:
+            return quantity * self.contract_size
+        return quantity * self.contract_size * price
+
+    def round_price(self, price: float) -> float:
+        """Round a price to the nearest tick."""
+        return round_nearest(price, self.tick_size) if self.tick_size > 0 else price
+
+    def round_quantity(self, quantity: float) -> float:
+        """Round a quantity down to the lot step."""
+        return round_down(quantity, self.lot_step) if self.lot_step > 0 else quantity
+
+    def prepare_order(self, order: Order) -> Order:
+        """Round the prices and quantity of an order and check them against the rules.
+
+        The minimum notional is only checked for orders with a price and not
+        for reduce-only orders, which exchanges accept below it.
+
+        Args:
+            order (Order): Order request, rounded in place.
+
+        Returns:
+            Order: The rounded order.
+
+        Raises:
+            InvalidOrderError: If the order breaks the trading rules.
+        """
+        if not self.is_trading:
+            raise InvalidOrderError(
+                order.to_dict(),
+                f"{self.symbol} is not trading (status '{self.status.get_name()}')",
+            )
+
+        order.quantity = self.round_quantity(order.quantity)
+        if order.price is not None:
+            order.price = self.round_price(order.price)
+        if order.stop_price is not None:
+            order.stop_price = self.round_price(order.stop_price)
+        order.validate()
+
+        if order.quantity < self.min_quantity:
+            raise InvalidOrderError(
+                order.to_dict(),
+                f"Order quantity {order.quantity} is below the minimum of {self.min_quantity}",
+            )
+        if 0 < self.max_quantity < order.quantity:
+            raise InvalidOrderError(
+                order.to_dict(),
+                f"Order quantity {order.quantity} is above the maximum of {self.max_quantity}",
+            )
+        price = order.price or order.stop_price
+        if price is not None and not order.reduce_only:
+            notional = self.get_notional(order.quantity, price)
+            if notional < self.min_notional:
+                raise InvalidOrderError(
+                    order.to_dict(),
+                    f"Order notional {notional} is below the minimum of {self.min_notional}",
+                )
+        return order
+
+    def to_dict(self) -> dict[str, Any]:
+        """Convert the instrument to a dictionary."""
+        return {
+            "symbol": self.symbol,
+            "base_asset": self.base_asset,
+            "quote_asset": self.quote_asset,
+            "settlement_asset": self.settlement_asset,
+            "tick_size": self.tick_size,
+            "lot_step": self.lot_step,
+            "min_quantity": self.min_quantity,
+            "max_quantity": self.max_quantity,
+            "min_notional": self.min_notional,
+            "max_leverage": self.max_leverage,
+            "contract_size": self.contract_size,
+            "status": self.status.get_name(),
+        }
+
+
+class InstrumentRegistry:
+    """Instruments of a market, loaded from the exchange on first use and cached.
+
+    The specifications are reloaded once they are older than `ttl` seconds.
+    A loader returning `None` means that the exchange does not publish them,
+    in which case orders are sent unchecked.
+    """
+
+    def __init__(
+        self,
+        loader: Callable[[], list[Instrument] | None],
+        ttl: float = INSTRUMENT_CACHE_TTL,
+    ) -> None:
+        """Initialize an empty registry.
+
+        Args:
+            loader (Callable[[], list[Instrument] | None]): Fetches the instruments of the market.
+            ttl (float): Lifetime of the cached specifications (seconds).
+        """
+        self.loader: Callable[[], list[Instrument] | None] = loader
+        self.ttl: float = ttl
+        self._instruments: dict[str, Instrument] | None = None
+        self._loaded_at: float | None = None
+        self._lock: Lock = Lock()
+
+    def __contains__(self, symbol: str) -> bool:
+        instruments = self._get_instruments()
+        return instruments is not None and symbol.upper() in instruments
+
+    def __len__(self) -> int:
+        return len(self._get_instruments() or {})
+
+    def get(self, symbol: str) -> Instrument | None:
+        """Get the instrument of a symbol.
+
+        Args:
+            symbol (str): Trading symbol.
+
+        Returns:
+            Instrument | None: The instrument, None if the exchange does not publish them.
+
+        Raises:
+            UnsupportedPairError: If the exchange does not list the symbol.
+        """
+        instruments = self._get_instruments()
+        if instruments is None:
+            return None
+        instrument = instruments.get(symbol.upper())
+        if instrument is None:
+            raise UnsupportedPairError(symbol)
+        return instrument
+
+    def prepare_order(self, order: Order) -> Order:
+        """Round and check an order against the instrument of its symbol.
+
+        Raises:
+            UnsupportedPairError: If the exchange does not list the symbol.
+            InvalidOrderError: If the order breaks the trading rules.
+        """
+        instrument = self.get(order.symbol)
+        return order if instrument is None else instrument.prepare_order(order)
+
+    def refresh(self) -> None:
+        """Reload the instruments from the exchange."""
+        instruments = self.loader()
+        with self._lock:
+            self._instruments = (
+                None
+                if instruments is None
+                else {item.symbol.upper(): item for item in instruments}
+            )
+            self._loaded_at = time.monotonic()
+
+    def _get_instruments(self) -> dict[str, Instrument] | None:
+        """Cached instruments by symbol, reloaded once expired."""
+        if self._loaded_at is None or time.monotonic() - self._loaded_at > self.ttl:
+            self.refresh()
+        return self._instruments
@@ -0,0 +1,52 @@
+from enum import Enum
+from typing import Self
+
+
+class InstrumentStatus(Enum):
+    """Trading status of an instrument.
+
+    Supported statuses:
+    - TRADING: Open for trading
+    - HALTED: Temporarily suspended (maintenance, auction, settlement)
+    - DELISTED: Removed or expired, no longer tradable
+    """
+
+    TRADING = {
+        "name": "trading",
+        "description": "Open for trading",
+    }
+    HALTED = {
+        "name": "halted",
+        "description": "Temporarily suspended",
+    }
+    DELISTED = {
+        "name": "delisted",
+        "description": "No longer tradable",
+    }
+
+    def get_name(self) -> str:
+        """Return the name of the instrument status."""
+        name = self.value["name"]
+        if isinstance(name, str):
+            return name
+        raise TypeError(
+            f"Instrument status name must be a string, got {type(name).__name__}"
+        )
+
+    def get_description(self) -> str:
+        """Return the description of the instrument status."""
+        description = self.value["description"]
+        if isinstance(description, str):
+            return description
+        raise TypeError(
+            f"Instrument status description must be a string, got {type(description).__name__}"
+        )
+
+    @classmethod
+    def get_instrument_status_from(cls, name: str) -> Self:
+        """Get the instrument status from a string."""
+        normalized_name = name.lower().strip()
+        for item in cls:
+            if item.get_name() == normalized_name:
+                return item
+        raise ValueError(f"Unknown instrument status: {name}")
@@ -0,0 +1,52 @@
+from enum import Enum
+from typing import Self
+
+
+class IntrabarPath(Enum):
+    """Order in which a backtest assumes a candle visited its prices.
+
+    Supported paths:
+    - NEAREST: Open, the extreme closer to the open, the other extreme, close
+    - OHLC: Open, high, low, close
+    - OLHC: Open, low, high, close
+    """
+
+    NEAREST = {
+        "name": "nearest",
+        "description": "Open, the extreme closer to the open, the other extreme, close",
+    }
+    OHLC = {
+        "name": "ohlc",
+        "description": "Open, high, low, close",
+    }
+    OLHC = {
+        "name": "olhc",
+        "description": "Open, low, high, close",
+    }
+
+    def get_name(self) -> str:
+        """Return the name of the intrabar path."""
+        name = self.value["name"]
+        if isinstance(name, str):
+            return name
+        raise TypeError(
+            f"Intrabar path name must be a string, got {type(name).__name__}"
+        )
+
+    def get_description(self) -> str:
+        """Return the description of the intrabar path."""
+        description = self.value["description"]
+        if isinstance(description, str):
+            return description
+        raise TypeError(
+            f"Intrabar path description must be a string, got {type(description).__name__}"
+        )
+
+    @classmethod
+    def get_intrabar_path_from(cls, name: str) -> Self:
+        """Get the intrabar path from a string."""
+        normalized_name = name.lower().strip()
+        for item in cls:
+            if item.get_name() == normalized_name:
+                return item
+        raise ValueError(f"Unknown intrabar path: {name}")
@@ -0,0 +1,52 @@
+from enum import Enum
+from typing import Self
+
+
+class OptimizationMethod(Enum):
+    """Search method of the parameter optimization.
+
+    Supported methods:
+    - GRID: Every combination of the parameter values
+    - RANDOM: Random combinations, up to a number of passes
+    - GENETIC: Genetic algorithm evolving the best combinations
+    """
+
+    GRID = {
+        "name": "grid",
+        "description": "Every combination of the parameter values",
+    }
+    RANDOM = {
+        "name": "random",
+        "description": "Random combinations of the parameter values",
+    }
+    GENETIC = {
+        "name": "genetic",
+        "description": "Genetic algorithm over the parameter values",
+    }
+
+    def get_name(self) -> str:
+        """Return the name of the optimization method."""
+        name = self.value["name"]
+        if isinstance(name, str):
+            return name
+        raise TypeError(
+            f"Optimization method name must be a string, got {type(name).__name__}"
+        )
+
+    def get_description(self) -> str:
+        """Return the description of the optimization method."""
+        description = self.value["description"]
+        if isinstance(description, str):
+            return description
+        raise TypeError(
+            f"Optimization method description must be a string, got {type(description).__name__}"
+        )
+
+    @classmethod
+    def get_optimization_method_from(cls, name: str) -> Self:
+        """Get the optimization method from a string."""
+        normalized_name = name.lower().strip()
+        for item in cls:
+            if item.get_name() == normalized_name:
+                return item
+        raise ValueError(f"Unknown optimization method: {name}")
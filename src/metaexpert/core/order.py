@@ -0,0 +1,105 @@
+"""Order"""
+
+from dataclasses import dataclass, field
+from datetime import UTC, datetime
+from typing import Any
+
+from .exceptions import InvalidOrderError
+from .order_side import OrderSide
+from .order_status import OrderStatus
+from .order_type import OrderType
+from .time_in_force import TimeInForce
+
+
+@dataclass
+class Order:
+    """Exchange-agnostic order model.
+
+    Adapters translate exchange responses into this model so that strategies
+    work with the same object regardless of the venue.
+    """
+
+    # --- Request ---
+    symbol: str
+    side: OrderSide
+    type: OrderType
+    quantity: float
+    price: float | None = None
+    stop_price: float | None = None
+    time_in_force: TimeInForce = TimeInForce.GTC
+    reduce_only: bool = False
+    post_only: bool = False
+    client_order_id: str | None = None
+    #
+    # --- State ---
+    id: str | None = None
+    status: OrderStatus = OrderStatus.NEW
+    filled_quantity: float = 0.0
+    average_price: float | None = None
+    created_at: datetime = field(default_factory=lambda: datetime.now(UTC))
+    updated_at: datetime | None = None
+
+    @property
+    def is_open(self) -> bool:
+        """Check if the order can still be executed."""
+        return self.status.is_open()
+
+    @property
+    def is_filled(self) -> bool:
+        """Check if the order is fully executed."""
+        return self.status is OrderStatus.FILLED
+
+    @property
+    def remaining_quantity(self) -> float:
+        """Quantity that has not been executed yet."""
+        return max(self.quantity - self.filled_quantity, 0.0)
+
+    def validate(self) -> None:
+        """Validate the order request.
+
+        Raises:
+            InvalidOrderError: If the combination of parameters is invalid.
+        """
+        if not self.symbol:
+            raise InvalidOrderError(self.to_dict(), "Order symbol is required")
+        if self.quantity <= 0:
+            raise InvalidOrderError(
+                self.to_dict(), f"Order quantity must be positive, got {self.quantity}"
+            )
+        if self.type.requires_price() and (self.price is None or self.price <= 0):
+            raise InvalidOrderError(
+                self.to_dict(),
+                f"Order type '{self.type.get_name()}' requires a positive price",
+            )
+        if self.type.requires_stop_price() and (
+            self.stop_price is None or self.stop_price <= 0
+        ):
+            raise InvalidOrderError(
+                self.to_dict(),
+                f"Order type '{self.type.get_name()}' requires a positive stop price",
+            )
+        if self.post_only and self.type is not OrderType.LIMIT:
+            raise InvalidOrderError(
+                self.to_dict(), "Post-only is supported for limit orders only"
+            )
+
+    def to_dict(self) -> dict[str, Any]:
+        """Return a plain dictionary representation (used for event payloads)."""
+        return {
+            "id": self.id,
+            "client_order_id": self.client_order_id,
+            "symbol": self.symbol,
+            "side": self.side.get_name(),
+            "type": self.type.get_name(),
+            "time_in_force": self.time_in_force.get_name(),
+            "price": self.price,
+            "stop_price": self.stop_price,
+            "quantity": self.quantity,
+            "filled_quantity": self.filled_quantity,
+            "average_price": self.average_price,
+            "reduce_only": self.reduce_only,
+            "post_only": self.post_only,
+            "status": self.status.get_name(),
+            "created_at": self.created_at.isoformat(),
+            "updated_at": self.updated_at.isoformat() if self.updated_at else None,
+        }
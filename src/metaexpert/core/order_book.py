@@ -0,0 +1,231 @@
+"""Order book"""
+
+from collections.abc import Callable
+from dataclasses import dataclass, field
+from datetime import UTC, datetime
+from typing import Any
+
+from .exceptions import OrderBookOutOfSyncError
+
+# Price level as reported by the exchange: (price, size) decimal strings
+RawLevel = tuple[str, str]
+
+# Checksum of the top levels of a book: (bids, asks), best first
+BookChecksum = Callable[[list[RawLevel], list[RawLevel]], int]
+
+
+@dataclass(frozen=True)
+class OrderBook:
+    """Top levels of an order book, passed to the `on_book` handler.
+
+    Bids are sorted by decreasing price and asks by increasing price, so the
+    first level of each side is the best one.
+    """
+
+    symbol: str
+    bids: tuple[tuple[float, float], ...]
+    asks: tuple[tuple[float, float], ...]
+    time: datetime = field(default_factory=lambda: datetime.now(UTC))
+    sequence: int | None = None
+
+    @property
+    def best_bid(self) -> float | None:
+        """Highest bid price, `None` if there is no bid."""
+        return self.bids[0][0] if self.bids else None
+
+    @property
+    def best_ask(self) -> float | None:
+        """Lowest ask price, `None` if there is no ask."""
+        return self.asks[0][0] if self.asks else None
+
+    @property
+    def mid_price(self) -> float | None:
+        """Mean of the best bid and ask prices."""
+        if self.best_bid is None or self.best_ask is None:
+            return None
+        return (self.best_bid + self.best_ask) / 2
+
+    @property
+    def spread(self) -> float | None:
+        """Difference between the best ask and bid prices."""
+        if self.best_bid is None or self.best_ask is None:
+            return None
+        return self.best_ask - self.best_bid
+
+    @property
+    def spread_pct(self) -> float | None:
+        """Spread in percent of the mid price."""
+        spread, mid_price = self.spread, self.mid_price
+        if spread is None or not mid_price:
+            return None
+        return spread / mid_price * 100
+
+    def to_dict(self) -> dict[str, Any]:
+        """Convert the order book to a dictionary."""
+        return {
+            "symbol": self.symbol,
+            "bids": [list(level) for level in self.bids],
+            "asks": [list(level) for level in self.asks],
+            "best_bid": self.best_bid,
+            "best_ask": self.best_ask,
+            "spread": self.spread,
+            "spread_pct": self.spread_pct,
+            "time": self.time,
+            "sequence": self.sequence,
+        }
+
+
+@dataclass(frozen=True)
+class OrderBookUpdate:
+    """Exchange-agnostic snapshot or diff of an order book.
+
+    The levels keep the decimal strings of the exchange, as checksums are
+    computed on them; a level with a zero size removes the price. The update
+    ids describe the sequence of the diffs:
+
+    - `last_id`: id of the last change included in the update.
+    - `first_id`: id of the first change included (Binance `U`, Bybit `u`).
+    - `prev_id`: `last_id` of the previous update (Binance futures `pu`,
+      OKX `prevSeqId`), checked instead of `first_id` when set.
+    """
+
+    symbol: str
+    bids: tuple[RawLevel, ...]
+    asks: tuple[RawLevel, ...]
+    time: datetime
+    is_snapshot: bool = False
+    first_id: int | None = None
+    last_id: int | None = None
+    prev_id: int | None = None
+    checksum: int | None = None
+
+
+class LocalOrderBook:
+    """Order book of a symbol kept from a snapshot and the following diffs.
+
+    Every diff must follow the previous update, otherwise
+    `OrderBookOutOfSyncError` is raised and a new snapshot is needed. The
+    first diff after a snapshot only has to cover the next update id, and
+    older diffs, already part of the snapshot, are ignored. When the
+    exchange sends checksums, the top levels are verified after every update.
+    """
+
+    def __init__(self, symbol: str, checksum: BookChecksum | None = None) -> None:
+        """Initialize an empty book.
+
+        Args:
+            symbol (str): Trading symbol.
+            checksum (BookChecksum | None): Checksum of the exchange, if it sends one.
+        """
+        self.symbol: str = symbol
+        self.checksum: BookChecksum | None = checksum
+        self.last_id: int | None = None
+        self.time: datetime | None = None
+        self._bids: dict[float, RawLevel] = {}
+        self._asks: dict[float, RawLevel] = {}
+        self._is_synced: bool = False
+        self._is_continuous: bool = False
+
+    @property
+    def is_synced(self) -> bool:
+        """Whether the book was initialized by a snapshot."""
+        return self._is_synced
+
+    def reset(self) -> None:
+        """Clear the book, until the next snapshot."""
+        self._bids.clear()
+        self._asks.clear()
+        self.last_id = None
+        self.time = None
+        self._is_synced = False
+        self._is_continuous = False
+
+    def apply(self, update: OrderBookUpdate) -> bool:
+        """Apply a snapshot or a diff.
+
+        Returns:
+            bool: False if the diff was ignored, being older than the book.
+
+        Raises:
+            OrderBookOutOfSyncError: If the diff does not follow the book or
+                the checksum does not match.
+        """
+        if update.is_snapshot:
+            self.reset()
+            self._is_synced = True
+        elif not self._is_synced:
+            raise OrderBookOutOfSyncError(
+                self.symbol, f"Order book of {self.symbol} has no snapshot"
+            )
+        elif self.last_id is not None and update.last_id is not None:
+            if update.last_id <= self.last_id and update.prev_id != self.last_id:
+                return False
+            self._check_sequence(update, self.last_id)
+
+        self._update_side(self._bids, update.bids)
+        self._update_side(self._asks, update.asks)
+        self.last_id = update.last_id
+        self.time = update.time
+        self._is_continuous = not update.is_snapshot
+
+        if update.checksum is not None and self.checksum is not None:
+            bids, asks = self._get_raw_levels()
+            if self.checksum(bids, asks) != update.checksum:
+                raise OrderBookOutOfSyncError(
+                    self.symbol, f"Order book checksum mismatch for {self.symbol}"
+                )
+        return True
+
+    def get_order_book(self, depth: int | None = None) -> OrderBook:
+        """Top levels of the book.
+
+        Args:
+            depth (int | None): Number of levels per side, all if omitted.
+        """
+        bids = sorted(self._bids, reverse=True)[:depth]
+        asks = sorted(self._asks)[:depth]
+        return OrderBook(
+            symbol=self.symbol,
+            bids=tuple((price, float(self._bids[price][1])) for price in bids),
+            asks=tuple((price, float(self._asks[price][1])) for price in asks),
+            time=self.time or datetime.now(UTC),
+            sequence=self.last_id,
+        )
+
+    def _check_sequence(self, update: OrderBookUpdate, last_id: int) -> None:
+        """Check that a diff follows the last update of the book."""
+        if update.prev_id is not None and self._is_continuous:
+            follows = update.prev_id == last_id
+        elif update.first_id is not None:
+            # The first diff after a snapshot may start before its last id
+            next_id = last_id + 1
+            follows = (
+                update.first_id <= next_id <= update.last_id
+                if not self._is_continuous
+                else update.first_id == next_id
+            )
+        else:
+            follows = update.prev_id is None or update.prev_id == last_id
+
+        if not follows:
+            raise OrderBookOutOfSyncError(
+                self.symbol,
+                f"Order book of {self.symbol} skipped updates after {last_id}",
+            )
+
+    def _get_raw_levels(self) -> tuple[list[RawLevel], list[RawLevel]]:
+        """Levels as reported by the exchange, best first."""
+        bids = [self._bids[price] for price in sorted(self._bids, reverse=True)]
+        asks = [self._asks[price] for price in sorted(self._asks)]
+        return bids, asks
+
+    @staticmethod
+    def _update_side(
+        side: dict[float, RawLevel], levels: tuple[RawLevel, ...]
+    ) -> None:
+        """Set the size of the updated prices, removing the empty ones."""
+        for price, size in levels:
+            if float(size) == 0:
+                side.pop(float(price), None)
+            else:
+                side[float(price)] = (price, size)
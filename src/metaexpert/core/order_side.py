@@ -0,0 +1,53 @@
+from enum import Enum
+from typing import Self
+
+
+class OrderSide(Enum):
+    """Order side enumeration.
+
+    Supported order sides:
+    - BUY: Buy order (opens long / closes short)
+    - SELL: Sell order (opens short / closes long)
+    """
+
+    BUY = {
+        "name": "buy",
+        "description": "Buy order",
+    }
+    SELL = {
+        "name": "sell",
+        "description": "Sell order",
+    }
+
+    def get_name(self) -> str:
+        """Return the name of the order side."""
+        name = self.value["name"]
+        if isinstance(name, str):
+            return name
+        raise TypeError(f"Order side name must be a string, got {type(name).__name__}")
+
+    def get_description(self) -> str:
+        """Return the description of the order side."""
+        description = self.value["description"]
+        if isinstance(description, str):
+            return description
+        raise TypeError(
+            f"Order side description must be a string, got {type(description).__name__}"
+        )
+
+    def opposite(self) -> "OrderSide":
+        """Return the opposite order side."""
+        return OrderSide.SELL if self is OrderSide.BUY else OrderSide.BUY
+
+    @classmethod
+    def get_order_side_from(cls, name: str) -> Self:
+        """Get the order side from a string.
+
+        Unlike other enumerations there is no default side: an unknown value
+        raises instead of silently trading in the wrong direction.
+        """
+        normalized_name = name.lower().strip()
+        for item in cls:
+            if item.get_name() == normalized_name:
+                return item
+        raise ValueError(f"Unknown order side: {name}")
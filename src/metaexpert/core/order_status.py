@@ -0,0 +1,77 @@
+from enum import Enum
+from typing import Self
+
+
+class OrderStatus(Enum):
+    """Order status enumeration.
+
+    Supported order statuses:
+    - NEW: Accepted and waiting for execution
+    - PARTIALLY_FILLED: Partially executed
+    - FILLED: Fully executed
+    - CANCELED: Cancelled before full execution
+    - REJECTED: Rejected by the exchange
+    - EXPIRED: Expired by its time-in-force policy
+    """
+
+    NEW = {
+        "name": "new",
+        "description": "Accepted and waiting for execution",
+        "is_open": True,
+    }
+    PARTIALLY_FILLED = {
+        "name": "partially_filled",
+        "description": "Partially executed",
+        "is_open": True,
+    }
+    FILLED = {
+        "name": "filled",
+        "description": "Fully executed",
+        "is_open": False,
+    }
+    CANCELED = {
+        "name": "canceled",
+        "description": "Cancelled before full execution",
+        "is_open": False,
+    }
+    REJECTED = {
+        "name": "rejected",
+        "description": "Rejected by the exchange",
+        "is_open": False,
+    }
+    EXPIRED = {
+        "name": "expired",
+        "description": "Expired by its time-in-force policy",
+        "is_open": False,
+    }
+
+    def get_name(self) -> str:
+        """Return the name of the order status."""
+        name = self.value["name"]
+        if isinstance(name, str):
+            return name
+        raise TypeError(
+            f"Order status name must be a string, got {type(name).__name__}"
+        )
+
+    def get_description(self) -> str:
+        """Return the description of the order status."""
+        description = self.value["description"]
+        if isinstance(description, str):
+            return description
+        raise TypeError(
+            f"Order status description must be a string, got {type(description).__name__}"
+        )
+
+    def is_open(self) -> bool:
+        """Check if an order with this status can still be executed."""
+        return bool(self.value["is_open"])
+
+    @classmethod
+    def get_order_status_from(cls, name: str) -> Self:
+        """Get the order status from a string."""
+        normalized_name = name.lower().strip()
+        for item in cls:
+            if item.get_name() == normalized_name:
+                return item
+        raise ValueError(f"Unknown order status: {name}")
@@ -0,0 +1,81 @@
+from enum import Enum
+from typing import Self
+
+
+class OrderType(Enum):
+    """Order type enumeration.
+
+    Supported order types:
+    - MARKET: Execute immediately at the best available price
+    - LIMIT: Execute at the given price or better
+    - STOP: Market order triggered at the stop price
+    - STOP_LIMIT: Limit order triggered at the stop price
+    - TAKE_PROFIT: Market order triggered at the take-profit price
+    """
+
+    MARKET = {
+        "name": "market",
+        "description": "Market order",
+        "price": False,
+        "stop_price": False,
+    }
+    LIMIT = {
+        "name": "limit",
+        "description": "Limit order",
+        "price": True,
+        "stop_price": False,
+    }
+    STOP = {
+        "name": "stop",
+        "description": "Stop market order",
+        "price": False,
+        "stop_price": True,
+    }
+    STOP_LIMIT = {
+        "name": "stop_limit",
+        "description": "Stop limit order",
+        "price": True,
+        "stop_price": True,
+    }
+    TAKE_PROFIT = {
+        "name": "take_profit",
+        "description": "Take-profit market order",
+        "price": False,
+        "stop_price": True,
+    }
+
+    def get_name(self) -> str:
+        """Return the name of the order type."""
+        name = self.value["name"]
+        if isinstance(name, str):
+            return name
+        raise TypeError(f"Order type name must be a string, got {type(name).__name__}")
+
+    def get_description(self) -> str:
+        """Return the description of the order type."""
+        description = self.value["description"]
+        if isinstance(description, str):
+            return description
+        raise TypeError(
+            f"Order type description must be a string, got {type(description).__name__}"
+        )
+
+    def requires_price(self) -> bool:
+        """Check if this order type requires a limit price."""
+        return bool(self.value["price"])
+
+    def requires_stop_price(self) -> bool:
+        """Check if this order type requires a trigger (stop) price."""
+        return bool(self.value["stop_price"])
+
+    @classmethod
+    def get_order_type_from(cls, name: str) -> Self:
+        """Get the order type from a string ("stop-limit" and "stop_limit" alike).
+
+        An unknown value raises instead of silently sending a market order.
+        """
+        normalized_name = name.lower().strip().replace("-", "_")
+        for item in cls:
+            if item.get_name() == normalized_name:
+                return item
+        raise ValueError(f"Unknown order type: {name}")
@@ -0,0 +1,131 @@
+"""Position"""
+
+from dataclasses import dataclass, field
+from datetime import UTC, datetime
+from typing import Any
+
+from .contract_type import ContractType
+from .margin_mode import MarginMode
+from .position_side import PositionSide
+
+
+@dataclass
+class Position:
+    """Exchange-agnostic position model.
+
+    The size is always non-negative; the direction is given by `side`. The
+    size of an inverse (coin-margined) position is its face value in the
+    quote currency, and its PnL and margin are in the base coin.
+    """
+
+    symbol: str
+    side: PositionSide
+    size: float = 0.0
+    entry_price: float = 0.0
+    mark_price: float = 0.0
+    unrealized_pnl: float = 0.0
+    realized_pnl: float = 0.0
+    leverage: int = 1
+    liquidation_price: float | None = None
+    margin_mode: MarginMode = MarginMode.ISOLATED
+    contract_type: ContractType = ContractType.LINEAR
+    updated_at: datetime = field(default_factory=lambda: datetime.now(UTC))
+
+    @property
+    def is_open(self) -> bool:
+        """Check if the position has a non-zero size."""
+        return self.size > 0
+
+    @property
+    def signed_size(self) -> float:
+        """Size with sign: positive for long, negative for short."""
+        return self.size * self.side.get_sign()
+
+    @property
+    def is_inverse(self) -> bool:
+        """Check if the position is coin-margined."""
+        return self.contract_type is ContractType.INVERSE
+
+    @property
+    def notional(self) -> float:
+        """Position value in the quote currency at the mark price (entry price if no mark yet)."""
+        if self.is_inverse:
+            return self.size
+        return self.size * (self.mark_price or self.entry_price)
+
+    @property
+    def margin(self) -> float:
+        """Initial margin of the position, in the settlement currency."""
+        return self.get_value(self.entry_price) / max(self.leverage, 1)
+
+    def update_mark_price(self, price: float) -> None:
+        """Update the mark price and recalculate the unrealized PnL."""
+        self.mark_price = price
+        self.unrealized_pnl = self.get_pnl(price)
+        self.updated_at = datetime.now(UTC)
+
+    def get_pnl(self, price: float, size: float | None = None) -> float:
+        """Calculate the PnL of closing `size` (the whole position by default) at `price`."""
+        size = self.size if size is None else size
+        if self.is_inverse:
+            if price <= 0 or self.entry_price <= 0:
+                return 0.0
+            return (1 / self.entry_price - 1 / price) * size * self.side.get_sign()
+        return (price - self.entry_price) * size * self.side.get_sign()
+
+    def get_value(self, price: float, size: float | None = None) -> float:
+        """Value of `size` (the whole position by default) at `price`, in the settlement currency."""
+        size = self.size if size is None else size
+        if self.is_inverse:
+            return size / price if price > 0 else 0.0
+        return size * price
+
+    def get_liquidation_price(
+        self, margin: float, maintenance_margin_rate: float
+    ) -> float | None:
+        """Price at which the margin left no longer covers the maintenance margin.
+
+        Args:
+            margin (float): Collateral of the position: its own margin in isolated
+                mode, the free balance of the account in cross mode.
+            maintenance_margin_rate (float): Maintenance margin as a share of the
+                position value (0.005 = 0.5%).
+
+        Returns:
+            float | None: The liquidation price, None if the position cannot be liquidated.
+        """
+        if not self.is_open or self.entry_price <= 0:
+            return None
+
+        size, entry, rate = self.size, self.entry_price, maintenance_margin_rate
+        if self.is_inverse:
+            # margin + size * (1/entry - 1/price) * sign = rate * size / price
+            if self.side is PositionSide.LONG:
+                denominator = margin + size / entry
+                price = size * (1 + rate) / denominator if denominator > 0 else 0.0
+            else:
+                denominator = size / entry - margin
+                price = size * (1 - rate) / denominator if denominator > 0 else 0.0
+        elif self.side is PositionSide.LONG:
+            # margin + (price - entry) * size * sign = rate * size * price
+            price = (entry * size - margin) / (size * (1 - rate))
+        else:
+            price = (entry * size + margin) / (size * (1 + rate))
+        return price if price > 0 else None
+
+    def to_dict(self) -> dict[str, Any]:
+        """Return a plain dictionary representation (used for event payloads)."""
+        return {
+            "symbol": self.symbol,
+            "side": self.side.get_name(),
+            "size": self.size,
+            "entry_price": self.entry_price,
+            "mark_price": self.mark_price,
+            "unrealized_pnl": self.unrealized_pnl,
+            "realized_pnl": self.realized_pnl,
+            "leverage": self.leverage,
+            "liquidation_price": self.liquidation_price,
+            "margin_mode": self.margin_mode.get_name(),
+            "contract_type": self.contract_type.get_name(),
+            "updated_at": self.updated_at.isoformat(),
+        }
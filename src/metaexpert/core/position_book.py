@@ -0,0 +1,247 @@
+"""Position book"""
+
+from threading import RLock
+
+from metaexpert.logger import MetaLogger as Logger, get_logger
+
+from .contract_type import ContractType
+from .margin_mode import MarginMode
+from .order_side import OrderSide
+from .position import Position
+from .position_mode import PositionMode
+from .position_side import PositionSide
+
+
+class PositionBook:
+    """Local book of positions kept per symbol.
+
+    In ONEWAY mode a symbol holds at most one net position: an opposite fill
+    reduces it and may flip it to the other side. In HEDGE mode long and short
+    positions of the same symbol are tracked independently. The positions
+    of an inverse book are coin-margined.
+    """
+
+    def __init__(
+        self,
+        position_mode: PositionMode = PositionMode.ONEWAY,
+        contract_type: ContractType = ContractType.LINEAR,
+    ) -> None:
+        self.position_mode: PositionMode = position_mode
+        self.contract_type: ContractType = contract_type
+        self._positions: dict[str, dict[PositionSide, Position]] = {}
+        self._lock: RLock = RLock()
+        self.logger: Logger = get_logger("PositionBook")
+
+    def apply_fill(
+        self,
+        symbol: str,
+        side: OrderSide,
+        quantity: float,
+        price: float,
+        *,
+        reduce_only: bool = False,
+        position_side: PositionSide | None = None,
+        leverage: int = 1,
+        margin_mode: MarginMode = MarginMode.ISOLATED,
+    ) -> tuple[Position, float]:
+        """Apply an executed trade to the book.
+
+        Args:
+            symbol (str): Trading symbol.
+            side (OrderSide): Side of the executed order.
+            quantity (float): Executed quantity.
+            price (float): Execution price.
+            reduce_only (bool): The fill may only reduce a position.
+            position_side (PositionSide | None): Target position in HEDGE mode
+                (derived from `side` and `reduce_only` when omitted).
+            leverage (int): Leverage applied to newly opened positions.
+            margin_mode (MarginMode): Margin mode applied to newly opened positions.
+
+        Returns:
+            tuple[Position, float]: The affected position and the PnL realized by the fill.
+        """
+        if quantity <= 0:
+            raise ValueError(f"Fill quantity must be positive, got {quantity}")
+
+        with self._lock:
+            if self.position_mode is PositionMode.HEDGE:
+                if position_side is None:
+                    opening_side = side.opposite() if reduce_only else side
+                    position_side = PositionSide.get_position_side_from(
+                        opening_side.get_name()
+                    )
+                position = self._get_or_create(
+                    symbol, position_side, leverage, margin_mode
+                )
+                if side is position_side.get_open_side():
+                    self._increase(position, quantity, price)
+                    return position, 0.0
+                closed = min(quantity, position.size)
+                return position, self._reduce(position, closed, price)
+
+            position = self._get_open(symbol)
+            if position is None or side is position.side.get_open_side():
+                if position is None:
+                    if reduce_only:
+                        raise ValueError(f"No open position to reduce for {symbol}")
+                    position = self._get_or_create(
+                        symbol,
+                        PositionSide.get_position_side_from(side.get_name()),
+                        leverage,
+                        margin_mode,
+                    )
+                self._increase(position, quantity, price)
+                return position, 0.0
+
+            closed = min(quantity, position.size)
+            realized = self._reduce(position, closed, price)
+            remainder = quantity - closed
+            if remainder > 0 and not reduce_only:
+                position = self._get_or_create(
+                    symbol, position.side, leverage, margin_mode, flip=True
+                )
+                self._increase(position, remainder, price)
+            return position, realized
+
+    def sync(self, position: Position) -> None:
+        """Replace the local state of a position with the exchange state."""
+        with self._lock:
+            sides = self._positions.setdefault(position.symbol, {})
+            if self.position_mode is PositionMode.ONEWAY:
+                # The net position replaces the other side, even once flat
+                sides.clear()
+            sides[position.side] = position
+
+    def update_mark_price(self, symbol: str, price: float) -> None:
+        """Update the mark price of all positions of a symbol."""
+        with self._lock:
+            for position in self._positions.get(symbol, {}).values():
+                if position.is_open:
+                    position.update_mark_price(price)
+
+    def get_position(
+        self, symbol: str, side: PositionSide | None = None
+    ) -> Position | None:
+        """Get an open position of a symbol.
+
+        Without `side` the first open position is returned, which in ONEWAY
+        mode is the net position of the symbol.
+        """
+        with self._lock:
+            for position in self._positions.get(symbol, {}).values():
+                if position.is_open and (side is None or position.side is side):
+                    return position
+            return None
+
+    def get_positions(self, symbol: str | None = None) -> list[Position]:
+        """Get all open positions, optionally filtered by symbol."""
+        with self._lock:
+            return [
+                position
+                for key, sides in self._positions.items()
+                if symbol is None or key == symbol
+                for position in sides.values()
+                if position.is_open
+            ]
+
+    def get_net_size(self, symbol: str) -> float:
+        """Net size of a symbol: long size minus short size."""
+        return sum(position.signed_size for position in self.get_positions(symbol))
+
+    def get_exposure(self, symbol: str | None = None) -> float:
+        """Net notional exposure (long minus short), optionally for one symbol."""
+        return sum(
+            position.notional * position.side.get_sign()
+            for position in self.get_positions(symbol)
+        )
+
+    def clear(self, symbol: str | None = None) -> None:
+        """Remove all positions, optionally for one symbol only."""
+        with self._lock:
+            if symbol is None:
+                self._positions.clear()
+            else:
+                self._positions.pop(symbol, None)
+
+    def _get_open(self, symbol: str) -> Position | None:
+        """Get the open net position of a symbol (ONEWAY mode)."""
+        for position in self._positions.get(symbol, {}).values():
+            if position.is_open:
+                return position
+        return None
+
+    def _get_or_create(
+        self,
+        symbol: str,
+        side: PositionSide,
+        leverage: int,
+        margin_mode: MarginMode,
+        *,
+        flip: bool = False,
+    ) -> Position:
+        """Get a position slot, creating a fresh one if it is flat."""
+        if flip:
+            side = (
+                PositionSide.SHORT if side is PositionSide.LONG else PositionSide.LONG
+            )
+
+        sides = self._positions.setdefault(symbol, {})
+        position = sides.get(side)
+        if position is None or not position.is_open:
+            position = Position(
+                symbol=symbol,
+                side=side,
+                leverage=leverage,
+                margin_mode=margin_mode,
+                contract_type=self.contract_type,
+            )
+            sides[side] = position
+        return position
+
+    def _increase(self, position: Position, quantity: float, price: float) -> None:
+        """Increase a position, averaging the entry price.
+
+        The entry price of an inverse position is the harmonic mean of its
+        fill prices, so that its coin-margined PnL stays additive.
+        """
+        total = position.size + quantity
+        if position.is_inverse and position.size > 0:
+            position.entry_price = total / (
+                position.size / position.entry_price + quantity / price
+            )
+        else:
+            position.entry_price = (
+                position.entry_price * position.size + price * quantity
+            ) / total
+        position.size = total
+        position.update_mark_price(price)
+        self.logger.debug(
+            "Position %s %s increased to %s @ %s",
+            position.symbol,
+            position.side.get_name(),
+            position.size,
+            position.entry_price,
+        )
+
+    def _reduce(self, position: Position, quantity: float, price: float) -> float:
+        """Reduce a position and return the realized PnL."""
+        if quantity <= 0:
+            return 0.0
+
+        realized = position.get_pnl(price, quantity)
+        position.size -= quantity
+        position.realized_pnl += realized
+        if position.size <= 1e-12:
+            position.size = 0.0
+            position.unrealized_pnl = 0.0
+        else:
+            position.update_mark_price(price)
+        self.logger.debug(
+            "Position %s %s reduced by %s @ %s, realized PnL: %s",
+            position.symbol,
+            position.side.get_name(),
+            quantity,
+            price,
+            realized,
+        )
+        return realized
@@ -0,0 +1,68 @@
+from enum import Enum
+from typing import Self
+
+from .order_side import OrderSide
+
+
+class PositionSide(Enum):
+    """Position side enumeration.
+
+    Supported position sides:
+    - LONG: Long position (profits when the price rises)
+    - SHORT: Short position (profits when the price falls)
+    """
+
+    LONG = {
+        "name": "long",
+        "description": "Long position",
+        "sign": 1,
+    }
+    SHORT = {
+        "name": "short",
+        "description": "Short position",
+        "sign": -1,
+    }
+
+    def get_name(self) -> str:
+        """Return the name of the position side."""
+        name = self.value["name"]
+        if isinstance(name, str):
+            return name
+        raise TypeError(
+            f"Position side name must be a string, got {type(name).__name__}"
+        )
+
+    def get_description(self) -> str:
+        """Return the description of the position side."""
+        description = self.value["description"]
+        if isinstance(description, str):
+            return description
+        raise TypeError(
+            f"Position side description must be a string, got {type(description).__name__}"
+        )
+
+    def get_sign(self) -> int:
+        """Return the PnL direction of the position side (1 or -1)."""
+        sign = self.value["sign"]
+        if isinstance(sign, int):
+            return sign
+        raise TypeError(
+            f"Position side sign must be an integer, got {type(sign).__name__}"
+        )
+
+    def get_open_side(self) -> OrderSide:
+        """Return the order side that opens (increases) a position on this side."""
+        return OrderSide.BUY if self is PositionSide.LONG else OrderSide.SELL
+
+    def get_close_side(self) -> OrderSide:
+        """Return the order side that closes (reduces) a position on this side."""
+        return self.get_open_side().opposite()
+
+    @classmethod
+    def get_position_side_from(cls, name: str) -> Self:
+        """Get the position side from a string ('long'/'short' or 'buy'/'sell')."""
+        normalized_name = name.lower().strip()
+        for item in cls:
+            if normalized_name in (item.get_name(), item.get_open_side().get_name()):
+                return item
+        raise ValueError(f"Unknown position side: {name}")
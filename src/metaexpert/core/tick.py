@@ -0,0 +1,33 @@
+"""Tick"""
+
+from dataclasses import dataclass, field
+from datetime import datetime
+from typing import Any
+
+from .order_side import OrderSide
+
+
+@dataclass(frozen=True)
+class Tick:
+    """Exchange-agnostic trade tick.
+
+    `side` is the side of the taker, when the exchange reports it.
+    """
+
+    symbol: str
+    time: datetime
+    price: float
+    volume: float = 0.0
+    side: OrderSide | None = None
+    extra: dict[str, Any] = field(default_factory=dict, compare=False)
+
+    def to_dict(self) -> dict[str, Any]:
+        """Return the rates dictionary passed to the `on_tick` handler."""
+        return {
+            "symbol": self.symbol,
+            "time": self.time,
+            "price": self.price,
+            "volume": self.volume,
+            "side": self.side.get_name() if self.side is not None else None,
+            **self.extra,
+        }
@@ -0,0 +1,55 @@
+from enum import Enum
+from typing import Self
+
+
+class TimeInForce(Enum):
+    """Time-in-force enumeration for limit orders.
+
+    Supported policies:
+    - GTC: Good till cancelled
+    - IOC: Immediate or cancel
+    - FOK: Fill or kill
+    """
+
+    GTC = {
+        "name": "gtc",
+        "description": "Good till cancelled",
+    }
+    IOC = {
+        "name": "ioc",
+        "description": "Immediate or cancel",
+    }
+    FOK = {
+        "name": "fok",
+        "description": "Fill or kill",
+    }
+
+    def get_name(self) -> str:
+        """Return the name of the time in force."""
+        name = self.value["name"]
+        if isinstance(name, str):
+            return name
+        raise TypeError(
+            f"Time in force name must be a string, got {type(name).__name__}"
+        )
+
+    def get_description(self) -> str:
+        """Return the description of the time in force."""
+        description = self.value["description"]
+        if isinstance(description, str):
+            return description
+        raise TypeError(
+            f"Time in force description must be a string, got {type(description).__name__}"
+        )
+
+    @classmethod
+    def get_time_in_force_from(cls, name: str) -> Self:
+        """Get the time in force from a string.
+
+        An unknown value raises instead of silently keeping the order open.
+        """
+        normalized_name = name.lower().strip()
+        for item in cls:
+            if item.get_name() == normalized_name:
+                return item
+        raise ValueError(f"Unknown time in force: {name}")
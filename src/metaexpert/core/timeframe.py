@@ -1,4 +1,4 @@
-from datetime import datetime, timedelta
+from datetime import UTC, datetime, timedelta
 from enum import Enum
 from typing import Self
 
@@ -167,6 +167,20 @@ class Timeframe(Enum):
             f"Timeframe delta must be a timedelta, got {type(delta).__name__}"
         )
 
+    def get_candle_open_time(self, time: datetime) -> datetime:
+        """Get the open time of the candle containing a given (aware) time.
+
+        Weekly candles open on Monday 00:00 UTC, the others are aligned to the
+        Unix epoch.
+        """
+        time = time.astimezone(UTC)
+        if self.get_name()[-1] == "w":
+            monday = time - timedelta(days=time.weekday())
+            return monday.replace(hour=0, minute=0, second=0, microsecond=0)
+
+        seconds = self.get_seconds()
+        return datetime.fromtimestamp(time.timestamp() // seconds * seconds, UTC)
+
     @classmethod
     def get_timeframe_from(cls, name: str) -> Self:
         """Get the period type from a string."""
@@ -210,15 +224,18 @@ class Timeframe(Enum):
         ) * timeframe_seconds
         # Add one interval to get the start of the next candle
         next_candle_start_ts = current_candle_start_ts + timeframe_seconds
-        return datetime.fromtimestamp(next_candle_start_ts)
+        return datetime.fromtimestamp(next_candle_start_ts, UTC)
 
-    def get_next_candle_time(self) -> datetime:
+    def get_next_candle_time(self, time: datetime | None = None) -> datetime:
         """Calculate the timestamp of the next candle based on the timeframe.
 
+        Args:
+            time (datetime | None): Reference (aware) time, now by default.
+
         Returns:
-            datetime: Timestamp of the next candle
+            datetime: Open time of the next candle, in UTC
         """
-        now = datetime.now()
+        now = time.astimezone(UTC) if time is not None else datetime.now(UTC)
         name = self.get_name()
         if name[-1] == "w":
             return self._get_next_weekly_candle_time(now)
@@ -1,14 +1,31 @@
 """Trade"""
 
 from abc import ABC, abstractmethod
+from collections.abc import Callable
 
+from metaexpert.config import DEFAULT_TIME_IN_FORCE
 from metaexpert.logger import MetaLogger as Logger
 from metaexpert.logger import get_logger
 
+from .margin_mode import MarginMode
+from .order import Order
+from .order_side import OrderSide
+from .order_type import OrderType
+from .position import Position
+from .position_side import PositionSide
+from .time_in_force import TimeInForce
+
 
 class Trade(ABC):
     """Trade"""
 
+    # Pre-trade check of the new entries: returns the reason blocking one, if any
+    entry_check: Callable[[str, OrderSide, float], str | None] | None = None
+    # Whether reduce-only stop and take-profit orders can protect the positions
+    supports_protective_orders: bool = False
+    # Receiver of the stop-loss, take-profit and trailing stop distances of `trade()`
+    protection_update: Callable[[float, float, float], None] | None = None
+
     def __init__(self, symbol: str, **kwargs) -> None:
         self.logger: Logger = get_logger("Trade")
         self._fee: float = 0
@@ -31,7 +48,6 @@ class Trade(ABC):
     def fee(self, fee: float) -> None:
         self._fee = fee
 
-    @abstractmethod
     def trade(
         self,
         *,
@@ -43,49 +59,223 @@ class Trade(ABC):
         slippage: int = 0,
         fee: float = 0,
     ) -> None:
+        """Set the trading parameters of the next positions.
+
+        The stop-loss, take-profit and trailing stop distances are handed to
+        the protection manager of the expert, 0 keeping the current one.
+
+        Args:
+            stop_loss (float): Stop-loss distance from the entry (%).
+            take_profit (float): Take-profit distance from the entry (%).
+            trailing_stop (float): Trailing stop distance from the best price (%).
+            fee (float): Fee rate.
+        """
         # self._lots = lots
-        # self._stop_loss = stop_loss
-        # self._take_profit = take_profit
-        # self._trailing_stop = trailing_stop
         # self._positions = positions
         # self._slippage = slippage
         self._fee = fee
-        pass
+        if self.protection_update is not None:
+            self.protection_update(stop_loss, take_profit, trailing_stop)
 
     # POSITION
-    @abstractmethod
-    def open_position(self, side: str, fee: float) -> bool:
-        return False
+    def open_position(
+        self,
+        symbol: str,
+        side: str | PositionSide,
+        quantity: float,
+        *,
+        price: float | None = None,
+    ) -> Order | None:
+        """Open or increase a position with a market order (limit if `price` is given).
 
-    @abstractmethod
-    def close_position(self, side: str) -> bool:
-        return False
+        Args:
+            symbol (str): Trading symbol.
+            side (str | PositionSide): Position side ('long'/'short' or 'buy'/'sell').
+            quantity (float): Quantity to add to the position.
+            price (float | None): Limit price. Defaults to None (market order).
 
-    @abstractmethod
-    def close_all_positions(self, side: str) -> bool:
-        return False
+        Returns:
+            Order | None: The entry order, None if the entry filters blocked it.
+        """
+        if not isinstance(side, PositionSide):
+            side = PositionSide.get_position_side_from(side)
+
+        if self.entry_check is not None:
+            reason = self.entry_check(symbol, side.get_open_side(), quantity)
+            if reason is not None:
+                return None
+
+        return self.place_order(
+            symbol,
+            side.get_open_side(),
+            OrderType.MARKET if price is None else OrderType.LIMIT,
+            quantity,
+            price=price,
+        )
+
+    def close_position(
+        self, symbol: str, side: str | PositionSide | None = None
+    ) -> list[Order]:
+        """Close the open position(s) of a symbol with reduce-only market orders.
+
+        Args:
+            symbol (str): Trading symbol.
+            side (str | PositionSide | None): Position side to close. Defaults to None (all sides).
+
+        Returns:
+            list[Order]: The closing orders.
+        """
+        if isinstance(side, str):
+            side = PositionSide.get_position_side_from(side)
+
+        return [
+            self.place_order(
+                symbol,
+                position.side.get_close_side(),
+                OrderType.MARKET,
+                position.size,
+                reduce_only=True,
+            )
+            for position in self.get_positions(symbol)
+            if side is None or position.side is side
+        ]
+
+    def close_all_positions(self, symbol: str | None = None) -> list[Order]:
+        """Close all open positions, optionally for one symbol only."""
+        symbols = {position.symbol for position in self.get_positions(symbol)}
+        return [
+            order for item in sorted(symbols) for order in self.close_position(item)
+        ]
+
+    def get_position(
+        self, symbol: str, side: str | PositionSide | None = None
+    ) -> Position | None:
+        """Get an open position of a symbol, optionally on one side only."""
+        if isinstance(side, str):
+            side = PositionSide.get_position_side_from(side)
+
+        for position in self.get_positions(symbol):
+            if side is None or position.side is side:
+                return position
+        return None
 
     @abstractmethod
-    def modify_position(self) -> bool:
-        return False
+    def get_positions(self, symbol: str | None = None) -> list[Position]:
+        """Get the open positions, optionally for one symbol only."""
+        pass
 
     @abstractmethod
-    def modify_all_positions(self) -> bool:
-        return False
+    def modify_position(
+        self,
+        symbol: str,
+        *,
+        leverage: int | None = None,
+        margin_mode: str | MarginMode | None = None,
+    ) -> None:
+        """Change the leverage and/or margin mode used for a symbol."""
+        pass
 
     # ORDER
+    @staticmethod
+    def create_order(
+        symbol: str,
+        side: str | OrderSide,
+        order_type: str | OrderType,
+        quantity: float,
+        *,
+        price: float | None = None,
+        stop_price: float | None = None,
+        time_in_force: str | TimeInForce = DEFAULT_TIME_IN_FORCE,
+        reduce_only: bool = False,
+        post_only: bool = False,
+        client_order_id: str | None = None,
+    ) -> Order:
+        """Build and validate an order request.
+
+        Args:
+            symbol (str): Trading symbol (e.g., "BTCUSDT").
+            side (str | OrderSide): Order side ('buy', 'sell').
+            order_type (str | OrderType): Order type ('market', 'limit', 'stop', 'stop_limit', 'take_profit').
+            quantity (float): Order quantity in base currency (contracts for futures).
+            price (float | None): Limit price (required for 'limit' and 'stop_limit').
+            stop_price (float | None): Trigger price (required for 'stop', 'stop_limit' and 'take_profit').
+            time_in_force (str | TimeInForce): Time in force ('gtc', 'ioc', 'fok').
+            reduce_only (bool): Only reduce an existing position.
+            post_only (bool): Only add liquidity (limit orders only).
+            client_order_id (str | None): Custom order ID.
+
+        Returns:
+            Order: Validated order request.
+
+        Raises:
+            InvalidOrderError: If the order parameters are invalid.
+        """
+        order = Order(
+            symbol=symbol,
+            side=side
+            if isinstance(side, OrderSide)
+            else OrderSide.get_order_side_from(side),
+            type=order_type
+            if isinstance(order_type, OrderType)
+            else OrderType.get_order_type_from(order_type),
+            quantity=quantity,
+            price=price,
+            stop_price=stop_price,
+            time_in_force=time_in_force
+            if isinstance(time_in_force, TimeInForce)
+            else TimeInForce.get_time_in_force_from(time_in_force),
+            reduce_only=reduce_only,
+            post_only=post_only,
+            client_order_id=client_order_id,
+        )
+        order.validate()
+        return order
+
     @abstractmethod
-    def open_order(self, side: str) -> bool:
-        return False
+    def place_order(
+        self,
+        symbol: str,
+        side: str | OrderSide,
+        order_type: str | OrderType,
+        quantity: float,
+        *,
+        price: float | None = None,
+        stop_price: float | None = None,
+        time_in_force: str | TimeInForce = DEFAULT_TIME_IN_FORCE,
+        reduce_only: bool = False,
+        post_only: bool = False,
+        client_order_id: str | None = None,
+    ) -> Order:
+        """Place a new order and return its state as reported by the venue."""
+        pass
 
     @abstractmethod
-    def close_order(self, side: str) -> bool:
-        return False
+    def cancel_order(self, symbol: str, order_id: str) -> Order:
+        """Cancel an open order and return its final state."""
+        pass
+
+    @abstractmethod
+    def cancel_all_orders(self, symbol: str) -> list[Order]:
+        """Cancel all open orders for a symbol."""
+        pass
 
     @abstractmethod
-    def close_all_orders(self, side: str) -> bool:
-        return False
+    def amend_order(
+        self,
+        symbol: str,
+        order_id: str,
+        *,
+        quantity: float | None = None,
+        price: float | None = None,
+        stop_price: float | None = None,
+    ) -> Order:
+        """Amend quantity and/or prices of an open order."""
+        pass
 
+    @abstractmethod
+    def get_order(self, symbol: str, order_id: str) -> Order:
+        """Get the current state of an order."""
+        pass
 
 #    datetime          m_long_timer;
 #    datetime          m_short_timer;
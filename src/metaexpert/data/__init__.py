@@ -0,0 +1,18 @@
+"""Historical data components of the MetaExpert library."""
+
+from .cache import CandleCache, SeriesKey
+from .funding import CSVFundingSource, FundingSource
+from .loader import DataLoader
+from .source import BarSource, CSVSource, FileSource, ParquetSource
+
+__all__ = [
+    "BarSource",
+    "CSVFundingSource",
+    "CSVSource",
+    "CandleCache",
+    "DataLoader",
+    "FileSource",
+    "FundingSource",
+    "ParquetSource",
+    "SeriesKey",
+]
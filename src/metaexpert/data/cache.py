@@ -0,0 +1,246 @@
+"""Candle cache."""
+
+import csv
+import json
+from dataclasses import dataclass
+from datetime import datetime
+from pathlib import Path
+from threading import RLock
+from typing import Any
+
+from metaexpert.config import (
+    DATA_DIRECTORY,
+    DATA_FORMAT_CSV,
+    DATA_FORMAT_PARQUET,
+    DEFAULT_DATA_FORMAT,
+)
+from metaexpert.core import Candle, Timeframe
+from metaexpert.logger import MetaLogger as Logger, get_logger
+from metaexpert.utils.time import to_milliseconds, to_utc
+
+COLUMNS = ("time", "open", "high", "low", "close", "volume")
+
+
+@dataclass(frozen=True)
+class SeriesKey:
+    """Identifies a candle series in the cache."""
+
+    exchange: str
+    market_type: str
+    symbol: str
+    timeframe: Timeframe
+
+
+class CandleCache:
+    """Local on-disk cache of historical candles.
+
+    Every series is stored as `<directory>/<exchange>/<market_type>/<SYMBOL>/<timeframe>.<csv|parquet>`
+    next to a `<timeframe>.json` file listing the time ranges already fetched,
+    so that later runs work offline and only download the missing ranges.
+    """
+
+    def __init__(
+        self,
+        directory: str | Path = DATA_DIRECTORY,
+        data_format: str = DEFAULT_DATA_FORMAT,
+    ) -> None:
+        """Initialize the candle cache.
+
+        Args:
+            directory (str | Path): Root directory of the cache.
+            data_format (str): File format of the series ('csv' or 'parquet').
+        """
+        data_format = data_format.lower().strip()
+        if data_format not in (DATA_FORMAT_CSV, DATA_FORMAT_PARQUET):
+            raise ValueError(f"Unsupported data format: {data_format}")
+
+        self.directory: Path = Path(directory)
+        self.data_format: str = data_format
+        self.logger: Logger = get_logger("CandleCache")
+        self._series: dict[SeriesKey, dict[datetime, Candle]] = {}
+        self._lock: RLock = RLock()
+
+    def get_path(self, key: SeriesKey) -> Path:
+        """Path of the data file of a series."""
+        return (
+            self.directory
+            / key.exchange
+            / key.market_type
+            / key.symbol.upper()
+            / f"{key.timeframe.get_name()}.{self.data_format}"
+        )
+
+    def load(
+        self,
+        key: SeriesKey,
+        start: datetime | None = None,
+        end: datetime | None = None,
+    ) -> list[Candle]:
+        """Load the cached candles of a series opened within [start, end), oldest first."""
+        with self._lock:
+            series = self._get_series(key)
+            return [
+                candle
+                for open_time, candle in sorted(series.items())
+                if (start is None or open_time >= start)
+                and (end is None or open_time < end)
+            ]
+
+    def save(
+        self, key: SeriesKey, candles: list[Candle], start: datetime, end: datetime
+    ) -> None:
+        """Merge candles into a series and mark [start, end) as fetched.
+
+        Args:
+            key (SeriesKey): Series to update.
+            candles (list[Candle]): Fetched candles (may be empty if the range has no trading).
+            start (datetime): Start of the fetched range.
+            end (datetime): End of the fetched range (exclusive).
+        """
+        with self._lock:
+            series = self._get_series(key)
+            for candle in candles:
+                if candle.is_closed:
+                    series[candle.open_time] = candle
+
+            ranges = self.get_ranges(key)
+            ranges.append((start, end))
+            self._write_series(key, series)
+            self._write_ranges(key, self._merge_ranges(ranges))
+            self.logger.debug(
+                "Cached %d candles of %s %s",
+                len(candles),
+                key.symbol,
+                key.timeframe.get_name(),
+            )
+
+    def get_ranges(self, key: SeriesKey) -> list[tuple[datetime, datetime]]:
+        """Time ranges of a series that are already fetched."""
+        path = self.get_path(key).with_suffix(".json")
+        if not path.exists():
+            return []
+        content = json.loads(path.read_text(encoding="utf-8"))
+        return [
+            (to_utc(start), to_utc(end)) for start, end in content.get("ranges", [])
+        ]
+
+    def get_missing_ranges(
+        self, key: SeriesKey, start: datetime, end: datetime
+    ) -> list[tuple[datetime, datetime]]:
+        """Parts of [start, end) that are not fetched yet."""
+        missing: list[tuple[datetime, datetime]] = []
+        cursor = start
+        for range_start, range_end in self._merge_ranges(self.get_ranges(key)):
+            if range_end <= cursor:
+                continue
+            if range_start >= end:
+                break
+            if range_start > cursor:
+                missing.append((cursor, range_start))
+            cursor = max(cursor, range_end)
+        if cursor < end:
+            missing.append((cursor, end))
+        return missing
+
+    def clear(self, key: SeriesKey) -> None:
+        """Remove a series from the cache."""
+        with self._lock:
+            self._series.pop(key, None)
+            path = self.get_path(key)
+            path.unlink(missing_ok=True)
+            path.with_suffix(".json").unlink(missing_ok=True)
+
+    @staticmethod
+    def _merge_ranges(
+        ranges: list[tuple[datetime, datetime]],
+    ) -> list[tuple[datetime, datetime]]:
+        """Sort ranges and merge the overlapping or adjacent ones."""
+        merged: list[tuple[datetime, datetime]] = []
+        for start, end in sorted(ranges):
+            if merged and start <= merged[-1][1]:
+                merged[-1] = (merged[-1][0], max(merged[-1][1], end))
+            else:
+                merged.append((start, end))
+        return merged
+
+    def _get_series(self, key: SeriesKey) -> dict[datetime, Candle]:
+        """Get a series from memory, reading it from disk on first use."""
+        series = self._series.get(key)
+        if series is None:
+            series = {candle.open_time: candle for candle in self._read_series(key)}
+            self._series[key] = series
+        return series
+
+    def _read_series(self, key: SeriesKey) -> list[Candle]:
+        """Read a series file."""
+        path = self.get_path(key)
+        if not path.exists():
+            return []
+
+        if self.data_format == DATA_FORMAT_PARQUET:
+            rows = self._import_pyarrow().parquet.read_table(path).to_pylist()
+        else:
+            with path.open(newline="", encoding="utf-8") as file:
+                rows = list(csv.DictReader(file))
+
+        return [
+            Candle(
+                symbol=key.symbol.upper(),
+                timeframe=key.timeframe,
+                open_time=to_utc(int(row["time"])),
+                open=float(row["open"]),
+                high=float(row["high"]),
+                low=float(row["low"]),
+                close=float(row["close"]),
+                volume=float(row["volume"]),
+            )
+            for row in rows
+        ]
+
+    def _write_series(self, key: SeriesKey, series: dict[datetime, Candle]) -> None:
+        """Write a series file."""
+        path = self.get_path(key)
+        path.parent.mkdir(parents=True, exist_ok=True)
+        rows: list[dict[str, Any]] = [
+            {
+                "time": to_milliseconds(candle.open_time),
+                "open": candle.open,
+                "high": candle.high,
+                "low": candle.low,
+                "close": candle.close,
+                "volume": candle.volume,
+            }
+            for _, candle in sorted(series.items())
+        ]
+
+        if self.data_format == DATA_FORMAT_PARQUET:
+            pyarrow = self._import_pyarrow()
+            pyarrow.parquet.write_table(pyarrow.Table.from_pylist(rows), path)
+            return
+
+        with path.open("w", newline="", encoding="utf-8") as file:
+            writer = csv.DictWriter(file, fieldnames=COLUMNS)
+            writer.writeheader()
+            writer.writerows(rows)
+
+    def _write_ranges(
+        self, key: SeriesKey, ranges: list[tuple[datetime, datetime]]
+    ) -> None:
+        """Write the fetched ranges of a series."""
+        path = self.get_path(key).with_suffix(".json")
+        content = {
+            "ranges": [[start.isoformat(), end.isoformat()] for start, end in ranges]
+        }
+        path.write_text(json.dumps(content, indent=2), encoding="utf-8")
+
+    @staticmethod
+    def _import_pyarrow() -> Any:
+        """Import pyarrow, which is only required by the Parquet format."""
+        try:
+            import pyarrow
+            import pyarrow.parquet
+        except ImportError as e:
+            raise ImportError(
+                "The parquet data format requires pyarrow: pip install pyarrow"
+            ) from e
+        return pyarrow
@@ -0,0 +1,151 @@
+"""Funding rate sources."""
+
+import csv
+from abc import ABC, abstractmethod
+from bisect import bisect_left
+from datetime import UTC, datetime, tzinfo
+from pathlib import Path
+from typing import Any
+from zoneinfo import ZoneInfo
+
+from metaexpert.core import FundingRate, InvalidDataError, MissingDataError
+from metaexpert.logger import MetaLogger as Logger, get_logger
+from metaexpert.utils.time import to_utc
+
+FUNDING_COLUMNS = ("time", "rate")
+
+
+class FundingSource(ABC):
+    """Source of the historical funding rates of perpetual contracts."""
+
+    @abstractmethod
+    def load(
+        self,
+        symbol: str,
+        start: str | datetime,
+        end: str | datetime | None = None,
+    ) -> list[FundingRate]:
+        """Load the funding rates of the timestamps within [start, end), oldest first.
+
+        Args:
+            symbol (str): Trading symbol.
+            start (str | datetime): Start of the range (date string or datetime, UTC).
+            end (str | datetime | None): End of the range (exclusive), unbounded by default.
+
+        Returns:
+            list[FundingRate]: Funding rates of the range.
+        """
+
+
+class CSVFundingSource(FundingSource):
+    """Funding source reading the funding rates from CSV files with a header row.
+
+    The path may contain a `{symbol}` placeholder, e.g. `funding/{symbol}.csv`.
+    Every row is a funding timestamp with its rate and, optionally, the mark
+    price the payments are computed at (`mark_price` column); without it,
+    the price of the market at that time is used.
+    """
+
+    def __init__(
+        self,
+        path: str | Path,
+        *,
+        delimiter: str = ",",
+        columns: dict[str, str] | None = None,
+        timezone: str | tzinfo = UTC,
+    ) -> None:
+        """Initialize the CSV funding source.
+
+        Args:
+            path (str | Path): File path, optionally with a `{symbol}` placeholder.
+            delimiter (str): Field delimiter.
+            columns (dict[str, str] | None): Mapping of the fields (time, rate, mark_price)
+                to the column names of the file.
+            timezone (str | tzinfo): Timezone of timestamps without offset (UTC by default).
+        """
+        self.path: str = str(path)
+        self.delimiter: str = delimiter
+        self.columns: dict[str, str] = {
+            name: name for name in (*FUNDING_COLUMNS, "mark_price")
+        } | (columns or {})
+        self.timezone: tzinfo = (
+            ZoneInfo(timezone) if isinstance(timezone, str) else timezone
+        )
+        self.logger: Logger = get_logger(type(self).__name__)
+        self._rates: dict[Path, list[FundingRate]] = {}
+        self._times: dict[Path, list[datetime]] = {}
+
+    def get_path(self, symbol: str) -> Path:
+        """Path of the file of a symbol."""
+        return Path(self.path.format(symbol=symbol.upper()))
+
+    def load(
+        self,
+        symbol: str,
+        start: str | datetime,
+        end: str | datetime | None = None,
+    ) -> list[FundingRate]:
+        """Load the funding rates of the file within [start, end), oldest first."""
+        path = self.get_path(symbol)
+        rates = self._rates.get(path)
+        if rates is None:
+            rates = self._parse(symbol, self.read_rows(path))
+            self._rates[path] = rates
+            self._times[path] = [rate.time for rate in rates]
+            self.logger.info("Loaded %d funding rates from %s", len(rates), path)
+
+        times = self._times[path]
+        first = bisect_left(times, to_utc(start))
+        last = bisect_left(times, to_utc(end)) if end is not None else len(rates)
+        return rates[first:last]
+
+    def read_rows(self, path: Path) -> list[dict[str, Any]]:
+        """Read the rows of a CSV file."""
+        if not path.exists():
+            raise MissingDataError(str(path), f"Funding file not found: {path}")
+        with path.open(newline="", encoding="utf-8") as file:
+            return list(csv.DictReader(file, delimiter=self.delimiter))
+
+    def _parse(self, symbol: str, rows: list[dict[str, Any]]) -> list[FundingRate]:
+        """Convert raw rows into funding rates in chronological order."""
+        if rows:
+            for name in FUNDING_COLUMNS:
+                if self.columns[name] not in rows[0]:
+                    raise MissingDataError(
+                        self.columns[name],
+                        f"Missing column '{self.columns[name]}' for funding field '{name}'",
+                    )
+
+        rates: list[FundingRate] = []
+        for number, row in enumerate(rows, start=1):
+            try:
+                mark_price = row.get(self.columns["mark_price"])
+                rate = FundingRate(
+                    symbol=symbol.upper(),
+                    time=self._parse_time(row[self.columns["time"]]),
+                    rate=float(row[self.columns["rate"]]),
+                    mark_price=(
+                        float(mark_price) if mark_price not in (None, "") else None
+                    ),
+                )
+            except (TypeError, ValueError) as e:
+                raise InvalidDataError(row, f"{e} (row {number})") from e
+            if rates and rate.time <= rates[-1].time:
+                raise InvalidDataError(
+                    row, f"Out-of-order funding rate at {rate.time} (row {number})"
+                )
+            rates.append(rate)
+        return rates
+
+    def _parse_time(self, value: Any) -> datetime:
+        """Convert a raw time value (ISO 8601 or Unix timestamp) into an aware UTC datetime."""
+        text = str(value).strip()
+        try:
+            number = float(text)
+        except ValueError:
+            time = datetime.fromisoformat(text)
+        else:
+            return to_utc(int(number) if number.is_integer() else number)
+        if time.tzinfo is None:
+            time = time.replace(tzinfo=self.timezone)
+        return time.astimezone(UTC)
@@ -0,0 +1,113 @@
+"""Historical data loader."""
+
+from datetime import UTC, datetime
+
+from metaexpert.core import Candle, Timeframe
+from metaexpert.exchanges import MetaExchange
+from metaexpert.logger import MetaLogger as Logger, get_logger
+from metaexpert.utils.time import to_utc
+
+from .cache import CandleCache, SeriesKey
+from .source import BarSource
+
+
+class DataLoader(BarSource):
+    """Loads historical candles through an exchange adapter.
+
+    Requests are paged to respect the klines limit of the exchange and the
+    results are kept in a local cache: a range that was fetched once is read
+    from disk afterwards, and only the missing parts of a request hit the
+    exchange.
+    """
+
+    def __init__(
+        self, exchange: MetaExchange, cache: CandleCache | None = None
+    ) -> None:
+        """Initialize the data loader.
+
+        Args:
+            exchange (MetaExchange): Exchange adapter used to fetch the candles.
+            cache (CandleCache | None): Candle cache, the default CSV cache if omitted.
+        """
+        self.exchange: MetaExchange = exchange
+        self.cache: CandleCache = cache or CandleCache()
+        self.logger: Logger = get_logger("DataLoader")
+
+    def get_key(self, symbol: str, timeframe: Timeframe) -> SeriesKey:
+        """Cache key of a series of the exchange."""
+        return SeriesKey(
+            exchange=self.exchange.exchange,
+            market_type=self.exchange.market_type.get_name(),
+            symbol=symbol.upper(),
+            timeframe=timeframe,
+        )
+
+    def load(
+        self,
+        symbol: str,
+        timeframe: str | Timeframe,
+        start: str | datetime,
+        end: str | datetime | None = None,
+    ) -> list[Candle]:
+        """Load the closed candles opened within [start, end), oldest first.
+
+        Args:
+            symbol (str): Trading symbol.
+            timeframe (str | Timeframe): Candle timeframe.
+            start (str | datetime): Start of the range (date string or datetime, UTC).
+            end (str | datetime | None): End of the range (exclusive), now by default.
+
+        Returns:
+            list[Candle]: Candles of the range.
+        """
+        if isinstance(timeframe, str):
+            timeframe = Timeframe.get_timeframe_from(timeframe)
+
+        # Only closed candles are cached, so the range stops at the current candle
+        current = timeframe.get_candle_open_time(datetime.now(UTC))
+        range_start = timeframe.get_candle_open_time(to_utc(start))
+        range_end = min(to_utc(end), current) if end is not None else current
+
+        key = self.get_key(symbol, timeframe)
+        for missing_start, missing_end in self.cache.get_missing_ranges(
+            key, range_start, range_end
+        ):
+            candles = self.fetch(symbol, timeframe, missing_start, missing_end)
+            self.cache.save(key, candles, missing_start, missing_end)
+
+        return self.cache.load(key, range_start, range_end)
+
+    def fetch(
+        self, symbol: str, timeframe: Timeframe, start: datetime, end: datetime
+    ) -> list[Candle]:
+        """Fetch the candles opened within [start, end) from the exchange, page by page."""
+        limit = self.exchange.kline_limit
+        delta = timeframe.get_delta()
+        candles: list[Candle] = []
+        cursor = start
+
+        while cursor < end:
+            page = [
+                candle
+                for candle in self.exchange.get_klines(
+                    symbol, timeframe, cursor, end, limit
+                )
+                if cursor <= candle.open_time < end
+            ]
+            if not page:
+                break
+
+            candles.extend(page)
+            cursor = page[-1].open_time + delta
+            if len(page) < limit:
+                break
+
+        self.logger.info(
+            "Fetched %d %s %s candles from %s to %s",
+            len(candles),
+            symbol,
+            timeframe.get_name(),
+            start,
+            end,
+        )
+        return candles
@@ -0,0 +1,278 @@
+"""Bar sources."""
+
+import csv
+from abc import ABC, abstractmethod
+from datetime import UTC, datetime, tzinfo
+from pathlib import Path
+from typing import Any
+from zoneinfo import ZoneInfo
+
+from metaexpert.core import Candle, InvalidDataError, MissingDataError, Timeframe
+from metaexpert.logger import MetaLogger as Logger, get_logger
+from metaexpert.utils.time import to_utc
+
+REQUIRED_COLUMNS = ("time", "open", "high", "low", "close")
+
+
+class BarSource(ABC):
+    """Source of historical candles for backtesting and indicator history."""
+
+    @abstractmethod
+    def load(
+        self,
+        symbol: str,
+        timeframe: str | Timeframe,
+        start: str | datetime,
+        end: str | datetime | None = None,
+    ) -> list[Candle]:
+        """Load the closed candles opened within [start, end), oldest first.
+
+        Args:
+            symbol (str): Trading symbol.
+            timeframe (str | Timeframe): Candle timeframe.
+            start (str | datetime): Start of the range (date string or datetime, UTC).
+            end (str | datetime | None): End of the range (exclusive), now by default.
+
+        Returns:
+            list[Candle]: Candles of the range.
+        """
+
+    def load_last(
+        self,
+        symbol: str,
+        timeframe: str | Timeframe,
+        count: int,
+        end: str | datetime | None = None,
+    ) -> list[Candle]:
+        """Load the last `count` closed candles before `end` (now by default)."""
+        if isinstance(timeframe, str):
+            timeframe = Timeframe.get_timeframe_from(timeframe)
+
+        end_time = timeframe.get_candle_open_time(
+            to_utc(end) if end is not None else datetime.now(UTC)
+        )
+        start = end_time - timeframe.get_delta() * count
+        return self.load(symbol, timeframe, start, end_time)[-count:]
+
+
+class FileSource(BarSource, ABC):
+    """Bar source reading candles from local files.
+
+    The path may contain `{symbol}` and `{timeframe}` placeholders, e.g.
+    `candles/{symbol}_{timeframe}.csv`. Every file is validated against the
+    timeframe when it is first read: rows must be in chronological order,
+    aligned on the timeframe, without duplicates and without gaps.
+    """
+
+    def __init__(
+        self,
+        path: str | Path,
+        *,
+        columns: dict[str, str] | None = None,
+        timezone: str | tzinfo = UTC,
+        time_format: str | None = None,
+        allow_gaps: bool = False,
+    ) -> None:
+        """Initialize the file source.
+
+        Args:
+            path (str | Path): File path, optionally with `{symbol}`/`{timeframe}` placeholders.
+            columns (dict[str, str] | None): Mapping of the candle fields (time, open, high,
+                low, close, volume) to the column names of the file.
+            timezone (str | tzinfo): Timezone of timestamps without offset (UTC by default).
+            time_format (str | None): `strptime` format of the time column, ISO 8601
+                or Unix timestamps (seconds or milliseconds) if omitted.
+            allow_gaps (bool): Accept missing candles instead of raising `MissingDataError`.
+        """
+        self.path: str = str(path)
+        self.columns: dict[str, str] = {
+            name: name for name in (*REQUIRED_COLUMNS, "volume")
+        } | (columns or {})
+        self.timezone: tzinfo = (
+            ZoneInfo(timezone) if isinstance(timezone, str) else timezone
+        )
+        self.time_format: str | None = time_format
+        self.allow_gaps: bool = allow_gaps
+        self.logger: Logger = get_logger(type(self).__name__)
+        self._candles: dict[Path, list[Candle]] = {}
+
+    def get_path(self, symbol: str, timeframe: Timeframe) -> Path:
+        """Path of the file of a series."""
+        return Path(
+            self.path.format(symbol=symbol.upper(), timeframe=timeframe.get_name())
+        )
+
+    def load(
+        self,
+        symbol: str,
+        timeframe: str | Timeframe,
+        start: str | datetime,
+        end: str | datetime | None = None,
+    ) -> list[Candle]:
+        """Load the candles of the file opened within [start, end), oldest first."""
+        if isinstance(timeframe, str):
+            timeframe = Timeframe.get_timeframe_from(timeframe)
+
+        path = self.get_path(symbol, timeframe)
+        candles = self._candles.get(path)
+        if candles is None:
+            candles = self._parse(symbol, timeframe, self.read_rows(path))
+            self._candles[path] = candles
+            self.logger.info("Loaded %d candles from %s", len(candles), path)
+
+        range_start = to_utc(start)
+        range_end = to_utc(end) if end is not None else None
+        return [
+            candle
+            for candle in candles
+            if candle.open_time >= range_start
+            and (range_end is None or candle.open_time < range_end)
+        ]
+
+    @abstractmethod
+    def read_rows(self, path: Path) -> list[dict[str, Any]]:
+        """Read the raw rows of a file."""
+
+    def _parse(
+        self, symbol: str, timeframe: Timeframe, rows: list[dict[str, Any]]
+    ) -> list[Candle]:
+        """Convert raw rows into validated candles."""
+        if rows:
+            for name in REQUIRED_COLUMNS:
+                if self.columns[name] not in rows[0]:
+                    raise MissingDataError(
+                        self.columns[name],
+                        f"Missing column '{self.columns[name]}' for candle field '{name}'",
+                    )
+
+        delta = timeframe.get_delta()
+        candles: list[Candle] = []
+        for number, row in enumerate(rows, start=1):
+            candle = self._parse_row(symbol, timeframe, row, number)
+            if candles:
+                previous = candles[-1].open_time
+                if candle.open_time == previous:
+                    raise InvalidDataError(
+                        row, f"Duplicate candle at {candle.open_time} (row {number})"
+                    )
+                if candle.open_time < previous:
+                    raise InvalidDataError(
+                        row, f"Out-of-order candle at {candle.open_time} (row {number})"
+                    )
+                if candle.open_time != previous + delta and not self.allow_gaps:
+                    raise MissingDataError(
+                        "time",
+                        f"Missing {timeframe.get_name()} candles between {previous} "
+                        f"and {candle.open_time} (row {number})",
+                    )
+            candles.append(candle)
+        return candles
+
+    def _parse_row(
+        self, symbol: str, timeframe: Timeframe, row: dict[str, Any], number: int
+    ) -> Candle:
+        """Convert one raw row into a candle."""
+        try:
+            open_time = self._parse_time(row[self.columns["time"]])
+            volume = row.get(self.columns["volume"])
+            candle = Candle(
+                symbol=symbol.upper(),
+                timeframe=timeframe,
+                open_time=open_time,
+                open=float(row[self.columns["open"]]),
+                high=float(row[self.columns["high"]]),
+                low=float(row[self.columns["low"]]),
+                close=float(row[self.columns["close"]]),
+                volume=float(volume) if volume not in (None, "") else 0.0,
+            )
+        except (TypeError, ValueError) as e:
+            raise InvalidDataError(row, f"{e} (row {number})") from e
+
+        if timeframe.get_candle_open_time(open_time) != open_time:
+            raise InvalidDataError(
+                row,
+                f"Time {open_time} is not aligned on {timeframe.get_name()} (row {number})",
+            )
+        if candle.low > min(candle.open, candle.close) or candle.high < max(
+            candle.open, candle.close
+        ):
+            raise InvalidDataError(row, f"Inconsistent OHLC prices (row {number})")
+        return candle
+
+    def _parse_time(self, value: Any) -> datetime:
+        """Convert a raw time value into an aware UTC datetime."""
+        if isinstance(value, datetime):
+            time = value
+        elif isinstance(value, (int, float)):
+            return to_utc(value)
+        elif self.time_format is not None:
+            time = datetime.strptime(str(value).strip(), self.time_format)
+        else:
+            text = str(value).strip()
+            try:
+                # Unix timestamps, possibly exported with decimals
+                number = float(text)
+            except ValueError:
+                time = datetime.fromisoformat(text)
+            else:
+                return to_utc(int(number) if number.is_integer() else number)
+
+        if time.tzinfo is None:
+            time = time.replace(tzinfo=self.timezone)
+        return time.astimezone(UTC)
+
+
+class CSVSource(FileSource):
+    """Bar source reading candles from CSV files with a header row."""
+
+    def __init__(
+        self,
+        path: str | Path,
+        *,
+        delimiter: str = ",",
+        columns: dict[str, str] | None = None,
+        timezone: str | tzinfo = UTC,
+        time_format: str | None = None,
+        allow_gaps: bool = False,
+    ) -> None:
+        """Initialize the CSV source.
+
+        Args:
+            path (str | Path): File path, optionally with `{symbol}`/`{timeframe}` placeholders.
+            delimiter (str): Field delimiter.
+            columns (dict[str, str] | None): Mapping of the candle fields to the column names.
+            timezone (str | tzinfo): Timezone of timestamps without offset (UTC by default).
+            time_format (str | None): `strptime` format of the time column.
+            allow_gaps (bool): Accept missing candles instead of raising `MissingDataError`.
+        """
+        super().__init__(
+            path,
+            columns=columns,
+            timezone=timezone,
+            time_format=time_format,
+            allow_gaps=allow_gaps,
+        )
+        self.delimiter: str = delimiter
+
+    def read_rows(self, path: Path) -> list[dict[str, Any]]:
+        """Read the rows of a CSV file."""
+        if not path.exists():
+            raise MissingDataError(str(path), f"Data file not found: {path}")
+        with path.open(newline="", encoding="utf-8") as file:
+            return list(csv.DictReader(file, delimiter=self.delimiter))
+
+
+class ParquetSource(FileSource):
+    """Bar source reading candles from Parquet files (requires pyarrow)."""
+
+    def read_rows(self, path: Path) -> list[dict[str, Any]]:
+        """Read the rows of a Parquet file."""
+        if not path.exists():
+            raise MissingDataError(str(path), f"Data file not found: {path}")
+        try:
+            import pyarrow.parquet
+        except ImportError as e:
+            raise ImportError(
+                "The parquet data format requires pyarrow: pip install pyarrow"
+            ) from e
+        return pyarrow.parquet.read_table(path).to_pylist()
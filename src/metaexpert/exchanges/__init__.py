@@ -1,18 +1,30 @@
+import json
 from abc import ABC, abstractmethod
+from datetime import datetime
 from importlib import import_module
-from typing import Self
+from typing import Any, Self
 
 from metaexpert.core import (
+    Balance,
+    Broker,
+    Candle,
     ContractType,
+    Fill,
+    Instrument,
+    InstrumentRegistry,
     MarginMode,
-    Market,
     MarketType,
+    Order,
+    OrderBookUpdate,
+    Position,
+    PositionBook,
     PositionMode,
-    Trade,
+    Tick,
+    Timeframe,
 )
 
 
-class MetaExchange(Trade, Market, ABC):
+class MetaExchange(Broker, ABC):
     """Abstract base class for stock exchanges."""
 
     exchange: str
@@ -27,6 +39,12 @@ class MetaExchange(Trade, Market, ABC):
     contract_type: ContractType
     margin_mode: MarginMode
     position_mode: PositionMode
+    kline_limit: int = 500  # Maximum number of candles per klines request
+    _position_book: PositionBook | None = None
+    # Instruments shared by the adapters of the same exchange and market
+    _instrument_registries: dict[
+        tuple[str, MarketType, ContractType], InstrumentRegistry
+    ] = {}
 
     @classmethod
     def create(
@@ -64,7 +82,162 @@ class MetaExchange(Trade, Market, ABC):
         except (ImportError, AttributeError) as e:
             raise ValueError(f"Unsupported exchange: {cls.exchange}") from e
 
+    @property
+    def position_book(self) -> PositionBook:
+        """Local position book of the exchange, created on first use."""
+        if self._position_book is None:
+            self._position_book = PositionBook(self.position_mode, self.contract_type)
+        return self._position_book
+
+    @property
+    def instruments(self) -> InstrumentRegistry:
+        """Instruments of the market and contract types, loaded on first use."""
+        key = (self.exchange, self.market_type, self.contract_type)
+        if key not in self._instrument_registries:
+            self._instrument_registries[key] = InstrumentRegistry(self.get_instruments)
+        return self._instrument_registries[key]
+
+    def get_instruments(self) -> list[Instrument] | None:
+        """Fetch the trading rules of the symbols of the market, `None` if unsupported."""
+        return None
+
+    def prepare_order(self, order: Order) -> Order:
+        """Round and check an order against the instrument of its symbol before it is sent.
+
+        Raises:
+            UnsupportedPairError: If the exchange does not list the symbol.
+            InvalidOrderError: If the order breaks the trading rules.
+        """
+        return self.instruments.prepare_order(order)
+
     @abstractmethod
     def get_websocket_url(self, symbol: str, timeframe: str) -> str:
         """Get WebSocket URL for a given symbol and timeframe."""
         pass
+
+    def get_subscribe_messages(self, symbol: str, timeframe: str) -> list[dict]:
+        """Get the messages subscribing to the kline stream once connected.
+
+        Exchanges whose streams are selected by the WebSocket URL need none.
+        """
+        return []
+
+    def get_ping_message(self) -> str | dict | None:
+        """Application-level ping expected by the exchange stream, if any."""
+        return None
+
+    def parse_message(
+        self, message: str | bytes | dict[str, Any]
+    ) -> list[Candle | Tick]:
+        """Normalize a raw stream message into candles and trade ticks.
+
+        Kline updates are returned as candles, with `is_closed` set once the
+        candle is final; service messages (subscription acknowledgements,
+        pongs) produce an empty list.
+        """
+        raise NotImplementedError(
+            f"Stream messages are not supported for {self.exchange}"
+        )
+
+    def get_book_websocket_url(self, symbol: str) -> str | None:
+        """Get the URL of the L2 depth stream of a symbol, `None` if unsupported."""
+        return None
+
+    def get_book_subscribe_messages(self, symbol: str) -> list[dict]:
+        """Get the messages subscribing to the depth stream of a symbol once connected."""
+        return []
+
+    def get_book_unsubscribe_messages(self, symbol: str) -> list[dict]:
+        """Get the messages unsubscribing from the depth stream of a symbol.
+
+        Exchanges sending the book snapshot on the stream are resubscribed to
+        resynchronize a book; the others fetch it with `get_book_snapshot`.
+        """
+        return []
+
+    def get_book_snapshot(self, symbol: str) -> OrderBookUpdate | None:
+        """Get a snapshot of the book from the REST API, `None` if the stream sends it."""
+        return None
+
+    def parse_book_message(
+        self, message: str | bytes | dict[str, Any]
+    ) -> list[OrderBookUpdate]:
+        """Normalize a raw depth stream message into book snapshots and diffs."""
+        raise NotImplementedError(
+            f"Order book streams are not supported for {self.exchange}"
+        )
+
+    def compute_book_checksum(
+        self, bids: list[tuple[str, str]], asks: list[tuple[str, str]]
+    ) -> int:
+        """Compute the checksum of a book, for exchanges sending one with the updates.
+
+        Args:
+            bids (list[tuple[str, str]]): Bid levels, best first, as reported by the exchange.
+            asks (list[tuple[str, str]]): Ask levels, best first, as reported by the exchange.
+        """
+        raise NotImplementedError(
+            f"Order book checksums are not supported for {self.exchange}"
+        )
+
+    def get_user_stream_url(self) -> str | None:
+        """Get the URL of the private stream of the account, `None` if unsupported.
+
+        It is called before every connection, so that adapters authenticating
+        through the URL (e.g. with a Binance listen key) can renew it.
+        """
+        return None
+
+    def get_user_stream_login(self) -> dict | None:
+        """Get the authentication frame sent first on every private connection."""
+        return None
+
+    def check_user_stream_login(self, message: str | bytes) -> None:
+        """Check the response to the authentication frame.
+
+        Raises:
+            AuthenticationError: If the exchange rejected the credentials.
+        """
+        return None
+
+    def get_user_stream_subscriptions(self) -> list[dict]:
+        """Get the messages subscribing to the order, position and balance updates."""
+        return []
+
+    def keep_user_stream_alive(self) -> None:
+        """Extend the validity of the private stream credentials, if they expire."""
+        return None
+
+    def parse_user_message(
+        self, message: str | bytes | dict[str, Any]
+    ) -> list[Order | Fill | Position | Balance]:
+        """Normalize a private stream message into order, fill, position and balance updates.
+
+        An order update is returned before the fills it reports; service
+        messages produce an empty list.
+        """
+        raise NotImplementedError(
+            f"Private streams are not supported for {self.exchange}"
+        )
+
+    @staticmethod
+    def _load_message(message: str | bytes | dict[str, Any]) -> Any:
+        """Decode a JSON stream message, `None` if it is not JSON."""
+        if isinstance(message, dict):
+            return message
+        try:
+            return json.loads(message)
+        except ValueError:
+            return None
+
+    @abstractmethod
+    def get_klines(
+        self,
+        symbol: str,
+        timeframe: Timeframe,
+        start: datetime | None = None,
+        end: datetime | None = None,
+        limit: int = 500,
+    ) -> list[Candle]:
+        """Get historical candles for a symbol, oldest first."""
+        pass
@@ -1,6 +1,30 @@
+from dataclasses import replace
+from datetime import UTC, datetime
 from importlib import import_module
-from typing import Self
+from typing import Any, Self
 
+from metaexpert.config import DEFAULT_TIME_IN_FORCE
+from metaexpert.core import (
+    Balance,
+    Candle,
+    Fill,
+    Instrument,
+    InstrumentStatus,
+    InvalidOrderError,
+    MarginMode,
+    Order,
+    OrderBookUpdate,
+    OrderNotFoundError,
+    OrderSide,
+    OrderStatus,
+    OrderType,
+    Position,
+    PositionMode,
+    PositionSide,
+    Tick,
+    TimeInForce,
+    Timeframe,
+)
 from metaexpert.exchanges import (
     ContractType,
     # MarginMode,
@@ -9,23 +33,33 @@ from metaexpert.exchanges import (
     # PositionMode,
 )
 from metaexpert.exchanges.binance.config import (
+    BOOK_SNAPSHOT_LIMIT,
     FUTURES_MODULE_INVERSE,
     FUTURES_MODULE_LINEAR,
     FUTURES_PACKAGE,
     FUTURES_WS_BASE_URL,
+    FUTURES_WS_TESTNET_URL,
     SPOT_MODULE,
     SPOT_PACKAGE,
     SPOT_WS_BASE_URL,
+    SPOT_WS_TESTNET_URL,
 )
 from metaexpert.utils.package import install_package
+from metaexpert.utils.time import to_milliseconds, to_utc
 
 
 class Adapter(MetaExchange):
     """Implementation for the Binance exchange."""
 
+    kline_limit = 1000
+
     def __init__(self) -> None:
         """Initializes the Binance class."""
         self.client = self._create_client()
+        self._listen_key: str | None = None
+        # Spot stop orders would lock the balance twice for a stop-loss and a
+        # take-profit, so spot protections are watched locally
+        self.supports_protective_orders = self.market_type == MarketType.FUTURES
 
     def _create_client(self) -> Self:
         """Initializes and returns the Binance client based on market type."""
@@ -93,82 +127,747 @@ class Adapter(MetaExchange):
 
         return f"{base_url}/ws/{symbol.lower()}@kline_{timeframe}"
 
+    def parse_message(
+        self, message: str | bytes | dict[str, Any]
+    ) -> list[Candle | Tick]:
+        """Normalize Binance kline and trade events.
+
+        Docs: https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams
+        """
+        data = self._load_message(message)
+        if not isinstance(data, dict):
+            return []
+        # Combined streams wrap the event: {"stream": ..., "data": {...}}
+        event = data.get("data", data)
+
+        match event.get("e"):
+            case "kline":
+                kline = event["k"]
+                return [
+                    Candle(
+                        symbol=kline["s"],
+                        timeframe=Timeframe.get_timeframe_from(kline["i"]),
+                        open_time=to_utc(kline["t"]),
+                        open=float(kline["o"]),
+                        high=float(kline["h"]),
+                        low=float(kline["l"]),
+                        close=float(kline["c"]),
+                        volume=float(kline["v"]),
+                        is_closed=bool(kline["x"]),
+                    )
+                ]
+            case "trade" | "aggTrade":
+                return [
+                    Tick(
+                        symbol=event["s"],
+                        time=to_utc(event["T"]),
+                        price=float(event["p"]),
+                        volume=float(event["q"]),
+                        # "m": the buyer is the maker, so the taker sold
+                        side=OrderSide.SELL if event.get("m") else OrderSide.BUY,
+                    )
+                ]
+            case _:
+                return []
+
+    # ORDER BOOK STREAM
+    def get_book_websocket_url(self, symbol: str) -> str | None:
+        """URL of the diff depth stream of a symbol, updated every 100 ms.
+
+        Docs: https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#diff-depth-stream
+        """
+        return f"{self._get_stream_base_url()}/ws/{symbol.lower()}@depth@100ms"
+
+    def get_book_snapshot(self, symbol: str) -> OrderBookUpdate | None:
+        """Fetch the book snapshot that the diff depth stream is applied to."""
+        try:
+            response = self.client.depth(symbol.upper(), limit=BOOK_SNAPSHOT_LIMIT)
+        except Exception as e:
+            raise RuntimeError(f"Failed to get the Binance order book: {e}") from e
+
+        return OrderBookUpdate(
+            symbol=symbol.upper(),
+            bids=tuple((price, size) for price, size in response["bids"]),
+            asks=tuple((price, size) for price, size in response["asks"]),
+            time=to_utc(response["T"]) if "T" in response else datetime.now(UTC),
+            is_snapshot=True,
+            last_id=int(response["lastUpdateId"]),
+        )
+
+    def parse_book_message(
+        self, message: str | bytes | dict[str, Any]
+    ) -> list[OrderBookUpdate]:
+        """Normalize Binance diff depth events.
+
+        Futures events also carry the last update id of the previous event.
+        """
+        data = self._load_message(message)
+        if not isinstance(data, dict):
+            return []
+        event = data.get("data", data)
+        if event.get("e") != "depthUpdate":
+            return []
+
+        return [
+            OrderBookUpdate(
+                symbol=event["s"],
+                bids=tuple((price, size) for price, size in event["b"]),
+                asks=tuple((price, size) for price, size in event["a"]),
+                time=to_utc(event["E"]),
+                first_id=int(event["U"]),
+                last_id=int(event["u"]),
+                prev_id=int(event["pu"]) if "pu" in event else None,
+            )
+        ]
+
+    def _get_stream_base_url(self) -> str:
+        """Base URL of the streams of the market type."""
+        if self.market_type == MarketType.FUTURES:
+            return FUTURES_WS_TESTNET_URL if self.testnet else FUTURES_WS_BASE_URL
+        return SPOT_WS_TESTNET_URL if self.testnet else SPOT_WS_BASE_URL
+
+    # USER DATA STREAM
+    def get_user_stream_url(self) -> str | None:
+        """Create a listen key and return the URL of its user data stream.
+
+        Docs: https://developers.binance.com/docs/binance-spot-api-docs/user-data-stream
+        """
+        if not self.api_key or not self.api_secret:
+            raise ValueError(
+                "API key and secret are required for the user data stream."
+            )
+
+        try:
+            self._listen_key = self.client.new_listen_key()["listenKey"]
+        except Exception as e:
+            raise RuntimeError(f"Failed to create a Binance listen key: {e}") from e
+
+        return f"{self._get_stream_base_url()}/ws/{self._listen_key}"
+
+    def keep_user_stream_alive(self) -> None:
+        """Extend the listen key, which expires after 60 minutes without it."""
+        if self._listen_key is None:
+            return
+        try:
+            self.client.renew_listen_key(self._listen_key)
+        except Exception as e:
+            raise RuntimeError(f"Failed to renew the Binance listen key: {e}") from e
+
+    def parse_user_message(
+        self, message: str | bytes | dict[str, Any]
+    ) -> list[Order | Fill | Position | Balance]:
+        """Normalize Binance order, account and position events."""
+        data = self._load_message(message)
+        if not isinstance(data, dict):
+            return []
+
+        match data.get("e"):
+            case "executionReport":
+                return self._parse_execution(
+                    data,
+                    {
+                        "symbol": data["s"],
+                        "side": data["S"],
+                        "type": data["o"],
+                        "timeInForce": data["f"],
+                        "origQty": data["q"],
+                        "price": data["p"],
+                        "stopPrice": data["P"],
+                        "status": data["X"],
+                        "orderId": data["i"],
+                        "clientOrderId": data["c"],
+                        "executedQty": data["z"],
+                        "cummulativeQuoteQty": data["Z"],
+                        "updateTime": data["T"],
+                    },
+                )
+            case "ORDER_TRADE_UPDATE":
+                order = data["o"]
+                return self._parse_execution(
+                    order,
+                    {
+                        "symbol": order["s"],
+                        "side": order["S"],
+                        "type": order["o"],
+                        "timeInForce": order["f"],
+                        "origQty": order["q"],
+                        "price": order["p"],
+                        "stopPrice": order["sp"],
+                        "status": order["X"],
+                        "orderId": order["i"],
+                        "clientOrderId": order["c"],
+                        "executedQty": order["z"],
+                        "avgPrice": order["ap"],
+                        "reduceOnly": order.get("R", False),
+                        "updateTime": order["T"],
+                    },
+                )
+            case "outboundAccountPosition":
+                time = to_utc(data["u"])
+                return [
+                    Balance(
+                        asset=item["a"],
+                        free=float(item["f"]),
+                        locked=float(item["l"]),
+                        updated_at=time,
+                    )
+                    for item in data["B"]
+                ]
+            case "ACCOUNT_UPDATE":
+                time = to_utc(data["T"])
+                account = data["a"]
+                updates: list[Order | Fill | Position | Balance] = [
+                    Balance(
+                        asset=item["a"],
+                        free=float(item["cw"]),
+                        locked=float(item["wb"]) - float(item["cw"]),
+                        updated_at=time,
+                    )
+                    for item in account.get("B", [])
+                ]
+                updates.extend(
+                    self._parse_position_update(item, time)
+                    for item in account.get("P", [])
+                )
+                return updates
+            case _:
+                return []
+
+    def _parse_execution(
+        self, event: dict, response: dict
+    ) -> list[Order | Fill | Position | Balance]:
+        """Order of an execution event, followed by its fill when it traded."""
+        order = self._parse_order(response)
+        if event.get("x") != "TRADE":
+            return [order]
+
+        fill = Fill(
+            order_id=str(event["i"]),
+            symbol=event["s"],
+            side=order.side,
+            quantity=float(event["l"]),
+            price=float(event["L"]),
+            time=to_utc(event["T"]),
+            fee=float(event.get("n") or 0),
+            fee_asset=event.get("N"),
+            realized_pnl=float(event.get("rp") or 0),
+            trade_id=str(event["t"]),
+        )
+        return [order, fill]
+
+    def _parse_position_update(self, item: dict, time: datetime) -> Position:
+        """Convert a position of a futures account update into a Position."""
+        amount = float(item["pa"])
+        match item.get("ps", "BOTH"):
+            case "LONG":
+                side = PositionSide.LONG
+            case "SHORT":
+                side = PositionSide.SHORT
+            case _:
+                side = PositionSide.LONG if amount >= 0 else PositionSide.SHORT
+
+        # The leverage is not part of the event: keep the known one
+        known = self.position_book.get_position(item["s"], side)
+        return Position(
+            symbol=item["s"],
+            side=side,
+            size=abs(amount),
+            entry_price=float(item["ep"]),
+            unrealized_pnl=float(item.get("up", 0) or 0),
+            realized_pnl=float(item.get("cr", 0) or 0),
+            leverage=known.leverage if known else 1,
+            margin_mode=MarginMode.CROSS
+            if item.get("mt", "").lower() == "cross"
+            else MarginMode.ISOLATED,
+            updated_at=time,
+        )
+
+    def get_klines(
+        self,
+        symbol: str,
+        timeframe: Timeframe,
+        start: datetime | None = None,
+        end: datetime | None = None,
+        limit: int = 500,
+    ) -> list[Candle]:
+        """Get historical candles from Binance, oldest first."""
+        params: dict[str, Any] = {"limit": limit}
+        if start is not None:
+            params["startTime"] = to_milliseconds(start)
+        if end is not None:
+            params["endTime"] = to_milliseconds(end)
+
+        try:
+            response = self.client.klines(
+                symbol.upper(), timeframe.get_name(), **params
+            )
+        except Exception as e:
+            raise RuntimeError(f"Failed to get Binance klines: {e}") from e
+
+        now = datetime.now(UTC)
+        return [
+            Candle(
+                symbol=symbol.upper(),
+                timeframe=timeframe,
+                open_time=to_utc(item[0]),
+                open=float(item[1]),
+                high=float(item[2]),
+                low=float(item[3]),
+                close=float(item[4]),
+                volume=float(item[5]),
+                is_closed=to_utc(item[6]) < now,
+            )
+            for item in response
+        ]
+
+    def get_instruments(self) -> list[Instrument] | None:
+        """Get the trading rules of the symbols from the Binance exchange info."""
+        try:
+            response = self.client.exchange_info()
+        except Exception as e:
+            raise RuntimeError(f"Failed to get Binance exchange info: {e}") from e
+
+        max_leverages = self._get_max_leverages()
+        return [
+            self._parse_instrument(item, max_leverages)
+            for item in response.get("symbols", [])
+        ]
+
+    def _get_max_leverages(self) -> dict[str, int]:
+        """Maximum leverage by symbol (pair for inverse contracts), from the leverage brackets.
+
+        The brackets are private: without credentials the leverage is unknown.
+        """
+        if self.market_type != MarketType.FUTURES or not self.api_key:
+            return {}
+        try:
+            response = self.client.leverage_brackets()
+        except Exception as e:
+            raise RuntimeError(f"Failed to get Binance leverage brackets: {e}") from e
+        return {
+            item.get("symbol") or item["pair"]: max(
+                int(bracket["initialLeverage"]) for bracket in item["brackets"]
+            )
+            for item in response
+            if item.get("brackets")
+        }
+
+    def _parse_instrument(
+        self, item: dict, max_leverages: dict[str, int]
+    ) -> Instrument:
+        """Normalize a symbol of the Binance exchange info."""
+        filters = {entry["filterType"]: entry for entry in item.get("filters", [])}
+        price_filter = filters.get("PRICE_FILTER", {})
+        lot_size = filters.get("LOT_SIZE", {})
+        notional = filters.get("NOTIONAL") or filters.get("MIN_NOTIONAL") or {}
+
+        # Spot and USD-M symbols report a status, COIN-M ones a contract status
+        match item.get("status") or item.get("contractStatus"):
+            case "TRADING":
+                status = InstrumentStatus.TRADING
+            case "CLOSE" | "DELIVERED" | "SETTLING" | "END_OF_DAY":
+                status = InstrumentStatus.DELISTED
+            case _:
+                status = InstrumentStatus.HALTED
+
+        if self.market_type == MarketType.FUTURES:
+            settlement_asset = item.get("marginAsset", item["quoteAsset"])
+            max_leverage = max_leverages.get(
+                item["symbol"], max_leverages.get(item.get("pair", ""), 0)
+            )
+        else:
+            settlement_asset = item["quoteAsset"]
+            max_leverage = 1
+
+        return Instrument(
+            symbol=item["symbol"],
+            base_asset=item["baseAsset"],
+            quote_asset=item["quoteAsset"],
+            settlement_asset=settlement_asset,
+            tick_size=float(price_filter.get("tickSize", 0) or 0),
+            lot_step=float(lot_size.get("stepSize", 0) or 0),
+            min_quantity=float(lot_size.get("minQty", 0) or 0),
+            max_quantity=float(lot_size.get("maxQty", 0) or 0),
+            min_notional=float(
+                notional.get("minNotional", notional.get("notional", 0)) or 0
+            ),
+            max_leverage=max_leverage,
+            contract_size=float(item.get("contractSize", 1) or 1),
+            status=status,
+        )
+
     def get_account(self) -> dict:
-        """Retrieves account information from Binance."""
+        """Retrieves account information from Binance.
+
+        Spot accounts list their assets under `balances` (free and locked
+        amounts), futures accounts under `assets` (wallet, margin and
+        available balances).
+        """
         if not self.client:
             raise ConnectionError("Client is not initialized.")
         try:
-            return {}  # self.client.account()
+            return self.client.account()
         except Exception as e:
             raise RuntimeError(f"Failed to get Binance account info: {e}") from e
 
     def get_balance(self) -> dict | float:
-        """Retrieves the account balance from Binance."""
+        """Retrieves the wallet balance of every asset held, without unrealized PnL."""
         account_info = self.get_account()
-        balance = {
-            item["asset"]: item["free"]
-            for item in account_info.get("balances", [])
-            if float(item["free"]) > 0
-        }
-        return balance
+        if self.market_type == MarketType.FUTURES:
+            amounts = (
+                (item["asset"], float(item.get("walletBalance", 0) or 0))
+                for item in account_info.get("assets", [])
+            )
+        else:
+            amounts = (
+                (
+                    item["asset"],
+                    float(item.get("free", 0) or 0) + float(item.get("locked", 0) or 0),
+                )
+                for item in account_info.get("balances", [])
+            )
+        return {asset: amount for asset, amount in amounts if amount}
 
-    def trade(
+    # POSITION
+    def get_positions(self, symbol: str | None = None) -> list[Position]:
+        """Get the open positions on Binance and sync them into the position book.
+
+        Spot accounts hold balances rather than positions, so the list is empty.
+        """
+        if self.market_type != MarketType.FUTURES:
+            return []
+
+        try:
+            if symbol:
+                response = self.client.get_position_risk(symbol=symbol.upper())
+            else:
+                response = self.client.get_position_risk()
+        except Exception as e:
+            raise RuntimeError(f"Failed to get Binance positions: {e}") from e
+
+        positions: list[Position] = []
+        for item in response:
+            amount = float(item.get("positionAmt", 0) or 0)
+            match item.get("positionSide", "BOTH"):
+                case "LONG":
+                    side = PositionSide.LONG
+                case "SHORT":
+                    side = PositionSide.SHORT
+                case _:
+                    side = PositionSide.LONG if amount >= 0 else PositionSide.SHORT
+
+            liquidation_price = float(item.get("liquidationPrice", 0) or 0)
+            position = Position(
+                symbol=item["symbol"],
+                side=side,
+                size=abs(amount),
+                entry_price=float(item.get("entryPrice", 0) or 0),
+                mark_price=float(item.get("markPrice", 0) or 0),
+                unrealized_pnl=float(item.get("unRealizedProfit", 0) or 0),
+                leverage=int(item.get("leverage", 1) or 1),
+                liquidation_price=liquidation_price or None,
+                margin_mode=MarginMode.CROSS
+                if item.get("marginType", "").lower() == "cross"
+                else MarginMode.ISOLATED,
+            )
+            self.position_book.sync(position)
+            if position.is_open:
+                positions.append(position)
+
+        return positions
+
+    def modify_position(
         self,
+        symbol: str,
         *,
-        lots: float = 0,
-        stop_loss: float = 0,
-        take_profit: float = 0,
-        trailing_stop: float = 0,
-        positions: int = 0,
-        slippage: int = 0,
-        fee: float = 0,
+        leverage: int | None = None,
+        margin_mode: str | MarginMode | None = None,
     ) -> None:
-        """Execute a trade with specified parameters."""
-        # TODO: Implement actual trading logic using the client
-        pass
+        """Change the leverage and/or margin mode used for a symbol on Binance."""
+        if self.market_type != MarketType.FUTURES:
+            raise ValueError("Leverage and margin mode apply to futures only.")
 
-    # POSITION
-    def open_position(self, side: str, fee: float) -> bool:
-        """Open a position."""
-        # TODO: Implement position opening logic
-        return False
-
-    def close_position(self, side: str) -> bool:
-        """Close a position."""
-        # TODO: Implement position closing logic
-        return False
-
-    def close_all_positions(self, side: str) -> bool:
-        """Close all positions."""
-        # TODO: Implement closing all positions logic
-        return False
-
-    def modify_position(self) -> bool:
-        """Modify a position."""
-        # TODO: Implement position modification logic
-        return False
-
-    def modify_all_positions(self) -> bool:
-        """Modify all positions."""
-        # TODO: Implement modification of all positions logic
-        return False
+        if isinstance(margin_mode, str):
+            margin_mode = MarginMode.get_margin_mode_from(margin_mode)
+
+        try:
+            if margin_mode is not None:
+                self.client.change_margin_type(
+                    symbol=symbol.upper(),
+                    marginType="CROSSED"
+                    if margin_mode == MarginMode.CROSS
+                    else "ISOLATED",
+                )
+            if leverage is not None:
+                self.client.change_leverage(symbol=symbol.upper(), leverage=leverage)
+        except Exception as e:
+            raise RuntimeError(f"Failed to modify Binance position: {e}") from e
 
     # ORDER
-    def open_order(self, side: str) -> bool:
-        """Open an order."""
-        # TODO: Implement order opening logic
-        return False
+    def place_order(
+        self,
+        symbol: str,
+        side: str | OrderSide,
+        order_type: str | OrderType,
+        quantity: float,
+        *,
+        price: float | None = None,
+        stop_price: float | None = None,
+        time_in_force: str | TimeInForce = DEFAULT_TIME_IN_FORCE,
+        reduce_only: bool = False,
+        post_only: bool = False,
+        client_order_id: str | None = None,
+    ) -> Order:
+        """Place a new order on Binance, rounded to the trading rules of the symbol."""
+        order = self.create_order(
+            symbol,
+            side,
+            order_type,
+            quantity,
+            price=price,
+            stop_price=stop_price,
+            time_in_force=time_in_force,
+            reduce_only=reduce_only,
+            post_only=post_only,
+            client_order_id=client_order_id,
+        )
+        order = self.prepare_order(order)
+
+        params: dict[str, Any] = {
+            "symbol": order.symbol.upper(),
+            "side": order.side.get_name().upper(),
+            "type": self._get_order_type_name(order),
+            "quantity": order.quantity,
+        }
+        if order.price is not None and order.type.requires_price():
+            params["price"] = order.price
+            params["timeInForce"] = self._get_time_in_force_name(order)
+        if order.stop_price is not None and order.type.requires_stop_price():
+            params["stopPrice"] = order.stop_price
+        if self.market_type == MarketType.FUTURES:
+            if self.position_mode is PositionMode.HEDGE:
+                # Hedge mode needs the position side and rejects reduceOnly
+                opening = order.side.opposite() if order.reduce_only else order.side
+                params["positionSide"] = "LONG" if opening is OrderSide.BUY else "SHORT"
+            elif order.reduce_only:
+                params["reduceOnly"] = "true"
+        if order.client_order_id:
+            params["newClientOrderId"] = order.client_order_id
+
+        try:
+            response = self.client.new_order(**params)
+        except Exception as e:
+            raise RuntimeError(f"Failed to place Binance order: {e}") from e
+        return self._parse_order(response, order)
+
+    def cancel_order(self, symbol: str, order_id: str) -> Order:
+        """Cancel an open order on Binance."""
+        try:
+            response = self.client.cancel_order(
+                symbol=symbol.upper(), orderId=int(order_id)
+            )
+        except Exception as e:
+            raise RuntimeError(f"Failed to cancel Binance order: {e}") from e
+        return self._parse_order(response)
+
+    def cancel_all_orders(self, symbol: str) -> list[Order]:
+        """Cancel all open orders for a symbol on Binance."""
+        try:
+            match self.market_type:
+                case MarketType.FUTURES:
+                    open_orders = self.client.get_orders(symbol=symbol.upper())
+                case _:
+                    open_orders = self.client.get_open_orders(symbol=symbol.upper())
+            self.client.cancel_open_orders(symbol=symbol.upper())
+        except Exception as e:
+            raise RuntimeError(f"Failed to cancel Binance orders: {e}") from e
+
+        orders = [self._parse_order(item) for item in open_orders]
+        for order in orders:
+            order.status = OrderStatus.CANCELED
+        return orders
+
+    def amend_order(
+        self,
+        symbol: str,
+        order_id: str,
+        *,
+        quantity: float | None = None,
+        price: float | None = None,
+        stop_price: float | None = None,
+    ) -> Order:
+        """Amend an open order on Binance.
+
+        Futures support in-place modification of limit orders; everything else
+        is amended by cancelling and replacing the order.
+        """
+        current = self.get_order(symbol, order_id)
+        if not current.is_open:
+            raise InvalidOrderError(
+                current.to_dict(),
+                f"Cannot amend order in status '{current.status.get_name()}'",
+            )
+
+        if (
+            self.market_type == MarketType.FUTURES
+            and current.type is OrderType.LIMIT
+            and stop_price is None
+        ):
+            amended = self.prepare_order(
+                replace(
+                    current,
+                    quantity=quantity if quantity is not None else current.quantity,
+                    price=price if price is not None else current.price,
+                )
+            )
+            try:
+                response = self.client.modify_order(
+                    symbol=symbol.upper(),
+                    orderId=int(order_id),
+                    side=current.side.get_name().upper(),
+                    quantity=amended.quantity,
+                    price=amended.price,
+                )
+            except Exception as e:
+                raise RuntimeError(f"Failed to amend Binance order: {e}") from e
+            return self._parse_order(response, current)
+
+        self.cancel_order(symbol, order_id)
+        return self.place_order(
+            symbol,
+            current.side,
+            current.type,
+            quantity if quantity is not None else current.remaining_quantity,
+            price=price if price is not None else current.price,
+            stop_price=stop_price if stop_price is not None else current.stop_price,
+            time_in_force=current.time_in_force,
+            reduce_only=current.reduce_only,
+            post_only=current.post_only,
+        )
+
+    def get_order(self, symbol: str, order_id: str) -> Order:
+        """Get the current state of an order on Binance."""
+        try:
+            match self.market_type:
+                case MarketType.FUTURES:
+                    response = self.client.query_order(
+                        symbol=symbol.upper(), orderId=int(order_id)
+                    )
+                case _:
+                    response = self.client.get_order(
+                        symbol=symbol.upper(), orderId=int(order_id)
+                    )
+        except Exception as e:
+            raise OrderNotFoundError(
+                order_id, f"Failed to get Binance order: {e}"
+            ) from e
+        return self._parse_order(response)
+
+    def _get_order_type_name(self, order: Order) -> str:
+        """Map an order type to the Binance order type name."""
+        if order.post_only and self.market_type == MarketType.SPOT:
+            return "LIMIT_MAKER"
+
+        match order.type, self.market_type:
+            case OrderType.MARKET, _:
+                return "MARKET"
+            case OrderType.LIMIT, _:
+                return "LIMIT"
+            case OrderType.STOP, MarketType.SPOT:
+                return "STOP_LOSS"
+            case OrderType.STOP, _:
+                return "STOP_MARKET"
+            case OrderType.STOP_LIMIT, MarketType.SPOT:
+                return "STOP_LOSS_LIMIT"
+            case OrderType.STOP_LIMIT, _:
+                return "STOP"
+            case OrderType.TAKE_PROFIT, MarketType.SPOT:
+                return "TAKE_PROFIT"
+            case OrderType.TAKE_PROFIT, _:
+                return "TAKE_PROFIT_MARKET"
+            case _:
+                raise InvalidOrderError(order.to_dict())
+
+    def _get_time_in_force_name(self, order: Order) -> str:
+        """Map a time in force to the Binance name (GTX is post-only on futures)."""
+        if order.post_only and self.market_type == MarketType.FUTURES:
+            return "GTX"
+        return order.time_in_force.get_name().upper()
+
+    @staticmethod
+    def _parse_order(response: dict, request: Order | None = None) -> Order:
+        """Convert a Binance order response into an Order."""
+        order_type: OrderType
+        match response.get("type", ""):
+            case "LIMIT" | "LIMIT_MAKER":
+                order_type = OrderType.LIMIT
+            case "STOP_LOSS" | "STOP_MARKET":
+                order_type = OrderType.STOP
+            case "STOP_LOSS_LIMIT" | "STOP":
+                order_type = OrderType.STOP_LIMIT
+            case "TAKE_PROFIT" | "TAKE_PROFIT_MARKET":
+                order_type = OrderType.TAKE_PROFIT
+            case "MARKET":
+                order_type = OrderType.MARKET
+            case _:
+                order_type = request.type if request else OrderType.MARKET
+
+        status: OrderStatus
+        match response.get("status", ""):
+            case "PARTIALLY_FILLED":
+                status = OrderStatus.PARTIALLY_FILLED
+            case "FILLED":
+                status = OrderStatus.FILLED
+            case "CANCELED" | "PENDING_CANCEL":
+                status = OrderStatus.CANCELED
+            case "REJECTED":
+                status = OrderStatus.REJECTED
+            case "EXPIRED" | "EXPIRED_IN_MATCH":
+                status = OrderStatus.EXPIRED
+            case _:
+                status = OrderStatus.NEW
 
-    def close_order(self, side: str) -> bool:
-        """Close an order."""
-        # TODO: Implement order closing logic
-        return False
+        filled = float(response.get("executedQty", 0) or 0)
+        average_price: float | None = None
+        if float(response.get("avgPrice", 0) or 0) > 0:
+            average_price = float(response["avgPrice"])
+        elif filled > 0 and float(response.get("cummulativeQuoteQty", 0) or 0) > 0:
+            average_price = float(response["cummulativeQuoteQty"]) / filled
 
-    def close_all_orders(self, side: str) -> bool:
-        """Close all orders."""
-        # TODO: Implement closing all orders logic
-        return False
+        price = float(response.get("price", 0) or 0)
+        stop_price = float(response.get("stopPrice", 0) or 0)
+        timestamp = response.get("updateTime") or response.get("transactTime")
+        time_in_force = response.get("timeInForce")
 
+        return Order(
+            symbol=response.get("symbol", request.symbol if request else ""),
+            side=OrderSide.get_order_side_from(response["side"])
+            if "side" in response
+            else (request.side if request else OrderSide.BUY),
+            type=order_type,
+            quantity=float(
+                response.get("origQty", request.quantity if request else 0)
+            ),
+            price=price or (request.price if request else None),
+            stop_price=stop_price or (request.stop_price if request else None),
+            time_in_force=TimeInForce.get_time_in_force_from(time_in_force)
+            if time_in_force in ("IOC", "FOK")
+            else TimeInForce.GTC,
+            reduce_only=bool(
+                response.get("reduceOnly", request.reduce_only if request else False)
+            ),
+            post_only=time_in_force == "GTX"
+            or response.get("type") == "LIMIT_MAKER"
+            or (request.post_only if request else False),
+            client_order_id=response.get("clientOrderId"),
+            id=str(response["orderId"]) if "orderId" in response else None,
+            status=status,
+            filled_quantity=filled,
+            average_price=average_price,
+            updated_at=datetime.fromtimestamp(timestamp / 1000, UTC)
+            if timestamp
+            else None,
+        )
 
 # def balance(self: Adapter):
 #     self.client.get_balance()
@@ -6,6 +6,7 @@ SPOT_PACKAGE_VERSION: str = "3.12.0"
 SPOT_MODULE: str = "binance.spot"
 SPOT_WS_BASE_URL: str = "wss://stream.binance.com"
 SPOT_WS_PORT: set[int] = {9443, 443}
+SPOT_WS_TESTNET_URL: str = "wss://stream.testnet.binance.vision"
 
 # Futures trading
 FUTURES_PACKAGE: str = "binance-futures-connector"
@@ -14,3 +15,7 @@ FUTURES_MODULE_LINEAR: str = "binance.um_futures"  # USDT-M Futures /fapi/*
 FUTURES_MODULE_INVERSE: str = "binance.cm_futures"  # COIN-M Delivery /dapi/*
 FUTURES_WS_BASE_URL: str = "wss://fstream.binance.com"
 FUTURES_WS_PORT: set[int] = {9443, 443}
+FUTURES_WS_TESTNET_URL: str = "wss://stream.binancefuture.com"
+
+# Levels of the order book snapshot fetched to initialize the depth stream
+BOOK_SNAPSHOT_LIMIT: int = 1000
@@ -1,5 +1,29 @@
+import hashlib
+import hmac
+import time
+from datetime import datetime
 from typing import Any
 
+from metaexpert.config import DEFAULT_TIME_IN_FORCE
+from metaexpert.core import (
+    AuthenticationError,
+    Balance,
+    Candle,
+    ContractType,
+    Fill,
+    MarginMode,
+    MarketType,
+    Order,
+    OrderBookUpdate,
+    OrderSide,
+    OrderStatus,
+    OrderType,
+    Position,
+    PositionSide,
+    Tick,
+    TimeInForce,
+    Timeframe,
+)
 from metaexpert.exchanges import (
     # ContractType,
     # MarginMode,
@@ -8,11 +32,21 @@ from metaexpert.exchanges import (
     # PositionMode,
 )
 from metaexpert.exchanges.bybit.config import (
+    BOOK_DEPTH,
     INVERSE_WS_BASE_URL,
+    INVERSE_WS_TESTNET_URL,
+    KLINE_INTERVALS,
     LINEAR_WS_BASE_URL,
+    LINEAR_WS_TESTNET_URL,
     OPTION_WS_BASE_URL,
+    OPTION_WS_TESTNET_URL,
+    PRIVATE_WS_BASE_URL,
+    PRIVATE_WS_TESTNET_URL,
     SPOT_WS_BASE_URL,
+    SPOT_WS_TESTNET_URL,
+    USER_STREAM_TOPICS,
 )
+from metaexpert.utils.time import to_utc
 
 
 class Adapter(MetaExchange):
@@ -29,29 +63,302 @@ class Adapter(MetaExchange):
 
     def get_websocket_url(self, symbol: str, timeframe: str) -> str:
         """Constructs the WebSocket URL for a given symbol and timeframe."""
-        # Bybit uses different streams for different market types
+        # Bybit uses different streams for different market types, the kline
+        # topic is subscribed to once connected (see get_subscribe_messages)
         # Docs: https://bybit-exchange.github.io/docs/v5/websocket/public/kline
-        if self.market_type == "spot":
-            base_url = SPOT_WS_BASE_URL
-        elif self.market_type == "futures":
-            if self.contract_type == "linear":
-                base_url = LINEAR_WS_BASE_URL
-            elif self.contract_type == "inverse":
-                base_url = INVERSE_WS_BASE_URL
-            else:
+        return self._get_public_url()
+
+    def _get_public_url(self) -> str:
+        """URL of the public stream of the market type."""
+        match self.market_type:
+            case MarketType.SPOT:
+                return SPOT_WS_TESTNET_URL if self.testnet else SPOT_WS_BASE_URL
+            case MarketType.FUTURES if self.contract_type is ContractType.INVERSE:
+                return INVERSE_WS_TESTNET_URL if self.testnet else INVERSE_WS_BASE_URL
+            case MarketType.FUTURES:
+                return LINEAR_WS_TESTNET_URL if self.testnet else LINEAR_WS_BASE_URL
+            case MarketType.OPTIONS:
+                return OPTION_WS_TESTNET_URL if self.testnet else OPTION_WS_BASE_URL
+            case _:
                 raise ValueError(
-                    f"Unsupported contract type for Bybit futures: {self.contract_type}"
+                    f"Unsupported market type for Bybit WebSocket: {self.market_type}"
                 )
-        elif self.market_type == "option":
-            base_url = OPTION_WS_BASE_URL
-        else:
-            raise ValueError(
-                f"Unsupported market type for Bybit WebSocket: {self.market_type}"
+
+    def get_subscribe_messages(self, symbol: str, timeframe: str) -> list[dict]:
+        """Subscribe to the kline topic of a symbol."""
+        if timeframe not in KLINE_INTERVALS:
+            raise ValueError(f"Unsupported timeframe for Bybit: {timeframe}")
+        topic = f"kline.{KLINE_INTERVALS[timeframe]}.{symbol.upper()}"
+        return [{"op": "subscribe", "args": [topic]}]
+
+    def get_ping_message(self) -> str | dict | None:
+        """Bybit closes the connections without a ping every 20 seconds."""
+        return {"op": "ping"}
+
+    def parse_message(
+        self, message: str | bytes | dict[str, Any]
+    ) -> list[Candle | Tick]:
+        """Normalize Bybit kline and public trade messages.
+
+        Docs: https://bybit-exchange.github.io/docs/v5/websocket/public/kline
+        """
+        data = self._load_message(message)
+        if not isinstance(data, dict) or "topic" not in data:
+            return []
+
+        channel, *_, symbol = data["topic"].split(".")
+        match channel:
+            case "kline":
+                timeframes = {value: key for key, value in KLINE_INTERVALS.items()}
+                return [
+                    Candle(
+                        symbol=symbol,
+                        timeframe=Timeframe.get_timeframe_from(
+                            timeframes[str(item["interval"])]
+                        ),
+                        open_time=to_utc(int(item["start"])),
+                        open=float(item["open"]),
+                        high=float(item["high"]),
+                        low=float(item["low"]),
+                        close=float(item["close"]),
+                        volume=float(item["volume"]),
+                        is_closed=bool(item["confirm"]),
+                    )
+                    for item in data.get("data", [])
+                ]
+            case "publicTrade":
+                return [
+                    Tick(
+                        symbol=item["s"],
+                        time=to_utc(int(item["T"])),
+                        price=float(item["p"]),
+                        volume=float(item["v"]),
+                        side=OrderSide.get_order_side_from(item["S"]),
+                    )
+                    for item in data.get("data", [])
+                ]
+            case _:
+                return []
+
+    # ORDER BOOK STREAM
+    def get_book_websocket_url(self, symbol: str) -> str | None:
+        """The order book topic is served by the public stream of the market type."""
+        return self._get_public_url()
+
+    def get_book_subscribe_messages(self, symbol: str) -> list[dict]:
+        """Subscribe to the order book topic of a symbol."""
+        return [{"op": "subscribe", "args": [self._get_book_topic(symbol)]}]
+
+    def get_book_unsubscribe_messages(self, symbol: str) -> list[dict]:
+        """Unsubscribe from the order book topic, to get a new snapshot."""
+        return [{"op": "unsubscribe", "args": [self._get_book_topic(symbol)]}]
+
+    def parse_book_message(
+        self, message: str | bytes | dict[str, Any]
+    ) -> list[OrderBookUpdate]:
+        """Normalize Bybit order book snapshots and deltas.
+
+        The update id `u` increases by one per delta and restarts from 1 with
+        a snapshot when the service restarts.
+        Docs: https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook
+        """
+        data = self._load_message(message)
+        if not isinstance(data, dict) or "topic" not in data:
+            return []
+        if not data["topic"].startswith("orderbook."):
+            return []
+
+        item = data["data"]
+        update_id = int(item["u"])
+        return [
+            OrderBookUpdate(
+                symbol=item["s"],
+                bids=tuple((price, size) for price, size in item["b"]),
+                asks=tuple((price, size) for price, size in item["a"]),
+                time=to_utc(int(data["ts"])),
+                is_snapshot=data.get("type") == "snapshot" or update_id == 1,
+                first_id=update_id,
+                last_id=update_id,
             )
+        ]
+
+    @staticmethod
+    def _get_book_topic(symbol: str) -> str:
+        """Order book topic of a symbol."""
+        return f"orderbook.{BOOK_DEPTH}.{symbol.upper()}"
+
+    # USER DATA STREAM
+    def get_user_stream_url(self) -> str | None:
+        """URL of the private stream, shared by all the market types.
+
+        Docs: https://bybit-exchange.github.io/docs/v5/ws/connect
+        """
+        if not self.api_key or not self.api_secret:
+            raise ValueError("API key and secret are required for the private stream.")
+        return PRIVATE_WS_TESTNET_URL if self.testnet else PRIVATE_WS_BASE_URL
+
+    def get_user_stream_login(self) -> dict | None:
+        """Authentication frame, signed with an expiry 10 seconds ahead."""
+        expires = int((time.time() + 10) * 1000)
+        signature = hmac.new(
+            str(self.api_secret).encode(),
+            f"GET/realtime{expires}".encode(),
+            hashlib.sha256,
+        ).hexdigest()
+        return {"op": "auth", "args": [self.api_key, expires, signature]}
+
+    def check_user_stream_login(self, message: str | bytes) -> None:
+        """Raise AuthenticationError unless the authentication succeeded."""
+        data = self._load_message(message)
+        if not isinstance(data, dict) or not data.get("success"):
+            reason = data.get("ret_msg") if isinstance(data, dict) else message
+            raise AuthenticationError("bybit", f"Bybit login failed: {reason}")
+
+    def get_user_stream_subscriptions(self) -> list[dict]:
+        """Subscribe to the order, execution, position and wallet topics."""
+        return [{"op": "subscribe", "args": list(USER_STREAM_TOPICS)}]
+
+    def parse_user_message(
+        self, message: str | bytes | dict[str, Any]
+    ) -> list[Order | Fill | Position | Balance]:
+        """Normalize Bybit order, execution, position and wallet messages.
+
+        Docs: https://bybit-exchange.github.io/docs/v5/websocket/private/order
+        """
+        data = self._load_message(message)
+        if not isinstance(data, dict) or "topic" not in data:
+            return []
+
+        items = data.get("data", [])
+        match data["topic"]:
+            case "order":
+                return [self._parse_order(item) for item in items]
+            case "execution":
+                return [
+                    Fill(
+                        order_id=item["orderId"],
+                        symbol=item["symbol"],
+                        side=OrderSide.get_order_side_from(item["side"]),
+                        quantity=float(item["execQty"]),
+                        price=float(item["execPrice"]),
+                        time=to_utc(int(item["execTime"])),
+                        fee=float(item.get("execFee") or 0),
+                        fee_asset=item.get("feeCurrency") or None,
+                        realized_pnl=float(item.get("execPnl") or 0),
+                        trade_id=item.get("execId"),
+                    )
+                    for item in items
+                    if item.get("execType", "Trade") == "Trade"
+                ]
+            case "position":
+                return [self._parse_position(item) for item in items]
+            case "wallet":
+                return [
+                    Balance(
+                        asset=coin["coin"],
+                        free=float(coin["walletBalance"] or 0)
+                        - float(coin.get("locked") or 0),
+                        locked=float(coin.get("locked") or 0),
+                        updated_at=to_utc(int(data["creationTime"])),
+                    )
+                    for item in items
+                    for coin in item.get("coin", [])
+                ]
+            case _:
+                return []
+
+    @staticmethod
+    def _parse_order(item: dict) -> Order:
+        """Convert an order of the private stream into an Order."""
+        trigger_price = float(item.get("triggerPrice") or 0)
+        order_type: OrderType
+        match item["orderType"], item.get("stopOrderType") or "":
+            case _, "TakeProfit" | "PartialTakeProfit":
+                order_type = OrderType.TAKE_PROFIT
+            case "Limit", _ if trigger_price:
+                order_type = OrderType.STOP_LIMIT
+            case "Market", _ if trigger_price:
+                order_type = OrderType.STOP
+            case "Limit", _:
+                order_type = OrderType.LIMIT
+            case _:
+                order_type = OrderType.MARKET
+
+        status: OrderStatus
+        match item["orderStatus"]:
+            case "PartiallyFilled":
+                status = OrderStatus.PARTIALLY_FILLED
+            case "Filled":
+                status = OrderStatus.FILLED
+            case "Cancelled" | "PartiallyFilledCanceled" | "Deactivated":
+                status = OrderStatus.CANCELED
+            case "Rejected":
+                status = OrderStatus.REJECTED
+            case _:
+                status = OrderStatus.NEW
 
-        # Bybit kline interval mapping might be different from internal representation
-        # e.g. '1h' -> '60'. Assuming direct mapping for now.
-        return f"{base_url}/{timeframe}/{symbol}"
+        time_in_force = item.get("timeInForce", "GTC")
+        average_price = float(item.get("avgPrice") or 0)
+        return Order(
+            symbol=item["symbol"],
+            side=OrderSide.get_order_side_from(item["side"]),
+            type=order_type,
+            quantity=float(item["qty"]),
+            price=float(item.get("price") or 0) or None,
+            stop_price=trigger_price or None,
+            time_in_force=TimeInForce.get_time_in_force_from(time_in_force)
+            if time_in_force in ("IOC", "FOK")
+            else TimeInForce.GTC,
+            reduce_only=bool(item.get("reduceOnly")),
+            post_only=time_in_force == "PostOnly",
+            client_order_id=item.get("orderLinkId") or None,
+            id=item["orderId"],
+            status=status,
+            filled_quantity=float(item.get("cumExecQty") or 0),
+            average_price=average_price or None,
+            updated_at=to_utc(int(item["updatedTime"])),
+        )
+
+    @staticmethod
+    def _parse_position(item: dict) -> Position:
+        """Convert a position of the private stream into a Position."""
+        # One-way positions report an empty side once closed
+        match item.get("side"), item.get("positionIdx", 0):
+            case "Sell", _:
+                side = PositionSide.SHORT
+            case "", 2:
+                side = PositionSide.SHORT
+            case _:
+                side = PositionSide.LONG
+
+        liquidation_price = float(item.get("liqPrice") or 0)
+        return Position(
+            symbol=item["symbol"],
+            side=side,
+            size=float(item["size"]),
+            entry_price=float(item.get("entryPrice") or 0),
+            mark_price=float(item.get("markPrice") or 0),
+            unrealized_pnl=float(item.get("unrealisedPnl") or 0),
+            realized_pnl=float(item.get("cumRealisedPnl") or 0),
+            leverage=int(float(item.get("leverage") or 1)),
+            liquidation_price=liquidation_price or None,
+            margin_mode=MarginMode.ISOLATED
+            if item.get("tradeMode") == 1
+            else MarginMode.CROSS,
+            updated_at=to_utc(int(item["updatedTime"])),
+        )
+
+    def get_klines(
+        self,
+        symbol: str,
+        timeframe: Timeframe,
+        start: datetime | None = None,
+        end: datetime | None = None,
+        limit: int = 500,
+    ) -> list[Candle]:
+        """Get historical candles from Bybit."""
+        # TODO: Implement kline retrieval using self.client
+        raise NotImplementedError
 
     def get_balance(self) -> dict | float:
         """Retrieves the account balance from Bybit."""
@@ -63,59 +370,66 @@ class Adapter(MetaExchange):
         # TODO: Implement account info retrieval using self.client
         raise NotImplementedError
 
-    def trade(
+    # POSITION
+    def get_positions(self, symbol: str | None = None) -> list[Position]:
+        """Get the open positions on Bybit."""
+        # TODO: Implement position retrieval using self.client
+        raise NotImplementedError
+
+    def modify_position(
         self,
+        symbol: str,
         *,
-        lots: float = 0,
-        stop_loss: float = 0,
-        take_profit: float = 0,
-        trailing_stop: float = 0,
-        positions: int = 0,
-        slippage: int = 0,
-        fee: float = 0,
+        leverage: int | None = None,
+        margin_mode: str | MarginMode | None = None,
     ) -> None:
-        """Execute a trade with specified parameters."""
-        # TODO: Implement actual trading logic using the client
-        pass
-
-    # POSITION
-    def open_position(self, side: str, fee: float) -> bool:
-        """Open a position."""
-        # TODO: Implement position opening logic
-        return False
-
-    def close_position(self, side: str) -> bool:
-        """Close a position."""
-        # TODO: Implement position closing logic
-        return False
-
-    def close_all_positions(self, side: str) -> bool:
-        """Close all positions."""
-        # TODO: Implement closing all positions logic
-        return False
-
-    def modify_position(self) -> bool:
-        """Modify a position."""
-        # TODO: Implement position modification logic
-        return False
-
-    def modify_all_positions(self) -> bool:
-        """Modify all positions."""
-        # TODO: Implement modification of all positions logic
-        return False
+        """Change the leverage and/or margin mode used for a symbol on Bybit."""
+        # TODO: Implement leverage and margin mode changes using self.client
+        raise NotImplementedError
 
     # ORDER
-    def open_order(self, side: str) -> bool:
-        """Open an order."""
-        # TODO: Implement order opening logic
-        return False
-
-    def close_order(self, side: str) -> bool:
-        """Close an order."""
-        # TODO: Implement order closing logic
-        return False
-
-    def close_all_orders(self, side: str) -> bool:
-        """Close all orders."""
-        # TODO: Implement closing all orders logic
-        return False
+    def place_order(
+        self,
+        symbol: str,
+        side: str | OrderSide,
+        order_type: str | OrderType,
+        quantity: float,
+        *,
+        price: float | None = None,
+        stop_price: float | None = None,
+        time_in_force: str | TimeInForce = DEFAULT_TIME_IN_FORCE,
+        reduce_only: bool = False,
+        post_only: bool = False,
+        client_order_id: str | None = None,
+    ) -> Order:
+        """Place a new order on Bybit."""
+        # TODO: Implement order placement using self.client
+        raise NotImplementedError
+
+    def cancel_order(self, symbol: str, order_id: str) -> Order:
+        """Cancel an open order on Bybit."""
+        # TODO: Implement order cancellation using self.client
+        raise NotImplementedError
+
+    def cancel_all_orders(self, symbol: str) -> list[Order]:
+        """Cancel all open orders for a symbol on Bybit."""
+        # TODO: Implement cancellation of all orders using self.client
+        raise NotImplementedError
+
+    def amend_order(
+        self,
+        symbol: str,
+        order_id: str,
+        *,
+        quantity: float | None = None,
+        price: float | None = None,
+        stop_price: float | None = None,
+    ) -> Order:
+        """Amend an open order on Bybit."""
+        # TODO: Implement order amendment using self.client
+        raise NotImplementedError
+
+    def get_order(self, symbol: str, order_id: str) -> Order:
+        """Get the current state of an order on Bybit."""
+        # TODO: Implement order retrieval using self.client
+        raise NotImplementedError
@@ -51,6 +51,25 @@ SPREAD_WS_TESTNET_URL: str = "wss://stream-testnet.bybit.com/v5/public/spread"
 # USDT/USDC Options:
 OPTION_WS_TESTNET_URL: str = "wss://stream-testnet.bybit.com/v5/public/option"
 
+# Kline intervals of the public stream, by timeframe:
+KLINE_INTERVALS: dict[str, str] = {
+    "1m": "1",
+    "3m": "3",
+    "5m": "5",
+    "15m": "15",
+    "30m": "30",
+    "1h": "60",
+    "2h": "120",
+    "4h": "240",
+    "6h": "360",
+    "12h": "720",
+    "1d": "D",
+    "1w": "W",
+}
+
+# Depth of the order book topic (50 levels, pushed every 20 ms):
+BOOK_DEPTH: int = 50
+
 # -----------------------------------------------------------------------------
 # WEBSOCKET PRIVATE STREAM
 # -----------------------------------------------------------------------------
@@ -61,6 +80,9 @@ PRIVATE_WS_BASE_URL: str = "wss://stream.bybit.com/v5/private"
 # Testnet:
 PRIVATE_WS_TESTNET_URL: str = "wss://stream-testnet.bybit.com/v5/private"
 
+# Topics of the order, fill, position and balance updates:
+USER_STREAM_TOPICS: tuple[str, ...] = ("order", "execution", "position", "wallet")
+
 # -----------------------------------------------------------------------------
 # WEBSOCKET ORDER ENTRY
 # -----------------------------------------------------------------------------
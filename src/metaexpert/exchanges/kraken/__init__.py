@@ -1,9 +1,42 @@
+from dataclasses import replace
+from datetime import datetime
 from importlib import import_module
 from typing import Any
 
-from metaexpert.core import MarketType
+from metaexpert.config import DEFAULT_TIME_IN_FORCE
+from metaexpert.core import (
+    Candle,
+    MarginMode,
+    MarketType,
+    Order,
+    OrderSide,
+    OrderType,
+    Position,
+    Tick,
+    TimeInForce,
+    Timeframe,
+)
 from metaexpert.exchanges import MetaExchange
 from metaexpert.utils.package import install_package
+from metaexpert.utils.time import to_utc
+
+# Public spot stream (WebSocket API v2)
+WS_BASE_URL: str = "wss://ws.kraken.com/v2"
+
+# OHLC intervals in minutes, by timeframe
+KLINE_INTERVALS: dict[str, int] = {
+    "1m": 1,
+    "5m": 5,
+    "15m": 15,
+    "30m": 30,
+    "1h": 60,
+    "4h": 240,
+    "1d": 1440,
+    "1w": 10080,
+}
+
+# Quote currencies used to split symbols into pairs (BTCUSD -> BTC/USD)
+QUOTE_CURRENCIES: tuple[str, ...] = ("USDT", "USDC", "USD", "EUR", "GBP", "BTC", "ETH")
 
 
 class Adapter(MetaExchange):
@@ -13,6 +46,8 @@ class Adapter(MetaExchange):
         """Initializes the Kraken Stock class."""
         # The client is created on-demand based on market type
         self.client = None
+        # Last OHLC update of every stream: Kraken does not flag closed candles
+        self._klines: dict[tuple[str, int], Candle] = {}
 
     def _create_client(self) -> Any:
         """Initializes and returns the Kraken client."""
@@ -33,6 +68,97 @@ class Adapter(MetaExchange):
 
     def get_websocket_url(self, symbol: str, timeframe: str) -> str:
         """Constructs the WebSocket URL for a given symbol and timeframe."""
+        # Docs: https://docs.kraken.com/api/docs/websocket-v2/ohlc
+        if self.market_type != MarketType.SPOT:
+            raise ValueError(
+                f"Unsupported market type for Kraken WebSocket: {self.market_type}"
+            )
+        return WS_BASE_URL
+
+    def get_subscribe_messages(self, symbol: str, timeframe: str) -> list[dict]:
+        """Subscribe to the OHLC channel of a pair."""
+        if timeframe not in KLINE_INTERVALS:
+            raise ValueError(f"Unsupported timeframe for Kraken: {timeframe}")
+        params = {
+            "channel": "ohlc",
+            "symbol": [self.get_pair(symbol)],
+            "interval": KLINE_INTERVALS[timeframe],
+            "snapshot": False,
+        }
+        return [{"method": "subscribe", "params": params}]
+
+    @staticmethod
+    def get_pair(symbol: str) -> str:
+        """Kraken pair of a symbol (BTCUSD -> BTC/USD)."""
+        symbol = symbol.upper()
+        if "/" not in symbol:
+            for quote in QUOTE_CURRENCIES:
+                if symbol.endswith(quote) and len(symbol) > len(quote):
+                    return f"{symbol[: -len(quote)]}/{quote}"
+        return symbol
+
+    def parse_message(
+        self, message: str | bytes | dict[str, Any]
+    ) -> list[Candle | Tick]:
+        """Normalize Kraken OHLC and trade messages.
+
+        Kraken sends the forming candle on every trade without a close flag: a
+        candle is reported closed when the first update of the next one arrives.
+        """
+        data = self._load_message(message)
+        if not isinstance(data, dict) or data.get("type") not in ("snapshot", "update"):
+            return []
+
+        match data.get("channel"):
+            case "ohlc":
+                timeframes = {value: key for key, value in KLINE_INTERVALS.items()}
+                candles: list[Candle | Tick] = []
+                for item in data["data"]:
+                    candle = Candle(
+                        symbol=item["symbol"].replace("/", ""),
+                        timeframe=Timeframe.get_timeframe_from(
+                            timeframes[int(item["interval"])]
+                        ),
+                        open_time=to_utc(item["interval_begin"]),
+                        open=float(item["open"]),
+                        high=float(item["high"]),
+                        low=float(item["low"]),
+                        close=float(item["close"]),
+                        volume=float(item["volume"]),
+                        is_closed=False,
+                    )
+                    key = (candle.symbol, int(item["interval"]))
+                    previous = self._klines.get(key)
+                    if previous is not None and previous.open_time < candle.open_time:
+                        candles.append(replace(previous, is_closed=True))
+                    if previous is None or previous.open_time <= candle.open_time:
+                        self._klines[key] = candle
+                        candles.append(candle)
+                return candles
+            case "trade":
+                return [
+                    Tick(
+                        symbol=item["symbol"].replace("/", ""),
+                        time=to_utc(item["timestamp"]),
+                        price=float(item["price"]),
+                        volume=float(item["qty"]),
+                        side=OrderSide.get_order_side_from(item["side"]),
+                    )
+                    for item in data["data"]
+                ]
+            case _:
+                return []
+
+    def get_klines(
+        self,
+        symbol: str,
+        timeframe: Timeframe,
+        start: datetime | None = None,
+        end: datetime | None = None,
+        limit: int = 500,
+    ) -> list[Candle]:
+        """Get historical candles from Kraken."""
+        # TODO: Implement kline retrieval using self.client
         raise NotImplementedError
 
     def get_balance(self) -> dict | float:
@@ -49,61 +175,66 @@ class Adapter(MetaExchange):
         # TODO: Implement account info retrieval using self.client
         raise NotImplementedError
 
-    def trade(
+    # POSITION
+    def get_positions(self, symbol: str | None = None) -> list[Position]:
+        """Get the open positions on Kraken."""
+        # TODO: Implement position retrieval using self.client
+        raise NotImplementedError
+
+    def modify_position(
         self,
+        symbol: str,
         *,
-        lots: float = 0,
-        stop_loss: float = 0,
-        take_profit: float = 0,
-        trailing_stop: float = 0,
-        positions: int = 0,
-        slippage: int = 0,
-        fee: float = 0,
+        leverage: int | None = None,
+        margin_mode: str | MarginMode | None = None,
     ) -> None:
-        """Execute a trade with specified parameters."""
-        if not self.client:
-            self.client = self._create_client()
-        # TODO: Implement actual trading logic using the client
-        pass
-
-    # POSITION
-    def open_position(self, side: str, fee: float) -> bool:
-        """Open a position."""
-        # TODO: Implement position opening logic
-        return False
-
-    def close_position(self, side: str) -> bool:
-        """Close a position."""
-        # TODO: Implement position closing logic
-        return False
-
-    def close_all_positions(self, side: str) -> bool:
-        """Close all positions."""
-        # TODO: Implement closing all positions logic
-        return False
-
-    def modify_position(self) -> bool:
-        """Modify a position."""
-        # TODO: Implement position modification logic
-        return False
-
-    def modify_all_positions(self) -> bool:
-        """Modify all positions."""
-        # TODO: Implement modification of all positions logic
-        return False
+        """Change the leverage and/or margin mode used for a symbol on Kraken."""
+        # TODO: Implement leverage and margin mode changes using self.client
+        raise NotImplementedError
 
     # ORDER
-    def open_order(self, side: str) -> bool:
-        """Open an order."""
-        # TODO: Implement order opening logic
-        return False
-
-    def close_order(self, side: str) -> bool:
-        """Close an order."""
-        # TODO: Implement order closing logic
-        return False
-
-    def close_all_orders(self, side: str) -> bool:
-        """Close all orders."""
-        # TODO: Implement closing all orders logic
-        return False
\ No newline at end of file
+    def place_order(
+        self,
+        symbol: str,
+        side: str | OrderSide,
+        order_type: str | OrderType,
+        quantity: float,
+        *,
+        price: float | None = None,
+        stop_price: float | None = None,
+        time_in_force: str | TimeInForce = DEFAULT_TIME_IN_FORCE,
+        reduce_only: bool = False,
+        post_only: bool = False,
+        client_order_id: str | None = None,
+    ) -> Order:
+        """Place a new order on Kraken."""
+        # TODO: Implement order placement using self.client
+        raise NotImplementedError
+
+    def cancel_order(self, symbol: str, order_id: str) -> Order:
+        """Cancel an open order on Kraken."""
+        # TODO: Implement order cancellation using self.client
+        raise NotImplementedError
+
+    def cancel_all_orders(self, symbol: str) -> list[Order]:
+        """Cancel all open orders for a symbol on Kraken."""
+        # TODO: Implement cancellation of all orders using self.client
+        raise NotImplementedError
+
+    def amend_order(
+        self,
+        symbol: str,
+        order_id: str,
+        *,
+        quantity: float | None = None,
+        price: float | None = None,
+        stop_price: float | None = None,
+    ) -> Order:
+        """Amend an open order on Kraken."""
+        # TODO: Implement order amendment using self.client
+        raise NotImplementedError
+
+    def get_order(self, symbol: str, order_id: str) -> Order:
+        """Get the current state of an order on Kraken."""
+        # TODO: Implement order retrieval using self.client
+        raise NotImplementedError
@@ -1,5 +1,17 @@
+from datetime import datetime
 from typing import Any
 
+from metaexpert.config import DEFAULT_TIME_IN_FORCE
+from metaexpert.core import (
+    Candle,
+    MarginMode,
+    Order,
+    OrderSide,
+    OrderType,
+    Position,
+    TimeInForce,
+    Timeframe,
+)
 from metaexpert.exchanges import (
     # ContractType,
     # MarginMode,
@@ -27,6 +39,18 @@ class Adapter(MetaExchange):
         # Docs:
         return ""
 
+    def get_klines(
+        self,
+        symbol: str,
+        timeframe: Timeframe,
+        start: datetime | None = None,
+        end: datetime | None = None,
+        limit: int = 500,
+    ) -> list[Candle]:
+        """Get historical candles from MEXC."""
+        # TODO: Implement kline retrieval using self.client
+        raise NotImplementedError
+
     def get_balance(self) -> dict | float:
         """Retrieves the account balance from MEXC."""
         # TODO: Implement balance retrieval using self.client
@@ -37,59 +61,66 @@ class Adapter(MetaExchange):
         # TODO: Implement account info retrieval using self.client
         raise NotImplementedError
 
-    def trade(
+    # POSITION
+    def get_positions(self, symbol: str | None = None) -> list[Position]:
+        """Get the open positions on MEXC."""
+        # TODO: Implement position retrieval using self.client
+        raise NotImplementedError
+
+    def modify_position(
         self,
+        symbol: str,
         *,
-        lots: float = 0,
-        stop_loss: float = 0,
-        take_profit: float = 0,
-        trailing_stop: float = 0,
-        positions: int = 0,
-        slippage: int = 0,
-        fee: float = 0,
+        leverage: int | None = None,
+        margin_mode: str | MarginMode | None = None,
     ) -> None:
-        """Execute a trade with specified parameters."""
-        # TODO: Implement actual trading logic using the client
-        pass
-
-    # POSITION
-    def open_position(self, side: str, fee: float) -> bool:
-        """Open a position."""
-        # TODO: Implement position opening logic
-        return False
-
-    def close_position(self, side: str) -> bool:
-        """Close a position."""
-        # TODO: Implement position closing logic
-        return False
-
-    def close_all_positions(self, side: str) -> bool:
-        """Close all positions."""
-        # TODO: Implement closing all positions logic
-        return False
-
-    def modify_position(self) -> bool:
-        """Modify a position."""
-        # TODO: Implement position modification logic
-        return False
-
-    def modify_all_positions(self) -> bool:
-        """Modify all positions."""
-        # TODO: Implement modification of all positions logic
-        return False
+        """Change the leverage and/or margin mode used for a symbol on MEXC."""
+        # TODO: Implement leverage and margin mode changes using self.client
+        raise NotImplementedError
 
     # ORDER
-    def open_order(self, side: str) -> bool:
-        """Open an order."""
-        # TODO: Implement order opening logic
-        return False
-
-    def close_order(self, side: str) -> bool:
-        """Close an order."""
-        # TODO: Implement order closing logic
-        return False
-
-    def close_all_orders(self, side: str) -> bool:
-        """Close all orders."""
-        # TODO: Implement closing all orders logic
-        return False
+    def place_order(
+        self,
+        symbol: str,
+        side: str | OrderSide,
+        order_type: str | OrderType,
+        quantity: float,
+        *,
+        price: float | None = None,
+        stop_price: float | None = None,
+        time_in_force: str | TimeInForce = DEFAULT_TIME_IN_FORCE,
+        reduce_only: bool = False,
+        post_only: bool = False,
+        client_order_id: str | None = None,
+    ) -> Order:
+        """Place a new order on MEXC."""
+        # TODO: Implement order placement using self.client
+        raise NotImplementedError
+
+    def cancel_order(self, symbol: str, order_id: str) -> Order:
+        """Cancel an open order on MEXC."""
+        # TODO: Implement order cancellation using self.client
+        raise NotImplementedError
+
+    def cancel_all_orders(self, symbol: str) -> list[Order]:
+        """Cancel all open orders for a symbol on MEXC."""
+        # TODO: Implement cancellation of all orders using self.client
+        raise NotImplementedError
+
+    def amend_order(
+        self,
+        symbol: str,
+        order_id: str,
+        *,
+        quantity: float | None = None,
+        price: float | None = None,
+        stop_price: float | None = None,
+    ) -> Order:
+        """Amend an open order on MEXC."""
+        # TODO: Implement order amendment using self.client
+        raise NotImplementedError
+
+    def get_order(self, symbol: str, order_id: str) -> Order:
+        """Get the current state of an order on MEXC."""
+        # TODO: Implement order retrieval using self.client
+        raise NotImplementedError
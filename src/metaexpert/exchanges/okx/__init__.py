@@ -1,14 +1,47 @@
+import base64
+import hashlib
+import hmac
+import time
+import zlib
+from datetime import datetime
 from typing import Any
 
+from metaexpert.config import DEFAULT_TIME_IN_FORCE
+from metaexpert.core import (
+    AuthenticationError,
+    Balance,
+    Candle,
+    Fill,
+    MarginMode,
+    MarketType,
+    Order,
+    OrderBookUpdate,
+    OrderSide,
+    OrderStatus,
+    OrderType,
+    Position,
+    PositionSide,
+    Tick,
+    TimeInForce,
+    Timeframe,
+)
 from metaexpert.exchanges import (
     MetaExchange,
 )
 from metaexpert.exchanges.okx.config import (
-    FUTURES_WS_BASE_URL,
-    OPTION_WS_BASE_URL,
+    BOOK_CHANNEL,
+    BOOK_CHECKSUM_DEPTH,
+    BUSINESS_WS_BASE_URL,
+    BUSINESS_WS_TESTNET_URL,
+    KLINE_CHANNELS,
+    PRIVATE_WS_BASE_URL,
+    PRIVATE_WS_TESTNET_URL,
+    QUOTE_CURRENCIES,
     SPOT_WS_BASE_URL,
-    SWAP_WS_BASE_URL,
+    SPOT_WS_TESTNET_URL,
+    USER_STREAM_CHANNELS,
 )
+from metaexpert.utils.time import to_utc
 
 
 class Adapter(MetaExchange):
@@ -25,23 +58,318 @@ class Adapter(MetaExchange):
 
     def get_websocket_url(self, symbol: str, timeframe: str) -> str:
         """Constructs the WebSocket URL for a given symbol and timeframe."""
-        # OKX uses different streams for different market types
+        # OKX serves the candlestick channels of all market types on the business
+        # endpoint, the channel is subscribed to once connected
         # Docs: https://www.okx.com/docs-v5/en/#websocket-api-public-channel-candlesticks
-        if self.market_type == "spot":
-            base_url = SPOT_WS_BASE_URL
-        elif self.market_type == "futures":
-            base_url = FUTURES_WS_BASE_URL
-        elif self.market_type == "swap":
-            base_url = SWAP_WS_BASE_URL
-        elif self.market_type == "option":
-            base_url = OPTION_WS_BASE_URL
-        else:
+        return BUSINESS_WS_TESTNET_URL if self.testnet else BUSINESS_WS_BASE_URL
+
+    def get_subscribe_messages(self, symbol: str, timeframe: str) -> list[dict]:
+        """Subscribe to the candlestick channel of an instrument."""
+        if timeframe not in KLINE_CHANNELS:
+            raise ValueError(f"Unsupported timeframe for OKX: {timeframe}")
+        channel = KLINE_CHANNELS[timeframe]
+        args = [{"channel": channel, "instId": self.get_inst_id(symbol)}]
+        return [{"op": "subscribe", "args": args}]
+
+    def get_inst_id(self, symbol: str) -> str:
+        """OKX instrument id of a symbol (BTCUSDT -> BTC-USDT, or BTC-USDT-SWAP for futures)."""
+        symbol = symbol.upper()
+        if "-" not in symbol:
+            for quote in QUOTE_CURRENCIES:
+                if symbol.endswith(quote) and len(symbol) > len(quote):
+                    symbol = f"{symbol[: -len(quote)]}-{quote}"
+                    break
+        if self.market_type is MarketType.FUTURES and symbol.count("-") == 1:
+            symbol = f"{symbol}-SWAP"
+        return symbol
+
+    @staticmethod
+    def get_symbol(inst_id: str) -> str:
+        """Symbol of an OKX instrument id (BTC-USDT-SWAP -> BTCUSDT)."""
+        return inst_id.removesuffix("-SWAP").replace("-", "")
+
+    def get_ping_message(self) -> str | dict | None:
+        """OKX closes the connections idle for 30 seconds, unless pinged."""
+        return "ping"
+
+    def parse_message(
+        self, message: str | bytes | dict[str, Any]
+    ) -> list[Candle | Tick]:
+        """Normalize OKX candlestick and trade messages.
+
+        Docs: https://www.okx.com/docs-v5/en/#websocket-api-public-channel-candlesticks
+        """
+        data = self._load_message(message)
+        if not isinstance(data, dict) or "data" not in data:
+            return []
+
+        arg = data.get("arg", {})
+        channel = arg.get("channel", "")
+        symbol = self.get_symbol(arg.get("instId", ""))
+        if channel.startswith("candle"):
+            timeframes = {value: key for key, value in KLINE_CHANNELS.items()}
+            if channel not in timeframes:
+                return []
+            timeframe = Timeframe.get_timeframe_from(timeframes[channel])
+            # [ts, o, h, l, c, vol, volCcy, volCcyQuote, confirm]
+            return [
+                Candle(
+                    symbol=symbol,
+                    timeframe=timeframe,
+                    open_time=to_utc(int(item[0])),
+                    open=float(item[1]),
+                    high=float(item[2]),
+                    low=float(item[3]),
+                    close=float(item[4]),
+                    volume=float(item[5]),
+                    is_closed=item[8] == "1",
+                )
+                for item in data["data"]
+            ]
+        if channel == "trades":
+            return [
+                Tick(
+                    symbol=self.get_symbol(item["instId"]),
+                    time=to_utc(int(item["ts"])),
+                    price=float(item["px"]),
+                    volume=float(item["sz"]),
+                    side=OrderSide.get_order_side_from(item["side"]),
+                )
+                for item in data["data"]
+            ]
+        return []
+
+    # ORDER BOOK STREAM
+    def get_book_websocket_url(self, symbol: str) -> str | None:
+        """The order book channel is served by the public endpoint."""
+        return SPOT_WS_TESTNET_URL if self.testnet else SPOT_WS_BASE_URL
+
+    def get_book_subscribe_messages(self, symbol: str) -> list[dict]:
+        """Subscribe to the order book channel of an instrument."""
+        args = [{"channel": BOOK_CHANNEL, "instId": self.get_inst_id(symbol)}]
+        return [{"op": "subscribe", "args": args}]
+
+    def get_book_unsubscribe_messages(self, symbol: str) -> list[dict]:
+        """Unsubscribe from the order book channel, to get a new snapshot."""
+        args = [{"channel": BOOK_CHANNEL, "instId": self.get_inst_id(symbol)}]
+        return [{"op": "unsubscribe", "args": args}]
+
+    def parse_book_message(
+        self, message: str | bytes | dict[str, Any]
+    ) -> list[OrderBookUpdate]:
+        """Normalize OKX order book snapshots and incremental updates.
+
+        Docs: https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel
+        """
+        data = self._load_message(message)
+        if not isinstance(data, dict) or "data" not in data:
+            return []
+        if data.get("arg", {}).get("channel") != BOOK_CHANNEL:
+            return []
+
+        is_snapshot = data.get("action") == "snapshot"
+        return [
+            OrderBookUpdate(
+                symbol=self.get_symbol(data["arg"]["instId"]),
+                # Levels are [price, size, deprecated, number of orders]
+                bids=tuple((level[0], level[1]) for level in item["bids"]),
+                asks=tuple((level[0], level[1]) for level in item["asks"]),
+                time=to_utc(int(item["ts"])),
+                is_snapshot=is_snapshot,
+                last_id=int(item["seqId"]),
+                prev_id=None if is_snapshot else int(item["prevSeqId"]),
+                checksum=int(item["checksum"]) if "checksum" in item else None,
+            )
+            for item in data["data"]
+        ]
+
+    def compute_book_checksum(
+        self, bids: list[tuple[str, str]], asks: list[tuple[str, str]]
+    ) -> int:
+        """Signed CRC32 of the top 25 levels, alternating bids and asks."""
+        parts: list[str] = []
+        for index in range(BOOK_CHECKSUM_DEPTH):
+            if index < len(bids):
+                parts.extend(bids[index])
+            if index < len(asks):
+                parts.extend(asks[index])
+        checksum = zlib.crc32(":".join(parts).encode())
+        return checksum - 2**32 if checksum >= 2**31 else checksum
+
+    # USER DATA STREAM
+    def get_user_stream_url(self) -> str | None:
+        """URL of the private stream.
+
+        Docs: https://www.okx.com/docs-v5/en/#overview-websocket-login
+        """
+        if not self.api_key or not self.api_secret or not self.api_passphrase:
             raise ValueError(
-                f"Unsupported market type for OKX WebSocket: {self.market_type}"
+                "API key, secret and passphrase are required for the private stream."
             )
+        return PRIVATE_WS_TESTNET_URL if self.testnet else PRIVATE_WS_BASE_URL
+
+    def get_user_stream_login(self) -> dict | None:
+        """Login frame, signed with the current timestamp."""
+        timestamp = str(int(time.time()))
+        signature = hmac.new(
+            str(self.api_secret).encode(),
+            f"{timestamp}GET/users/self/verify".encode(),
+            hashlib.sha256,
+        ).digest()
+        return {
+            "op": "login",
+            "args": [
+                {
+                    "apiKey": self.api_key,
+                    "passphrase": self.api_passphrase,
+                    "timestamp": timestamp,
+                    "sign": base64.b64encode(signature).decode(),
+                }
+            ],
+        }
+
+    def check_user_stream_login(self, message: str | bytes) -> None:
+        """Raise AuthenticationError unless the login succeeded."""
+        data = self._load_message(message)
+        if not isinstance(data, dict) or data.get("event") != "login":
+            reason = data.get("msg") if isinstance(data, dict) else message
+            raise AuthenticationError("okx", f"OKX login failed: {reason}")
+
+    def get_user_stream_subscriptions(self) -> list[dict]:
+        """Subscribe to the orders, positions and account channels."""
+        args = [dict(arg) for arg in USER_STREAM_CHANNELS]
+        return [{"op": "subscribe", "args": args}]
+
+    def parse_user_message(
+        self, message: str | bytes | dict[str, Any]
+    ) -> list[Order | Fill | Position | Balance]:
+        """Normalize OKX order, position and account messages.
+
+        Quantities of derivatives are reported in contracts.
+        Docs: https://www.okx.com/docs-v5/en/#order-book-trading-trade-ws-order-channel
+        """
+        data = self._load_message(message)
+        if not isinstance(data, dict) or "data" not in data:
+            return []
+
+        updates: list[Order | Fill | Position | Balance] = []
+        match data.get("arg", {}).get("channel"):
+            case "orders":
+                for item in data["data"]:
+                    order = self._parse_order(item)
+                    updates.append(order)
+                    if float(item.get("fillSz") or 0) > 0:
+                        updates.append(
+                            Fill(
+                                order_id=item["ordId"],
+                                symbol=order.symbol,
+                                side=order.side,
+                                quantity=float(item["fillSz"]),
+                                price=float(item["fillPx"]),
+                                time=to_utc(int(item["fillTime"])),
+                                # OKX reports the fee as a negative amount
+                                fee=-float(item.get("fillFee") or 0),
+                                fee_asset=item.get("fillFeeCcy") or None,
+                                realized_pnl=float(item.get("fillPnl") or 0),
+                                trade_id=item.get("tradeId") or None,
+                            )
+                        )
+            case "positions":
+                updates.extend(self._parse_position(item) for item in data["data"])
+            case "account":
+                for item in data["data"]:
+                    updates.extend(
+                        Balance(
+                            asset=detail["ccy"],
+                            free=float(detail.get("availBal") or 0),
+                            locked=float(detail.get("frozenBal") or 0),
+                            updated_at=to_utc(int(item["uTime"])),
+                        )
+                        for detail in item.get("details", [])
+                    )
+        return updates
+
+    def _parse_order(self, item: dict) -> Order:
+        """Convert an order of the private stream into an Order."""
+        order_type: OrderType
+        time_in_force = TimeInForce.GTC
+        match item["ordType"]:
+            case "market":
+                order_type = OrderType.MARKET
+            case "fok" | "ioc" as name:
+                order_type = OrderType.LIMIT
+                time_in_force = TimeInForce.get_time_in_force_from(name)
+            case _:
+                order_type = OrderType.LIMIT
 
-        # OKX has specific format for kline subscription
-        return f"{base_url}/candle{timeframe}@{symbol}"
+        status: OrderStatus
+        match item["state"]:
+            case "partially_filled":
+                status = OrderStatus.PARTIALLY_FILLED
+            case "filled":
+                status = OrderStatus.FILLED
+            case "canceled" | "mmp_canceled":
+                status = OrderStatus.CANCELED
+            case _:
+                status = OrderStatus.NEW
+
+        average_price = float(item.get("avgPx") or 0)
+        return Order(
+            symbol=self.get_symbol(item["instId"]),
+            side=OrderSide.get_order_side_from(item["side"]),
+            type=order_type,
+            quantity=float(item["sz"]),
+            price=float(item.get("px") or 0) or None,
+            time_in_force=time_in_force,
+            reduce_only=item.get("reduceOnly") == "true",
+            post_only=item["ordType"] == "post_only",
+            client_order_id=item.get("clOrdId") or None,
+            id=item["ordId"],
+            status=status,
+            filled_quantity=float(item.get("accFillSz") or 0),
+            average_price=average_price or None,
+            updated_at=to_utc(int(item["uTime"])),
+        )
+
+    def _parse_position(self, item: dict) -> Position:
+        """Convert a position of the private stream into a Position."""
+        amount = float(item.get("pos") or 0)
+        match item.get("posSide"):
+            case "long":
+                side = PositionSide.LONG
+            case "short":
+                side = PositionSide.SHORT
+            case _:
+                side = PositionSide.LONG if amount >= 0 else PositionSide.SHORT
+
+        liquidation_price = float(item.get("liqPx") or 0)
+        return Position(
+            symbol=self.get_symbol(item["instId"]),
+            side=side,
+            size=abs(amount),
+            entry_price=float(item.get("avgPx") or 0),
+            mark_price=float(item.get("markPx") or 0),
+            unrealized_pnl=float(item.get("upl") or 0),
+            realized_pnl=float(item.get("realizedPnl") or 0),
+            leverage=int(float(item.get("lever") or 1)),
+            liquidation_price=liquidation_price or None,
+            margin_mode=MarginMode.CROSS
+            if item.get("mgnMode") == "cross"
+            else MarginMode.ISOLATED,
+            updated_at=to_utc(int(item["uTime"])),
+        )
+
+    def get_klines(
+        self,
+        symbol: str,
+        timeframe: Timeframe,
+        start: datetime | None = None,
+        end: datetime | None = None,
+        limit: int = 500,
+    ) -> list[Candle]:
+        """Get historical candles from OKX."""
+        # TODO: Implement kline retrieval using self.client
+        raise NotImplementedError
 
     def get_balance(self) -> dict | float:
         """Retrieves the account balance from OKX."""
@@ -53,59 +381,66 @@ class Adapter(MetaExchange):
         # TODO: Implement account info retrieval using self.client
         raise NotImplementedError
 
-    def trade(
+    # POSITION
+    def get_positions(self, symbol: str | None = None) -> list[Position]:
+        """Get the open positions on OKX."""
+        # TODO: Implement position retrieval using self.client
+        raise NotImplementedError
+
+    def modify_position(
         self,
+        symbol: str,
         *,
-        lots: float = 0,
-        stop_loss: float = 0,
-        take_profit: float = 0,
-        trailing_stop: float = 0,
-        positions: int = 0,
-        slippage: int = 0,
-        fee: float = 0,
+        leverage: int | None = None,
+        margin_mode: str | MarginMode | None = None,
     ) -> None:
-        """Execute a trade with specified parameters."""
-        # TODO: Implement actual trading logic using the client
-        pass
-
-    # POSITION
-    def open_position(self, side: str, fee: float) -> bool:
-        """Open a position."""
-        # TODO: Implement position opening logic
-        return False
-
-    def close_position(self, side: str) -> bool:
-        """Close a position."""
-        # TODO: Implement position closing logic
-        return False
-
-    def close_all_positions(self, side: str) -> bool:
-        """Close all positions."""
-        # TODO: Implement closing all positions logic
-        return False
-
-    def modify_position(self) -> bool:
-        """Modify a position."""
-        # TODO: Implement position modification logic
-        return False
-
-    def modify_all_positions(self) -> bool:
-        """Modify all positions."""
-        # TODO: Implement modification of all positions logic
-        return False
+        """Change the leverage and/or margin mode used for a symbol on OKX."""
+        # TODO: Implement leverage and margin mode changes using self.client
+        raise NotImplementedError
 
     # ORDER
-    def open_order(self, side: str) -> bool:
-        """Open an order."""
-        # TODO: Implement order opening logic
-        return False
-
-    def close_order(self, side: str) -> bool:
-        """Close an order."""
-        # TODO: Implement order closing logic
-        return False
-
-    def close_all_orders(self, side: str) -> bool:
-        """Close all orders."""
-        # TODO: Implement closing all orders logic
-        return False
+    def place_order(
+        self,
+        symbol: str,
+        side: str | OrderSide,
+        order_type: str | OrderType,
+        quantity: float,
+        *,
+        price: float | None = None,
+        stop_price: float | None = None,
+        time_in_force: str | TimeInForce = DEFAULT_TIME_IN_FORCE,
+        reduce_only: bool = False,
+        post_only: bool = False,
+        client_order_id: str | None = None,
+    ) -> Order:
+        """Place a new order on OKX."""
+        # TODO: Implement order placement using self.client
+        raise NotImplementedError
+
+    def cancel_order(self, symbol: str, order_id: str) -> Order:
+        """Cancel an open order on OKX."""
+        # TODO: Implement order cancellation using self.client
+        raise NotImplementedError
+
+    def cancel_all_orders(self, symbol: str) -> list[Order]:
+        """Cancel all open orders for a symbol on OKX."""
+        # TODO: Implement cancellation of all orders using self.client
+        raise NotImplementedError
+
+    def amend_order(
+        self,
+        symbol: str,
+        order_id: str,
+        *,
+        quantity: float | None = None,
+        price: float | None = None,
+        stop_price: float | None = None,
+    ) -> Order:
+        """Amend an open order on OKX."""
+        # TODO: Implement order amendment using self.client
+        raise NotImplementedError
+
+    def get_order(self, symbol: str, order_id: str) -> Order:
+        """Get the current state of an order on OKX."""
+        # TODO: Implement order retrieval using self.client
+        raise NotImplementedError
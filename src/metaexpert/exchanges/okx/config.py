@@ -4,7 +4,7 @@
 SPOT_PACKAGE: str = "okx-connector"
 SPOT_PACKAGE_VERSION: str = "1.0.0"
 SPOT_MODULE: str = "okx.spot"
-SPOT_WS_BASE_URL: str = "wss://ws.okx.com:843/ws/v5/public"
+SPOT_WS_BASE_URL: str = "wss://ws.okx.com:8443/ws/v5/public"
 
 # Futures trading
 FUTURES_PACKAGE: str = "okx-connector"
@@ -30,3 +30,41 @@ SPOT_WS_TESTNET_URL: str = "wss://wspap.okx.com:8443/ws/v5/public"
 FUTURES_WS_TESTNET_URL: str = "wss://wspap.okx.com:8443/ws/v5/public"
 SWAP_WS_TESTNET_URL: str = "wss://wspap.okx.com:8443/ws/v5/public"
 OPTION_WS_TESTNET_URL: str = "wss://wspap.okx.com:8443/ws/v5/public"
+
+# Candlestick channels are served by the business endpoint
+BUSINESS_WS_BASE_URL: str = "wss://ws.okx.com:8443/ws/v5/business"
+BUSINESS_WS_TESTNET_URL: str = "wss://wspap.okx.com:8443/ws/v5/business"
+
+# Order book channel: 400 levels, snapshot then incremental updates every 100 ms,
+# with a checksum of the top levels
+BOOK_CHANNEL: str = "books"
+BOOK_CHECKSUM_DEPTH: int = 25
+
+# Order, position and account channels are served by the private endpoint
+PRIVATE_WS_BASE_URL: str = "wss://ws.okx.com:8443/ws/v5/private"
+PRIVATE_WS_TESTNET_URL: str = "wss://wspap.okx.com:8443/ws/v5/private"
+USER_STREAM_CHANNELS: tuple[dict[str, str], ...] = (
+    {"channel": "orders", "instType": "ANY"},
+    {"channel": "positions", "instType": "ANY"},
+    {"channel": "account"},
+)
+
+# Candlestick channels, by timeframe (UTC-aligned from 6h)
+KLINE_CHANNELS: dict[str, str] = {
+    "1m": "candle1m",
+    "3m": "candle3m",
+    "5m": "candle5m",
+    "15m": "candle15m",
+    "30m": "candle30m",
+    "1h": "candle1H",
+    "2h": "candle2H",
+    "4h": "candle4H",
+    "6h": "candle6Hutc",
+    "12h": "candle12Hutc",
+    "1d": "candle1Dutc",
+    "3d": "candle3Dutc",
+    "1w": "candle1Wutc",
+}
+
+# Quote currencies used to split symbols into instrument ids (BTCUSDT -> BTC-USDT)
+QUOTE_CURRENCIES: tuple[str, ...] = ("USDT", "USDC", "USD", "EUR", "BTC", "ETH")
@@ -0,0 +1,7 @@
+"""Paper trading components of the MetaExpert library."""
+
+from .broker import PaperBroker
+
+__all__ = [
+    "PaperBroker",
+]
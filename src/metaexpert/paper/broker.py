@@ -0,0 +1,176 @@
+"""Paper trading broker."""
+
+from datetime import UTC, datetime
+from threading import RLock
+
+from metaexpert.backtest.broker import SimulatedBroker
+from metaexpert.backtest.fill_model import FillModel
+from metaexpert.config import DEFAULT_TIME_IN_FORCE
+from metaexpert.core import Candle, Order, OrderSide, OrderType, TimeInForce, Timeframe
+from metaexpert.data import FundingSource
+from metaexpert.exchanges import MetaExchange
+from metaexpert.logger import MetaLogger as Logger, get_logger
+
+
+class PaperBroker(SimulatedBroker):
+    """Simulated broker that fills orders against live market data.
+
+    Market data and trading rules still come from the exchange, but orders
+    never reach it: they are kept in an in-memory account seeded with the
+    initial capital. Market orders fill at the last traded price as soon as
+    one is known, resting orders fill when a price update crosses them.
+    A limit order the previous price had not reached rested on the book and
+    pays the maker fee, any other fill the taker fee.
+    Slippage and fees are applied to every fill and the same `on_order`,
+    `on_transaction` and `on_position` events as live trading are emitted.
+    Funding and liquidations are simulated as in a backtest, the funding
+    rates coming from the given funding source.
+    """
+
+    def __init__(
+        self,
+        exchange: MetaExchange,
+        initial_capital: float,
+        *,
+        fee: float | None = None,
+        slippage_pct: float = 0.0,
+        leverage: int = 1,
+        currency: str = "USDT",
+        maintenance_margin_rate: float | None = None,
+        funding_source: FundingSource | None = None,
+        fill_model: FillModel | None = None,
+    ) -> None:
+        """Initialize the paper broker.
+
+        Args:
+            exchange (MetaExchange): Exchange providing the market data.
+            initial_capital (float): Starting balance in the settlement currency.
+            fee (float | None): Fee rate (0.001 = 0.1%), the exchange fee by default.
+            slippage_pct (float): Adverse slippage applied to market and stop fills, in percent.
+            leverage (int): Default leverage for new positions.
+            currency (str): Settlement currency of the account.
+            maintenance_margin_rate (float | None): Maintenance margin as a share of the
+                position value (0.005 = 0.5%), None to never liquidate.
+            funding_source (FundingSource | None): Funding rates of the perpetual contracts, if any.
+            fill_model (FillModel | None): Execution model, replacing `fee` and `slippage_pct`.
+        """
+        super().__init__(
+            initial_capital,
+            fee=exchange.fee if fee is None else fee,
+            slippage_pct=slippage_pct,
+            leverage=leverage,
+            position_mode=exchange.position_mode,
+            margin_mode=exchange.margin_mode,
+            currency=currency,
+            instruments=exchange.instruments,
+            contract_type=exchange.contract_type,
+            maintenance_margin_rate=maintenance_margin_rate,
+            funding_source=funding_source,
+            fill_model=fill_model,
+        )
+        self.logger: Logger = get_logger("PaperBroker")
+        self.exchange: MetaExchange = exchange
+        self._lock: RLock = RLock()
+
+    # MARKET DATA
+    def process_price(
+        self, symbol: str, price: float, time: datetime | None = None
+    ) -> None:
+        """Match the open orders of a symbol against a live price update.
+
+        The update is matched as a one-minute candle closing at its time, so
+        the fills and the funding are stamped with it.
+
+        Args:
+            symbol (str): Trading symbol.
+            price (float): Last traded price.
+            time (datetime | None): Time of the update, now by default.
+        """
+        now = time or datetime.now(UTC)
+        with self._lock:
+            super().process_candle(self._get_tick(symbol, price, now))
+            self.now = now
+
+    def process_candle(self, candle: Candle) -> None:
+        """Match the open orders against the latest price of a (live) candle update."""
+        self.process_price(candle.symbol, candle.close)
+
+    # ORDER
+    def place_order(
+        self,
+        symbol: str,
+        side: str | OrderSide,
+        order_type: str | OrderType,
+        quantity: float,
+        *,
+        price: float | None = None,
+        stop_price: float | None = None,
+        time_in_force: str | TimeInForce = DEFAULT_TIME_IN_FORCE,
+        reduce_only: bool = False,
+        post_only: bool = False,
+        client_order_id: str | None = None,
+    ) -> Order:
+        """Accept an order; market orders fill immediately at the last price."""
+        with self._lock:
+            self.now = datetime.now(UTC)
+            order = super().place_order(
+                symbol,
+                side,
+                order_type,
+                quantity,
+                price=price,
+                stop_price=stop_price,
+                time_in_force=time_in_force,
+                reduce_only=reduce_only,
+                post_only=post_only,
+                client_order_id=client_order_id,
+            )
+
+            last_price = self.get_last_price(symbol)
+            if order.type is OrderType.MARKET and last_price is not None:
+                tick = self._get_tick(symbol, last_price, self.now)
+                self._fill(order, self.fill_model.get_price(order, last_price, tick))
+            return order
+
+    def _fill(
+        self,
+        order: Order,
+        price: float,
+        quantity: float | None = None,
+        *,
+        is_maker: bool = False,
+        is_liquidation: bool = False,
+    ) -> None:
+        """Execute (part of) an order, as a maker if it is a limit order that rested.
+
+        The last price is still the one before the update being matched: a
+        limit order it had not reached was resting on the book.
+        """
+        last_price = self.get_last_price(order.symbol)
+        if (
+            order.type is OrderType.LIMIT
+            and order.price is not None
+            and last_price is not None
+        ):
+            sign = 1 if order.side is OrderSide.BUY else -1
+            is_maker = sign * (last_price - order.price) > 0
+        super()._fill(
+            order,
+            price,
+            quantity,
+            is_maker=is_maker,
+            is_liquidation=is_liquidation,
+        )
+
+    @staticmethod
+    def _get_tick(symbol: str, price: float, time: datetime) -> Candle:
+        """Price update as a one-price candle closing at its time, for the fill model."""
+        return Candle(
+            symbol=symbol,
+            timeframe=Timeframe.M1,
+            open_time=time - Timeframe.M1.get_delta(),
+            open=price,
+            high=price,
+            low=price,
+            close=price,
+        )
@@ -0,0 +1,12 @@
+"""Process management components of the MetaExpert library."""
+
+from .monitor import ProcessMonitor
+from .state import ExpertState, StateStore
+from .supervisor import Supervisor
+
+__all__ = [
+    "ExpertState",
+    "ProcessMonitor",
+    "StateStore",
+    "Supervisor",
+]
@@ -0,0 +1,77 @@
+"""Expert side of the process management."""
+
+import os
+import sys
+from datetime import UTC, datetime
+from pathlib import Path
+from typing import Any
+
+from metaexpert.config import ENV_STATE_FILE
+from metaexpert.logger import MetaLogger as Logger, get_logger
+
+from .state import ExpertState, get_process_start_time, get_stop_file
+
+
+class ProcessMonitor:
+    """Record the state and heartbeat of the running expert in its state file.
+
+    The monitor is inactive unless the expert was started by the supervisor,
+    which passes the state file through `METAEXPERT_STATE_FILE`. Every update
+    rewrites all the fields known to the expert, so a concurrent write of the
+    supervisor is repaired by the next heartbeat.
+    """
+
+    def __init__(self, state_file: str | Path | None = None) -> None:
+        """Initialize the process monitor.
+
+        Args:
+            state_file (str | Path | None): State file, read from the environment if omitted.
+        """
+        state_file = state_file or os.getenv(ENV_STATE_FILE)
+        self.state_file: Path | None = Path(state_file) if state_file else None
+        self.logger: Logger = get_logger("ProcessMonitor")
+        self._fields: dict[str, Any] = {
+            "pid": os.getpid(),
+            "process_start": get_process_start_time(os.getpid()),
+        }
+
+    @property
+    def is_active(self) -> bool:
+        """Whether the expert is managed by the supervisor."""
+        return self.state_file is not None
+
+    def update(self, **fields: Any) -> None:
+        """Record expert fields (status, trade mode, symbol...) and a heartbeat."""
+        if self.state_file is None:
+            return
+
+        self._fields |= fields
+        try:
+            if self.state_file.is_file():
+                state = ExpertState.load(self.state_file)
+            else:
+                state = ExpertState(
+                    name=self.state_file.stem,
+                    pid=os.getpid(),
+                    script=os.path.abspath(sys.argv[0]),
+                    started_at=datetime.now(UTC),
+                )
+            for key, value in self._fields.items():
+                setattr(state, key, value)
+            state.heartbeat = datetime.now(UTC)
+            state.save(self.state_file)
+        except (OSError, ValueError) as e:
+            self.logger.warning("Failed to update the state file: %s", e)
+
+    def get_stop_reason(self) -> str | None:
+        """Reason of the stop requested by the supervisor, if any."""
+        if self.state_file is None:
+            return None
+
+        stop_file = get_stop_file(self.state_file)
+        try:
+            reason = stop_file.read_text(encoding="utf-8").strip()
+        except OSError:
+            return None
+        stop_file.unlink(missing_ok=True)
+        return reason or None
@@ -0,0 +1,222 @@
+"""State files of the expert processes."""
+
+import json
+import os
+import subprocess
+import sys
+from dataclasses import asdict, dataclass, fields
+from datetime import UTC, datetime, timedelta
+from pathlib import Path
+from typing import Any
+
+from metaexpert.config import (
+    PROCESS_DIRECTORY,
+    PROCESS_STATUS_DEAD,
+    PROCESS_STATUS_STARTING,
+    PROCESS_STATUS_STOPPED,
+)
+
+
+def is_process_alive(pid: int) -> bool:
+    """Check whether a process with the given id is running."""
+    if pid <= 0:
+        return False
+
+    if sys.platform == "win32":
+        import ctypes
+
+        kernel32 = ctypes.windll.kernel32
+        handle = kernel32.OpenProcess(0x1000, False, pid)  # Query limited information
+        if not handle:
+            return False
+        code = ctypes.c_ulong()
+        kernel32.GetExitCodeProcess(handle, ctypes.byref(code))
+        kernel32.CloseHandle(handle)
+        return code.value == 259  # STILL_ACTIVE
+
+    try:
+        os.kill(pid, 0)
+    except ProcessLookupError:
+        return False
+    except PermissionError:
+        return True
+    return True
+
+
+def get_process_start_time(pid: int) -> str | None:
+    """Start time of a process as an opaque token, `None` if it cannot be read.
+
+    Together with the pid it identifies a process, since the pid of an exited
+    process may be reused by another one.
+    """
+    if pid <= 0:
+        return None
+
+    if sys.platform == "win32":
+        import ctypes
+
+        kernel32 = ctypes.windll.kernel32
+        handle = kernel32.OpenProcess(0x1000, False, pid)  # Query limited information
+        if not handle:
+            return None
+        times = [ctypes.c_ulonglong() for _ in range(4)]  # Creation, exit, kernel, user
+        found = kernel32.GetProcessTimes(handle, *map(ctypes.byref, times))
+        kernel32.CloseHandle(handle)
+        return str(times[0].value) if found else None
+
+    if sys.platform.startswith("linux"):
+        # Field 22 of the stat file, counted after the parenthesized command name
+        try:
+            stat = Path(f"/proc/{pid}/stat").read_text(encoding="utf-8")
+            return stat.rsplit(")", 1)[1].split()[19]
+        except (OSError, IndexError):
+            return None
+
+    try:
+        output = subprocess.run(
+            ["ps", "-o", "lstart=", "-p", str(pid)],
+            capture_output=True,
+            text=True,
+            timeout=5,
+            check=False,
+        ).stdout.strip()
+    except (OSError, subprocess.SubprocessError):
+        return None
+    return output or None
+
+
+@dataclass
+class ExpertState:
+    """State of an expert process, shared between the supervisor and the expert.
+
+    The supervisor creates the state when it starts the process; the expert
+    then fills in its settings and refreshes the heartbeat while it runs.
+    """
+
+    name: str
+    pid: int
+    script: str
+    process_start: str | None = None  # Start time token telling a reused pid apart
+    status: str = PROCESS_STATUS_STARTING
+    started_at: datetime | None = None
+    heartbeat: datetime | None = None
+    trade_mode: str | None = None
+    exchange: str | None = None
+    symbol: str | None = None
+    timeframe: str | None = None
+    log_file: str | None = None
+    stop_reason: str | None = None
+
+    @property
+    def uptime(self) -> timedelta | None:
+        """Time since the process was started, while it runs."""
+        if self.started_at is None or self.status in (
+            PROCESS_STATUS_STOPPED,
+            PROCESS_STATUS_DEAD,
+        ):
+            return None
+        return datetime.now(UTC) - self.started_at
+
+    def is_alive(self) -> bool:
+        """Check whether the process is running and is still the recorded one."""
+        if not is_process_alive(self.pid):
+            return False
+        if self.process_start is None:
+            return True
+        start = get_process_start_time(self.pid)
+        return start is None or start == self.process_start
+
+    def to_dict(self) -> dict[str, Any]:
+        """Serializable representation of the state."""
+        return {
+            key: value.isoformat() if isinstance(value, datetime) else value
+            for key, value in asdict(self).items()
+        }
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> "ExpertState":
+        """Create a state from its serialized representation."""
+        values = {item.name: data.get(item.name) for item in fields(cls)}
+        for key in ("started_at", "heartbeat"):
+            if values[key] is not None:
+                values[key] = datetime.fromisoformat(values[key])
+        return cls(**{key: value for key, value in values.items() if value is not None})
+
+    @classmethod
+    def load(cls, path: str | Path) -> "ExpertState":
+        """Read a state file."""
+        return cls.from_dict(json.loads(Path(path).read_text(encoding="utf-8")))
+
+    def save(self, path: str | Path) -> None:
+        """Write the state file atomically, so that readers never see a partial file."""
+        path = Path(path)
+        path.parent.mkdir(parents=True, exist_ok=True)
+        temporary = path.with_name(f"{path.name}.{os.getpid()}.tmp")
+        temporary.write_text(json.dumps(self.to_dict(), indent=2), encoding="utf-8")
+        os.replace(temporary, path)
+
+
+class StateStore:
+    """Directory of the state, stop-request and log files of the experts.
+
+    Every expert has a `<name>.json` state file, a `<name>.log` file receiving
+    its console output and, while it is being stopped, a `<name>.stop` file
+    holding the deinitialization reason.
+    """
+
+    def __init__(self, directory: str | Path = PROCESS_DIRECTORY) -> None:
+        """Initialize the state store.
+
+        Args:
+            directory (str | Path): Directory of the files.
+        """
+        self.directory: Path = Path(directory)
+
+    def get_state_file(self, name: str) -> Path:
+        """Path of the state file of an expert."""
+        return self.directory / f"{name}.json"
+
+    def get_log_file(self, name: str) -> Path:
+        """Path of the log file of an expert."""
+        return self.directory / f"{name}.log"
+
+    def load(self, name: str) -> ExpertState | None:
+        """Read the state of an expert, `None` if it is unknown.
+
+        An expert whose process has exited without recording its shutdown is
+        reported as dead.
+        """
+        path = self.get_state_file(name)
+        if not path.is_file():
+            return None
+
+        state = ExpertState.load(path)
+        if state.status != PROCESS_STATUS_STOPPED and not state.is_alive():
+            state.status = PROCESS_STATUS_DEAD
+        return state
+
+    def save(self, state: ExpertState) -> None:
+        """Write the state of an expert."""
+        state.save(self.get_state_file(state.name))
+
+    def list(self) -> list[ExpertState]:
+        """States of all the known experts, by name."""
+        if not self.directory.is_dir():
+            return []
+        paths = sorted(self.directory.glob("*.json"))
+        states = (self.load(path.stem) for path in paths)
+        return [state for state in states if state is not None]
+
+    def remove(self, name: str) -> None:
+        """Delete the files of an expert."""
+        for path in (
+            self.get_state_file(name),
+            self.get_log_file(name),
+            get_stop_file(self.get_state_file(name)),
+        ):
+            path.unlink(missing_ok=True)
+
+
+def get_stop_file(state_file: str | Path) -> Path:
+    """Path of the stop-request file next to a state file."""
+    return Path(state_file).with_suffix(".stop")
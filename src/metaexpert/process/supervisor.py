@@ -0,0 +1,181 @@
+"""Supervisor of detached expert processes."""
+
+import os
+import signal
+import subprocess
+import sys
+import time
+from datetime import UTC, datetime
+from pathlib import Path
+from typing import Any
+
+from metaexpert.config import (
+    DEINIT_REASON_USER_STOP,
+    ENV_STATE_FILE,
+    PROCESS_STATUS_DEAD,
+    PROCESS_STATUS_STARTING,
+    PROCESS_STATUS_STOPPED,
+    STOP_TIMEOUT,
+)
+from metaexpert.core import ProcessError, ShutdownError
+from metaexpert.logger import MetaLogger as Logger, get_logger
+
+from .state import ExpertState, StateStore, get_process_start_time, get_stop_file
+
+
+class Supervisor:
+    """Start, stop and monitor experts running as background processes.
+
+    A detached expert runs in its own session, with its console output sent
+    to its log file. The path of its state file is passed through the
+    `METAEXPERT_STATE_FILE` environment variable, so that the expert can
+    record its settings and heartbeat. Stopping an expert writes the reason
+    to its stop-request file and sends `SIGTERM`, on which the expert runs
+    its `on_deinit` handler with that reason and exits. The start time of the
+    process is recorded with its pid, so that a pid reused by another process
+    after the expert exited is never signalled.
+    """
+
+    def __init__(self, store: StateStore | None = None) -> None:
+        """Initialize the supervisor.
+
+        Args:
+            store (StateStore | None): Store of the expert files, the default directory if omitted.
+        """
+        self.store: StateStore = store or StateStore()
+        self.logger: Logger = get_logger("Supervisor")
+        self._processes: dict[str, subprocess.Popen] = {}
+
+    def start(
+        self, script: Path, env: dict[str, str], name: str | None = None
+    ) -> ExpertState:
+        """Start an expert script as a detached process.
+
+        Args:
+            script (Path): Expert script.
+            env (dict[str, str]): Environment of the process.
+            name (str | None): Name of the expert, the project directory name by default.
+
+        Returns:
+            ExpertState: Initial state of the expert.
+        """
+        name = name or script.parent.name
+        state = self.store.load(name)
+        if state is not None and state.status not in (
+            PROCESS_STATUS_STOPPED,
+            PROCESS_STATUS_DEAD,
+        ):
+            raise ProcessError(f"Expert '{name}' is already running (pid {state.pid})")
+
+        self.store.directory.mkdir(parents=True, exist_ok=True)
+        state_file = self.store.get_state_file(name)
+        log_file = self.store.get_log_file(name)
+        get_stop_file(state_file).unlink(missing_ok=True)
+
+        # Detach the expert from the terminal, so that it survives the CLI
+        options: dict[str, Any] = (
+            {
+                "creationflags": subprocess.CREATE_NEW_PROCESS_GROUP
+                | subprocess.DETACHED_PROCESS
+            }
+            if sys.platform == "win32"
+            else {"start_new_session": True}
+        )
+        with log_file.open("ab") as log:
+            process = subprocess.Popen(
+                [sys.executable, str(script)],
+                cwd=script.parent,
+                env=env | {ENV_STATE_FILE: str(state_file)},
+                stdin=subprocess.DEVNULL,
+                stdout=log,
+                stderr=subprocess.STDOUT,
+                **options,
+            )
+
+        state = ExpertState(
+            name=name,
+            pid=process.pid,
+            script=str(script),
+            process_start=get_process_start_time(process.pid),
+            status=PROCESS_STATUS_STARTING,
+            started_at=datetime.now(UTC),
+            log_file=str(log_file),
+        )
+        self.store.save(state)
+        self._processes[name] = process
+        self.logger.info("Started expert %s (pid %d)", name, process.pid)
+        return state
+
+    def stop(
+        self,
+        name: str,
+        reason: str = DEINIT_REASON_USER_STOP,
+        timeout: float = STOP_TIMEOUT,
+        force: bool = False,
+    ) -> ExpertState:
+        """Stop an expert gracefully.
+
+        Args:
+            name (str): Name of the expert.
+            reason (str): Deinitialization reason passed to `on_deinit`.
+            timeout (float): Seconds to wait for the expert to exit.
+            force (bool): Kill the process if it is still running after the timeout.
+
+        Returns:
+            ExpertState: Final state of the expert.
+        """
+        state = self.get_state(name)
+        if not self._is_running(state):
+            raise ProcessError(f"Expert '{name}' is not running")
+
+        stop_file = get_stop_file(self.store.get_state_file(name))
+        stop_file.write_text(reason, encoding="utf-8")
+        os.kill(state.pid, signal.SIGTERM)
+        self.logger.info("Stopping expert %s (pid %d): %s", name, state.pid, reason)
+
+        if not self._wait(state, timeout):
+            if not force:
+                raise ShutdownError(
+                    name, f"Expert '{name}' did not stop within {timeout:g} seconds"
+                )
+            os.kill(state.pid, getattr(signal, "SIGKILL", signal.SIGTERM))
+            self.logger.warning("Killed expert %s (pid %d)", name, state.pid)
+            self._wait(state, timeout)
+
+        stop_file.unlink(missing_ok=True)
+        return self.get_state(name)
+
+    def get_state(self, name: str) -> ExpertState:
+        """Current state of an expert."""
+        state = self.store.load(name)
+        if state is None:
+            raise ProcessError(f"Unknown expert '{name}'")
+        return state
+
+    def list(self) -> list[ExpertState]:
+        """Current states of all the known experts."""
+        return self.store.list()
+
+    def remove(self, name: str) -> None:
+        """Forget an expert that is no longer running."""
+        state = self.get_state(name)
+        if self._is_running(state):
+            raise ProcessError(f"Expert '{name}' is still running")
+        self.store.remove(name)
+        self._processes.pop(name, None)
+
+    def _is_running(self, state: ExpertState) -> bool:
+        """Check whether an expert process is running, reaping it if it is a child."""
+        process = self._processes.get(state.name)
+        if process is not None and process.pid == state.pid:
+            return process.poll() is None
+        return state.is_alive()
+
+    def _wait(self, state: ExpertState, timeout: float) -> bool:
+        """Wait for an expert process to exit, return whether it did."""
+        deadline = time.monotonic() + timeout
+        while self._is_running(state):
+            if time.monotonic() >= deadline:
+                return False
+            time.sleep(0.1)
+        return True
@@ -0,0 +1,29 @@
+"""Risk management components of the MetaExpert library."""
+
+from .filters import (
+    EntryContext,
+    EntryFilter,
+    EntryFilters,
+    allowed_days_filter,
+    max_spread_filter,
+    min_volume_filter,
+    trade_hours_filter,
+)
+from .manager import RiskManager, RiskState
+from .protection import Protection, ProtectionManager
+from .sizing import PositionSizer
+
+__all__ = [
+    "EntryContext",
+    "EntryFilter",
+    "EntryFilters",
+    "PositionSizer",
+    "Protection",
+    "ProtectionManager",
+    "RiskManager",
+    "RiskState",
+    "allowed_days_filter",
+    "max_spread_filter",
+    "min_volume_filter",
+    "trade_hours_filter",
+]
@@ -0,0 +1,158 @@
+"""Pre-trade entry filters."""
+
+from collections.abc import Callable
+from datetime import datetime
+from functools import cached_property
+
+from metaexpert.core import OrderBook, OrderSide
+from metaexpert.logger import MetaLogger as Logger, get_logger
+
+
+class EntryContext:
+    """New entry checked by the filters, with the market data it may need.
+
+    The order book and the 24h volume are loaded on first access, so that a
+    filter pipeline that does not use them does not fetch them.
+    """
+
+    def __init__(
+        self,
+        symbol: str,
+        side: OrderSide,
+        quantity: float,
+        time: datetime,
+        *,
+        order_book: Callable[[], OrderBook | None] | None = None,
+        volume_24h: Callable[[], float | None] | None = None,
+    ) -> None:
+        """Initialize the entry context.
+
+        Args:
+            symbol (str): Trading symbol.
+            side (OrderSide): Side of the entry order.
+            quantity (float): Quantity of the entry order.
+            time (datetime): Current time, the simulated one in backtests.
+            order_book (Callable | None): Loader of the last order book of the symbol.
+            volume_24h (Callable | None): Loader of the 24h quote volume of the symbol.
+        """
+        self.symbol: str = symbol
+        self.side: OrderSide = side
+        self.quantity: float = quantity
+        self.time: datetime = time
+        self._load_order_book = order_book
+        self._load_volume_24h = volume_24h
+
+    @cached_property
+    def order_book(self) -> OrderBook | None:
+        """Last order book of the symbol, `None` if unknown."""
+        return self._load_order_book() if self._load_order_book else None
+
+    @cached_property
+    def volume_24h(self) -> float | None:
+        """Traded volume of the last 24 hours in quote currency, `None` if unknown."""
+        return self._load_volume_24h() if self._load_volume_24h else None
+
+
+# Returns the reason why an entry is blocked, `None` to allow it
+EntryFilter = Callable[[EntryContext], str | None]
+
+
+class EntryFilters:
+    """Pipeline of named filters run before every new entry.
+
+    The filters run in registration order and the first one returning a
+    reason blocks the entry. Registering a filter under an existing name
+    replaces it.
+    """
+
+    def __init__(self) -> None:
+        self._filters: dict[str, EntryFilter] = {}
+        self.logger: Logger = get_logger("EntryFilters")
+
+    def __contains__(self, name: str) -> bool:
+        return name in self._filters
+
+    def __len__(self) -> int:
+        return len(self._filters)
+
+    def add(self, func: EntryFilter, name: str | None = None) -> EntryFilter:
+        """Register a filter, under its function name by default."""
+        self._filters[name or func.__name__] = func
+        return func
+
+    def remove(self, name: str) -> None:
+        """Unregister a filter, if registered."""
+        self._filters.pop(name, None)
+
+    def check(self, context: EntryContext) -> str | None:
+        """Run the filters on an entry.
+
+        Returns:
+            str | None: Reason of the first filter blocking the entry, `None` if allowed.
+        """
+        for name, func in self._filters.items():
+            reason = func(context)
+            if reason is not None:
+                self.logger.warning(
+                    "Entry blocked by %s: %s %s %s, %s",
+                    name,
+                    context.side.get_name(),
+                    context.quantity,
+                    context.symbol,
+                    reason,
+                )
+                return reason
+        return None
+
+
+def trade_hours_filter(hours: set[int]) -> EntryFilter:
+    """Allow entries during the given UTC hours only (0-23)."""
+
+    def check(context: EntryContext) -> str | None:
+        if context.time.hour not in hours:
+            return f"hour {context.time.hour} UTC is outside the trade hours"
+        return None
+
+    return check
+
+
+def allowed_days_filter(days: set[int]) -> EntryFilter:
+    """Allow entries on the given UTC weekdays only (1=Mon, 7=Sun)."""
+
+    def check(context: EntryContext) -> str | None:
+        if context.time.isoweekday() not in days:
+            return f"day {context.time.isoweekday()} is not an allowed day"
+        return None
+
+    return check
+
+
+def max_spread_filter(max_spread_pct: float) -> EntryFilter:
+    """Block entries while the spread of the order book exceeds a percentage.
+
+    Entries pass when no order book is known (e.g. in backtests).
+    """
+
+    def check(context: EntryContext) -> str | None:
+        book = context.order_book
+        spread_pct = book.spread_pct if book is not None else None
+        if spread_pct is not None and spread_pct > max_spread_pct:
+            return f"spread {spread_pct:.4f}% exceeds {max_spread_pct}%"
+        return None
+
+    return check
+
+
+def min_volume_filter(min_volume: float) -> EntryFilter:
+    """Block entries while the 24h quote volume is below a minimum.
+
+    Entries pass when the volume is unknown.
+    """
+
+    def check(context: EntryContext) -> str | None:
+        volume = context.volume_24h
+        if volume is not None and volume < min_volume:
+            return f"24h volume {volume:,.0f} is below {min_volume:,.0f}"
+        return None
+
+    return check
@@ -0,0 +1,183 @@
+"""Account-level risk limits."""
+
+import json
+import os
+from dataclasses import asdict, dataclass
+from datetime import date, datetime
+from pathlib import Path
+from threading import Lock
+from typing import Any
+
+from metaexpert.core import RiskLimitError
+from metaexpert.logger import MetaLogger as Logger, get_logger
+
+
+@dataclass
+class RiskState:
+    """Equity marks of the risk manager, saved across restarts.
+
+    The peak equity and a drawdown breach are kept until the manager is
+    reset; the equity at the start of the UTC day and a daily loss breach
+    only for the day.
+    """
+
+    day: date
+    day_start_equity: float
+    peak_equity: float
+    drawdown_breach: str | None = None
+    daily_breach: str | None = None
+
+    def to_dict(self) -> dict[str, Any]:
+        """Serializable representation of the state."""
+        return asdict(self) | {"day": self.day.isoformat()}
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> "RiskState":
+        """Create a state from its serialized representation."""
+        return cls(**(data | {"day": date.fromisoformat(data["day"])}))
+
+    @classmethod
+    def load(cls, path: str | Path) -> "RiskState":
+        """Read a state file."""
+        return cls.from_dict(json.loads(Path(path).read_text(encoding="utf-8")))
+
+    def save(self, path: str | Path) -> None:
+        """Write the state file atomically, so that a crash never leaves a partial file."""
+        path = Path(path)
+        path.parent.mkdir(parents=True, exist_ok=True)
+        temporary = path.with_name(f"{path.name}.{os.getpid()}.tmp")
+        temporary.write_text(json.dumps(self.to_dict(), indent=2), encoding="utf-8")
+        os.replace(temporary, path)
+
+
+class RiskManager:
+    """Enforce the maximum drawdown and the daily loss limit of the account.
+
+    The equity, realized plus unrealized PnL in the settlement currency, is
+    reported with `update`. The drawdown is measured from the peak equity
+    and the daily loss from the equity at the start of the UTC day. Once a
+    limit is breached, `breach` holds the reason and new entries must be
+    blocked: until the next day for the daily loss, until `reset` for the
+    drawdown. With a state file, the marks survive a restart.
+    """
+
+    def __init__(
+        self,
+        max_drawdown_pct: float = 0.0,
+        daily_loss_limit: float = 0.0,
+        *,
+        state_file: str | Path | None = None,
+    ) -> None:
+        """Initialize the risk manager.
+
+        Args:
+            max_drawdown_pct (float): Max drawdown from the peak equity (0.2 = 20%), 0 to disable.
+            daily_loss_limit (float): Max loss of a UTC day in settlement currency, 0 to disable.
+            state_file (str | Path | None): File keeping the state across restarts.
+        """
+        self.max_drawdown_pct: float = max_drawdown_pct
+        self.daily_loss_limit: float = daily_loss_limit
+        self.state_file: Path | None = Path(state_file) if state_file else None
+        self.state: RiskState | None = None
+        self.logger: Logger = get_logger("RiskManager")
+        self._lock: Lock = Lock()
+
+    @property
+    def breach(self) -> str | None:
+        """Reason of the breached limit blocking the entries, if any."""
+        if self.state is None:
+            return None
+        return self.state.drawdown_breach or self.state.daily_breach
+
+    def update(self, equity: float, time: datetime) -> RiskLimitError | None:
+        """Record the equity and check the limits.
+
+        Args:
+            equity (float): Current equity in settlement currency.
+            time (datetime): Current time, the simulated one in backtests.
+
+        Returns:
+            RiskLimitError | None: The limit breached by this update, None otherwise.
+        """
+        with self._lock:
+            changed = self.state is None
+            state = self._get_state(equity, time.date())
+            if time.date() > state.day:
+                state.day = time.date()
+                state.day_start_equity = equity
+                state.daily_breach = None
+                changed = True
+            if equity > state.peak_equity:
+                state.peak_equity = equity
+                changed = True
+
+            breach = None
+            drawdown = self._get_drawdown(state, equity)
+            if state.drawdown_breach is None and drawdown is not None:
+                state.drawdown_breach = (
+                    f"drawdown of {drawdown:.2%} reached the "
+                    f"{self.max_drawdown_pct:.2%} limit"
+                )
+                breach = RiskLimitError("max_drawdown_pct", state.drawdown_breach)
+            loss = state.day_start_equity - equity
+            if (
+                state.daily_breach is None
+                and self.daily_loss_limit > 0
+                and loss >= self.daily_loss_limit
+            ):
+                state.daily_breach = (
+                    f"daily loss of {loss:.2f} reached the "
+                    f"{self.daily_loss_limit:.2f} limit"
+                )
+                breach = RiskLimitError("daily_loss_limit", state.daily_breach)
+
+            if breach is not None:
+                self.logger.error("Risk limit breached: %s", breach)
+            if changed or breach is not None:
+                self._save(state)
+            return breach
+
+    def reset(self) -> None:
+        """Clear the breaches and measure the drawdown from the next equity."""
+        with self._lock:
+            if self.state is None:
+                return
+            self.state.drawdown_breach = None
+            self.state.daily_breach = None
+            self.state.peak_equity = 0.0
+            self._save(self.state)
+
+    def _get_drawdown(self, state: RiskState, equity: float) -> float | None:
+        """Drawdown from the peak equity when it breaches the maximum, None otherwise."""
+        if self.max_drawdown_pct <= 0 or state.peak_equity <= 0:
+            return None
+        drawdown = (state.peak_equity - equity) / state.peak_equity
+        return drawdown if drawdown >= self.max_drawdown_pct else None
+
+    def _get_state(self, equity: float, day: date) -> RiskState:
+        """State of the manager, restored from the state file on first use."""
+        if self.state is not None:
+            return self.state
+
+        if self.state_file is not None and self.state_file.is_file():
+            try:
+                self.state = RiskState.load(self.state_file)
+                self.logger.info(
+                    "Risk state restored: peak equity %.2f, day start equity %.2f",
+                    self.state.peak_equity,
+                    self.state.day_start_equity,
+                )
+            except (OSError, ValueError, TypeError, KeyError) as e:
+                self.logger.warning("Failed to read the risk state: %s", e)
+        if self.state is None:
+            self.state = RiskState(day=day, day_start_equity=equity, peak_equity=equity)
+        return self.state
+
+    def _save(self, state: RiskState) -> None:
+        """Write the state file, if any."""
+        if self.state_file is None:
+            return
+        try:
+            state.save(self.state_file)
+        except OSError as e:
+            self.logger.warning("Failed to save the risk state: %s", e)
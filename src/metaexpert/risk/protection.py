@@ -0,0 +1,375 @@
+"""Protective orders of the open positions."""
+
+from dataclasses import dataclass
+from threading import RLock
+
+from metaexpert.core import (
+    Broker,
+    MetaExpertError,
+    Order,
+    OrderType,
+    Position,
+    PositionSide,
+)
+from metaexpert.logger import MetaLogger as Logger, get_logger
+
+
+@dataclass
+class Protection:
+    """Stop-loss and take-profit levels of an open position."""
+
+    symbol: str
+    side: PositionSide
+    size: float
+    entry_price: float
+    stop_price: float | None = None
+    take_profit_price: float | None = None
+    best_price: float = 0.0  # Most favourable price since the entry
+    is_trailing: bool = False
+    is_breakeven: bool = False
+    stop_order_id: str | None = None
+    stop_order_price: float | None = None  # Stop price of the native stop order
+    take_profit_order_id: str | None = None
+    is_closing: bool = False
+
+    def get_profit_pct(self, price: float) -> float:
+        """Profit of the position at a price, in percent of the entry price."""
+        change = (price - self.entry_price) / self.entry_price
+        return change * 100 * self.side.get_sign()
+
+    def is_better(self, stop_price: float) -> bool:
+        """Whether a stop price locks more profit than the current stop."""
+        if self.stop_price is None:
+            return True
+        if self.side is PositionSide.LONG:
+            return stop_price > self.stop_price
+        return stop_price < self.stop_price
+
+    def is_hit(
+        self, price: float, *, stop: bool = True, take_profit: bool = True
+    ) -> bool:
+        """Whether a price reaches the stop-loss or the take-profit.
+
+        Args:
+            price (float): Price to check.
+            stop (bool): Whether to check the stop-loss.
+            take_profit (bool): Whether to check the take-profit.
+        """
+        sign = self.side.get_sign()
+        if (
+            stop
+            and self.stop_price is not None
+            and (price - self.stop_price) * sign <= 0
+        ):
+            return True
+        return (
+            take_profit
+            and self.take_profit_price is not None
+            and (price - self.take_profit_price) * sign >= 0
+        )
+
+
+class ProtectionManager:
+    """Attach a stop-loss and a take-profit to every open position.
+
+    The levels are set in percent of the entry price. Once the profit reaches
+    `breakeven_pct` the stop moves to the entry price, and once it reaches
+    `trailing_activation_pct` the stop trails the best price at
+    `trailing_stop_pct`; the stop never moves back.
+
+    Brokers supporting protective orders (the simulated brokers, exchanges
+    with reduce-only stops) receive native stop and take-profit orders,
+    amended as the stop moves and cancelled with the position. Otherwise the
+    levels are watched locally and the position is closed at market when a
+    price update reaches one; so are the levels whose native order could not
+    be placed or amended, retried on every update. The positions are read
+    from the position book of the broker on every `update`.
+    """
+
+    def __init__(
+        self,
+        broker: Broker,
+        *,
+        stop_loss_pct: float = 0.0,
+        take_profit_pct: float = 0.0,
+        trailing_stop_pct: float = 0.0,
+        trailing_activation_pct: float = 0.0,
+        breakeven_pct: float = 0.0,
+    ) -> None:
+        """Initialize the protection manager.
+
+        Args:
+            broker (Broker): Broker holding the positions.
+            stop_loss_pct (float): Stop-loss distance from the entry (%), 0 to disable.
+            take_profit_pct (float): Take-profit distance from the entry (%), 0 to disable.
+            trailing_stop_pct (float): Trailing stop distance from the best price (%), 0 to disable.
+            trailing_activation_pct (float): Profit activating the trailing stop (%).
+            breakeven_pct (float): Profit moving the stop to the entry price (%), 0 to disable.
+        """
+        self.broker: Broker = broker
+        self.stop_loss_pct: float = stop_loss_pct
+        self.take_profit_pct: float = take_profit_pct
+        self.trailing_stop_pct: float = trailing_stop_pct
+        self.trailing_activation_pct: float = trailing_activation_pct
+        self.breakeven_pct: float = breakeven_pct
+        self.is_native: bool = broker.supports_protective_orders
+        self.protections: dict[tuple[str, PositionSide], Protection] = {}
+        self.logger: Logger = get_logger("ProtectionManager")
+        self._lock: RLock = RLock()
+
+    @property
+    def is_enabled(self) -> bool:
+        """Whether any protection is configured."""
+        return any(
+            value > 0
+            for value in (
+                self.stop_loss_pct,
+                self.take_profit_pct,
+                self.trailing_stop_pct,
+                self.breakeven_pct,
+            )
+        )
+
+    def set_levels(
+        self,
+        stop_loss_pct: float = 0.0,
+        take_profit_pct: float = 0.0,
+        trailing_stop_pct: float = 0.0,
+    ) -> None:
+        """Change the distances of the positions protected from now on, 0 keeping one.
+
+        Args:
+            stop_loss_pct (float): Stop-loss distance from the entry (%).
+            take_profit_pct (float): Take-profit distance from the entry (%).
+            trailing_stop_pct (float): Trailing stop distance from the best price (%).
+        """
+        with self._lock:
+            if stop_loss_pct > 0:
+                self.stop_loss_pct = stop_loss_pct
+            if take_profit_pct > 0:
+                self.take_profit_pct = take_profit_pct
+            if trailing_stop_pct > 0:
+                self.trailing_stop_pct = trailing_stop_pct
+
+    def update(self, symbol: str, price: float) -> None:
+        """Protect the positions of a symbol and move their stops at a new price.
+
+        Args:
+            symbol (str): Trading symbol.
+            price (float): Last price of the symbol.
+        """
+        if not self.is_enabled:
+            return
+
+        with self._lock:
+            positions = {
+                position.side: position
+                for position in self.broker.position_book.get_positions(symbol)
+            }
+            for key in [key for key in self.protections if key[0] == symbol]:
+                if key[1] not in positions:
+                    self._release(self.protections.pop(key))
+
+            for side, position in positions.items():
+                protection = self.protections.get((symbol, side))
+                if protection is None:
+                    protection = self._attach(position)
+                elif abs(protection.size - position.size) > 1e-12:
+                    self._resize(protection, position)
+                self._trail(protection, price)
+                if self.is_native:
+                    self._sync_orders(protection)
+
+                if self._is_hit(protection, price):
+                    self._close(protection, price)
+
+    def _is_hit(self, protection: Protection, price: float) -> bool:
+        """Whether a price reaches a level that no native order protects."""
+        if not self.is_native:
+            return protection.is_hit(price)
+        return protection.is_hit(
+            price,
+            stop=protection.stop_order_price != protection.stop_price,
+            take_profit=protection.take_profit_order_id is None,
+        )
+
+    def _attach(self, position: Position) -> Protection:
+        """Protect a new position."""
+        protection = Protection(
+            symbol=position.symbol,
+            side=position.side,
+            size=position.size,
+            entry_price=position.entry_price,
+            best_price=position.entry_price,
+        )
+        self._set_levels(protection)
+        self.protections[(position.symbol, position.side)] = protection
+        self.logger.info(
+            "Protecting %s %s %s @ %s: stop %s, take profit %s",
+            position.side.get_name(),
+            position.size,
+            position.symbol,
+            position.entry_price,
+            protection.stop_price,
+            protection.take_profit_price,
+        )
+        return protection
+
+    def _resize(self, protection: Protection, position: Position) -> None:
+        """Follow a position that was increased or partially closed."""
+        protection.size = position.size
+        if not (protection.is_trailing or protection.is_breakeven):
+            protection.entry_price = position.entry_price
+            self._set_levels(protection)
+        if self.is_native:
+            self._cancel_orders(protection)
+
+    def _set_levels(self, protection: Protection) -> None:
+        """Stop-loss and take-profit prices at their distances from the entry."""
+        sign = protection.side.get_sign()
+        entry = protection.entry_price
+        if self.stop_loss_pct > 0:
+            protection.stop_price = entry * (1 - sign * self.stop_loss_pct / 100)
+        if self.take_profit_pct > 0:
+            protection.take_profit_price = entry * (
+                1 + sign * self.take_profit_pct / 100
+            )
+
+    def _trail(self, protection: Protection, price: float) -> None:
+        """Move the stop to the entry price or behind the best price once profitable."""
+        if (price - protection.best_price) * protection.side.get_sign() > 0:
+            protection.best_price = price
+
+        profit_pct = protection.get_profit_pct(price)
+        stop_price = None
+        if (
+            self.breakeven_pct > 0
+            and not protection.is_breakeven
+            and profit_pct >= self.breakeven_pct
+        ):
+            protection.is_breakeven = True
+            stop_price = protection.entry_price
+        if self.trailing_stop_pct > 0 and profit_pct >= self.trailing_activation_pct:
+            protection.is_trailing = True
+        if protection.is_trailing:
+            trailing_price = protection.best_price * (
+                1 - protection.side.get_sign() * self.trailing_stop_pct / 100
+            )
+            if stop_price is None or (
+                (trailing_price - stop_price) * protection.side.get_sign() > 0
+            ):
+                stop_price = trailing_price
+
+        if stop_price is not None and protection.is_better(stop_price):
+            self.logger.info(
+                "Moving the stop of %s %s from %s to %s",
+                protection.side.get_name(),
+                protection.symbol,
+                protection.stop_price,
+                stop_price,
+            )
+            protection.stop_price = stop_price
+
+    def _sync_orders(self, protection: Protection) -> None:
+        """Place the missing native orders of a position and move its stop order."""
+        if protection.is_closing:
+            return
+        if (
+            protection.stop_price is not None
+            and protection.stop_order_price != protection.stop_price
+        ):
+            self._move_stop(protection)
+        if (
+            protection.take_profit_price is not None
+            and protection.take_profit_order_id is None
+        ):
+            order = self._place(
+                protection, OrderType.TAKE_PROFIT, protection.take_profit_price
+            )
+            protection.take_profit_order_id = order.id if order is not None else None
+
+    def _place(
+        self, protection: Protection, order_type: OrderType, stop_price: float
+    ) -> Order | None:
+        """Place one reduce-only protective order, None if the broker rejected it."""
+        try:
+            return self.broker.place_order(
+                protection.symbol,
+                protection.side.get_close_side(),
+                order_type,
+                protection.size,
+                stop_price=stop_price,
+                reduce_only=True,
+            )
+        except (MetaExpertError, OSError, RuntimeError) as e:
+            self.logger.error(
+                "Failed to place the %s order of %s: %s",
+                order_type.get_name(),
+                protection.symbol,
+                e,
+            )
+            return None
+
+    def _move_stop(self, protection: Protection) -> None:
+        """Amend the native stop order to the new stop price, or place it."""
+        if protection.stop_price is None:
+            return
+        if protection.stop_order_id is None:
+            order = self._place(protection, OrderType.STOP, protection.stop_price)
+            if order is not None:
+                protection.stop_order_id = order.id
+                protection.stop_order_price = protection.stop_price
+            return
+        try:
+            order = self.broker.amend_order(
+                protection.symbol,
+                protection.stop_order_id,
+                stop_price=protection.stop_price,
+            )
+            protection.stop_order_id = order.id
+            protection.stop_order_price = protection.stop_price
+        except (MetaExpertError, OSError, RuntimeError) as e:
+            self.logger.error("Failed to move the stop of %s: %s", protection.symbol, e)
+
+    def _cancel_orders(self, protection: Protection) -> None:
+        """Cancel the native orders of a protection that are still open."""
+        for order_id in (protection.stop_order_id, protection.take_profit_order_id):
+            if order_id is None:
+                continue
+            try:
+                if self.broker.get_order(protection.symbol, order_id).is_open:
+                    self.broker.cancel_order(protection.symbol, order_id)
+            except (MetaExpertError, OSError, RuntimeError) as e:
+                self.logger.warning(
+                    "Failed to cancel the protective order %s: %s", order_id, e
+                )
+        protection.stop_order_id = None
+        protection.stop_order_price = None
+        protection.take_profit_order_id = None
+
+    def _release(self, protection: Protection) -> None:
+        """Forget a closed position, cancelling its remaining native order."""
+        self.logger.debug(
+            "Position %s %s closed", protection.side.get_name(), protection.symbol
+        )
+        if self.is_native:
+            self._cancel_orders(protection)
+
+    def _close(self, protection: Protection, price: float) -> None:
+        """Close a position whose locally watched level was reached."""
+        if protection.is_closing:
+            return
+        self.logger.info(
+            "Closing %s %s at %s: stop %s, take profit %s",
+            protection.side.get_name(),
+            protection.symbol,
+            price,
+            protection.stop_price,
+            protection.take_profit_price,
+        )
+        protection.is_closing = True
+        try:
+            self.broker.close_position(protection.symbol, protection.side)
+        except (MetaExpertError, OSError, RuntimeError) as e:
+            protection.is_closing = False
+            self.logger.error("Failed to close %s: %s", protection.symbol, e)
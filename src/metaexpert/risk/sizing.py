@@ -0,0 +1,102 @@
+"""Position sizing."""
+
+from dataclasses import dataclass
+
+from metaexpert.core import ContractType, SizeType
+from metaexpert.utils.rounding import round_down
+
+
+@dataclass(frozen=True)
+class PositionSizer:
+    """Quantity of the new entries, computed from the sizing parameters of the expert.
+
+    - FIXED_BASE: `size_value` in base currency.
+    - FIXED_QUOTE: `size_value` in quote currency, at the entry price.
+    - PERCENT_EQUITY: `size_value` percent of the equity as margin (1.0 = 1%), times the leverage.
+    - RISK_BASED: `size_value` percent of the equity lost if the stop is hit,
+      the stop being `stop_loss_pct` percent away from the entry by default.
+
+    The notional is sized in quote currency, the equity of inverse contracts
+    (held in base currency) being valued at the entry price. It is capped by
+    the equity times the leverage and by `max_position_size_quote`, then
+    converted into contracts of `contract_size` base currency (linear) or
+    quote currency (inverse) and rounded down to the lot step. A quantity
+    below the minimum quantity or notional is zero.
+    """
+
+    size_type: SizeType
+    size_value: float
+    max_position_size_quote: float = 0.0
+    stop_loss_pct: float = 0.0
+    leverage: int = 1
+
+    def get_quantity(
+        self,
+        equity: float,
+        price: float,
+        *,
+        stop_price: float | None = None,
+        lot_step: float = 0.0,
+        min_quantity: float = 0.0,
+        min_notional: float = 0.0,
+        contract_size: float = 1.0,
+        contract_type: ContractType = ContractType.LINEAR,
+    ) -> float:
+        """Compute the quantity of an entry.
+
+        Args:
+            equity (float): Account equity in the settlement currency (base currency for inverse contracts).
+            price (float): Expected entry price.
+            stop_price (float | None): Stop-loss price, `stop_loss_pct` away from the entry by default.
+            lot_step (float): Quantity increment of the instrument, 0 to skip the rounding.
+            min_quantity (float): Minimum quantity of an order.
+            min_notional (float): Minimum notional of an order in quote currency.
+            contract_size (float): Base (linear) or quote (inverse) currency of a contract.
+            contract_type (ContractType): Linear (quote-margined) or inverse (coin-margined) contracts.
+
+        Returns:
+            float: Quantity in base currency (contracts for futures), 0 if too small.
+        """
+        if price <= 0:
+            raise ValueError(f"Entry price must be positive, got {price}")
+
+        is_inverse = contract_type is ContractType.INVERSE
+        equity = equity * price if is_inverse else equity
+        match self.size_type:
+            case SizeType.FIXED_BASE:
+                notional = self.size_value * price
+            case SizeType.FIXED_QUOTE:
+                notional = self.size_value
+            case SizeType.PERCENT_EQUITY:
+                notional = equity * self.size_value / 100 * self.leverage
+            case SizeType.RISK_BASED:
+                risk = equity * self.size_value / 100
+                notional = risk / self._get_stop_distance(price, stop_price) * price
+
+        # Never more than the buying power, nor the maximum position size
+        max_notional = max(equity, 0.0) * max(self.leverage, 1)
+        if self.max_position_size_quote > 0:
+            max_notional = min(max_notional, self.max_position_size_quote)
+
+        # Quote currency value of one contract
+        contract_value = contract_size if is_inverse else contract_size * price
+        quantity = min(notional, max_notional) / contract_value
+        if lot_step > 0:
+            quantity = round_down(quantity, lot_step)
+        if (
+            quantity <= 0
+            or quantity < min_quantity
+            or quantity * contract_value < min_notional
+        ):
+            return 0.0
+        return quantity
+
+    def _get_stop_distance(self, price: float, stop_price: float | None) -> float:
+        """Distance between the entry and the stop-loss prices."""
+        if stop_price is not None:
+            distance = abs(price - stop_price)
+        else:
+            distance = price * self.stop_loss_pct / 100
+        if distance <= 0:
+            raise ValueError("Risk-based sizing requires a stop-loss distance.")
+        return distance
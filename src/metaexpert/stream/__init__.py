@@ -0,0 +1,13 @@
+"""Market and user data stream components of the MetaExpert library."""
+
+from .book import BookStream
+from .dispatcher import StreamDispatcher
+from .market import MarketStream
+from .user import UserStream
+
+__all__ = [
+    "BookStream",
+    "MarketStream",
+    "StreamDispatcher",
+    "UserStream",
+]
@@ -0,0 +1,119 @@
+"""Order book stream."""
+
+import asyncio
+from collections.abc import Callable
+
+from metaexpert.config import ORDER_BOOK_DEPTH
+from metaexpert.core import (
+    EventType,
+    LocalOrderBook,
+    MetaExpertError,
+    OrderBook,
+    OrderBookOutOfSyncError,
+    OrderBookUpdate,
+)
+from metaexpert.exchanges import MetaExchange
+from metaexpert.websocket import WebSocketClient
+
+# Receives the top levels of a book after every update
+BookHandler = Callable[[OrderBook], None]
+
+
+class BookStream(WebSocketClient):
+    """L2 depth stream of an exchange, kept as local order books.
+
+    Every symbol has a local book, initialized by a snapshot and updated by
+    the following diffs. The snapshot comes either from the stream, after
+    the subscription, or from the REST API when the first diff arrives (e.g.
+    Binance). A diff that does not follow the book, or a checksum mismatch,
+    resets the book: it is resubscribed to, or a new snapshot is fetched.
+    The handler receives the top `depth` levels after every applied update.
+    """
+
+    def __init__(
+        self,
+        url: str,
+        exchange: MetaExchange,
+        handler: BookHandler,
+        *,
+        subscriptions: list[dict] | None = None,
+        depth: int = ORDER_BOOK_DEPTH,
+        name: str = "book",
+    ) -> None:
+        """Initialize the order book stream.
+
+        Args:
+            url (str): WebSocket URL.
+            exchange (MetaExchange): Exchange adapter parsing the depth messages.
+            handler (BookHandler): Receiver of the order books.
+            subscriptions (list[dict] | None): Messages sent once connected.
+            depth (int): Number of levels per side passed to the handler.
+            name (str): Name of the stream in the logs.
+        """
+        super().__init__(url, name=name, ping_message=exchange.get_ping_message())
+        self.exchange: MetaExchange = exchange
+        self.handler: BookHandler = handler
+        self.depth: int = depth
+        self.subscriptions = list(subscriptions or [])
+        self.books: dict[str, LocalOrderBook] = {}
+
+    async def on_open(self) -> None:
+        """Forget the books, the snapshots are sent or fetched again."""
+        for book in self.books.values():
+            book.reset()
+
+    async def on_error(self, error: Exception) -> None:
+        """Report a connection error to the `on_error` handlers."""
+        EventType.ON_ERROR.emit(error)
+
+    async def on_message(self, message: str | bytes) -> None:
+        """Apply the book updates of a message and pass the books to the handler."""
+        try:
+            updates = self.exchange.parse_book_message(message)
+        except (KeyError, IndexError, TypeError, ValueError) as e:
+            self.logger.warning("Unexpected book message: %s (%s)", message, e)
+            return
+
+        for update in updates:
+            await self._apply(update)
+
+    async def _apply(self, update: OrderBookUpdate) -> None:
+        """Apply an update to the book of its symbol."""
+        book = self.books.get(update.symbol)
+        if book is None:
+            book = LocalOrderBook(update.symbol, self.exchange.compute_book_checksum)
+            self.books[update.symbol] = book
+
+        if not book.is_synced and not update.is_snapshot:
+            snapshot = await self._fetch_snapshot(update.symbol)
+            if snapshot is None:
+                # The snapshot of the stream is still to come
+                return
+            book.apply(snapshot)
+
+        try:
+            if not book.apply(update):
+                return
+        except OrderBookOutOfSyncError as e:
+            self.logger.warning("%s, resynchronizing", e)
+            await self._resync(book)
+            return
+
+        self.handler(book.get_order_book(self.depth))
+
+    async def _fetch_snapshot(self, symbol: str) -> OrderBookUpdate | None:
+        """Fetch the snapshot of a book from the REST API, if the exchange provides it."""
+        try:
+            return await asyncio.to_thread(self.exchange.get_book_snapshot, symbol)
+        except (MetaExpertError, OSError, RuntimeError) as e:
+            self.logger.warning("Failed to fetch the %s order book: %s", symbol, e)
+            EventType.ON_ERROR.emit(e)
+            return None
+
+    async def _resync(self, book: LocalOrderBook) -> None:
+        """Reset a book and resubscribe to it, to receive a new snapshot."""
+        book.reset()
+        for message in self.exchange.get_book_unsubscribe_messages(book.symbol):
+            await self.send(message)
+        for message in self.exchange.get_book_subscribe_messages(book.symbol):
+            await self.send(message)
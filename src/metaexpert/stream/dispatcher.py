@@ -0,0 +1,226 @@
+"""Dispatch of the market data updates."""
+
+from collections import OrderedDict
+from collections.abc import Callable
+from datetime import UTC, datetime
+from threading import RLock
+from typing import Any
+
+from metaexpert.core import (
+    Balance,
+    Broker,
+    Candle,
+    EventType,
+    Fill,
+    MetaExpertError,
+    Order,
+    OrderBook,
+    Position,
+    Tick,
+    Timeframe,
+)
+from metaexpert.data import BarSource
+from metaexpert.exchanges import MetaExchange
+from metaexpert.logger import MetaLogger as Logger, get_logger
+from metaexpert.paper import PaperBroker
+
+
+class StreamDispatcher:
+    """Route normalized stream updates to the broker and the event handlers.
+
+    Every trade and every candle update of the primary timeframe calls
+    `on_tick`; a candle of any timeframe also calls `on_bar` once, when it
+    closes. In paper trading, the paper broker is fed with the prices first,
+    so that the handlers see the orders filled by the update; `on_price` is
+    called next, before the handlers, to protect the positions.
+
+    With a history source, the bars that the stream missed are fetched from
+    the REST API, so that `on_bar` is still called once per closed candle:
+    when the bar scheduler reports a candle close that did not arrive, after
+    a reconnection, and when a closed candle follows a gap.
+
+    The updates of the private stream call `on_order`, `on_transaction`,
+    `on_position` and `on_account`, with the same payloads as the simulated
+    brokers. The order books are kept by symbol and passed to `on_book`.
+    """
+
+    # Number of recent orders kept to describe their fills
+    MAX_CACHED_ORDERS: int = 1000
+
+    def __init__(
+        self,
+        broker: Broker,
+        primary_timeframe: Timeframe | None = None,
+        history: BarSource | None = None,
+        on_price: Callable[[str, float], None] | None = None,
+    ) -> None:
+        """Initialize the dispatcher.
+
+        Args:
+            broker (Broker): Trading venue of the expert.
+            primary_timeframe (Timeframe | None): Timeframe of the candles calling `on_tick`, all if omitted.
+            history (BarSource | None): Source of the closed candles missed by the stream.
+            on_price (Callable | None): Called with the symbol and the price of every tick and primary candle update.
+        """
+        self.broker: Broker = broker
+        self.primary_timeframe: Timeframe | None = primary_timeframe
+        self.history: BarSource | None = history
+        self.on_price: Callable[[str, float], None] | None = on_price
+        self.logger: Logger = get_logger("StreamDispatcher")
+        self._closed: dict[tuple[str, str], datetime] = {}
+        self._orders: OrderedDict[str, dict[str, Any]] = OrderedDict()
+        self.books: dict[str, OrderBook] = {}
+        self._lock: RLock = RLock()
+
+    def dispatch(self, update: Candle | Tick) -> None:
+        """Handle one candle or tick update."""
+        with self._lock:
+            if isinstance(update, Tick):
+                if isinstance(self.broker, PaperBroker):
+                    self.broker.process_price(update.symbol, update.price, update.time)
+                if self.on_price is not None:
+                    self.on_price(update.symbol, update.price)
+                EventType.ON_TICK.emit(update.to_dict())
+                return
+
+            rates = update.to_dict()
+            if self.primary_timeframe in (None, update.timeframe):
+                if isinstance(self.broker, PaperBroker):
+                    self.broker.process_candle(update)
+                if self.on_price is not None:
+                    self.on_price(update.symbol, update.close)
+                EventType.ON_TICK.emit(rates)
+
+            if update.is_closed:
+                self._fill_gap(update)
+                self._dispatch_bar(update)
+
+    def dispatch_user(self, update: Order | Fill | Position | Balance) -> None:
+        """Handle one order, fill, position or balance update of the account."""
+        with self._lock:
+            if isinstance(update, Order):
+                order = update.to_dict()
+                if update.id is not None:
+                    self._orders[update.id] = order
+                    self._orders.move_to_end(update.id)
+                    while len(self._orders) > self.MAX_CACHED_ORDERS:
+                        self._orders.popitem(last=False)
+                EventType.ON_ORDER.emit(order)
+            elif isinstance(update, Fill):
+                order = self._orders.get(
+                    update.order_id, {"id": update.order_id, "symbol": update.symbol}
+                )
+                EventType.ON_TRANSACTION.emit(order, update.to_dict())
+            elif isinstance(update, Position):
+                if isinstance(self.broker, MetaExchange):
+                    self.broker.position_book.sync(update)
+                EventType.ON_POSITION.emit(update.to_dict())
+            else:
+                EventType.ON_ACCOUNT.emit(update.to_dict())
+
+    def dispatch_book(self, book: OrderBook) -> None:
+        """Keep the last order book of a symbol and pass it to the `on_book` handler."""
+        with self._lock:
+            self.books[book.symbol] = book
+            EventType.ON_BOOK.emit(book)
+
+    def check_bars(
+        self, symbols: list[str], timeframe: Timeframe, open_time: datetime
+    ) -> None:
+        """Dispatch the closed candles missed by the stream, up to a candle close.
+
+        Args:
+            symbols (list[str]): Streamed symbols.
+            timeframe (Timeframe): Timeframe of the closed candle.
+            open_time (datetime): Open time of the candle that closed.
+        """
+        delta = timeframe.get_delta()
+        for symbol in symbols:
+            with self._lock:
+                last = self._closed.get((symbol, timeframe.get_name()))
+            if last is not None and last >= open_time:
+                continue
+
+            # Fetched unlocked, not to hold the stream updates meanwhile
+            start = last + delta if last is not None else open_time
+            candles = self._fetch_missed(symbol, timeframe, start, open_time + delta)
+            with self._lock:
+                for candle in candles:
+                    self._dispatch_bar(candle)
+
+    def backfill(self, symbols: list[str], timeframes: list[Timeframe]) -> None:
+        """Dispatch the closed candles missed while the stream was disconnected."""
+        now = datetime.now(UTC)
+        for timeframe in timeframes:
+            last_closed = timeframe.get_candle_open_time(now) - timeframe.get_delta()
+            self.check_bars(symbols, timeframe, last_closed)
+
+    def _fill_gap(self, candle: Candle) -> None:
+        """Dispatch the missing candles between the last closed bar and a new one."""
+        delta = candle.timeframe.get_delta()
+        last = self._closed.get((candle.symbol, candle.timeframe.get_name()))
+        if last is None or candle.open_time <= last + delta:
+            return
+
+        self.logger.warning(
+            "Gap in the %s %s stream: %s to %s",
+            candle.symbol,
+            candle.timeframe.get_name(),
+            last + delta,
+            candle.open_time,
+        )
+        for missed in self._fetch_missed(
+            candle.symbol, candle.timeframe, last + delta, candle.open_time
+        ):
+            self._dispatch_bar(missed)
+
+    def _fetch_missed(
+        self, symbol: str, timeframe: Timeframe, start: datetime, end: datetime
+    ) -> list[Candle]:
+        """Fetch the closed candles opened within [start, end) from the history."""
+        if self.history is None:
+            return []
+
+        # RuntimeError also covers the adapters without a klines endpoint
+        try:
+            candles = self.history.load(symbol, timeframe, start, end)
+        except (MetaExpertError, OSError, RuntimeError) as e:
+            self.logger.warning(
+                "Failed to fetch the missed %s %s bars: %s",
+                symbol,
+                timeframe.get_name(),
+                e,
+            )
+            EventType.ON_ERROR.emit(e)
+            return []
+
+        if candles:
+            self.logger.warning(
+                "Stream missed %d %s %s bars, fetched from the REST API",
+                len(candles),
+                symbol,
+                timeframe.get_name(),
+            )
+        return candles
+
+    def _dispatch_bar(self, candle: Candle) -> None:
+        """Call the `on_bar` handlers with a closed candle, once."""
+        if not self._is_new_bar(candle):
+            return
+
+        self.logger.debug(
+            "Bar closed: %s %s %s",
+            candle.symbol,
+            candle.timeframe.get_name(),
+            candle.open_time,
+        )
+        EventType.ON_BAR.emit(candle.to_dict(), timeframe=candle.timeframe.get_name())
+
+    def _is_new_bar(self, candle: Candle) -> bool:
+        """Check that a closed candle was not dispatched yet (streams may repeat it)."""
+        key = (candle.symbol, candle.timeframe.get_name())
+        last = self._closed.get(key)
+        if last is not None and candle.open_time <= last:
+            return False
+        self._closed[key] = candle.open_time
+        return True
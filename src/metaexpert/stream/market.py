@@ -0,0 +1,75 @@
+"""Market data stream."""
+
+import asyncio
+from collections.abc import Callable
+
+from metaexpert.core import Candle, EventType, Tick
+from metaexpert.websocket import WebSocketClient
+
+# Normalizes a raw stream message into candles and ticks
+MessageParser = Callable[[str | bytes], list[Candle | Tick]]
+
+# Receives every normalized update
+UpdateHandler = Callable[[Candle | Tick], None]
+
+# Fetches the data missed while the stream was disconnected
+ReconnectHandler = Callable[[], None]
+
+
+class MarketStream(WebSocketClient):
+    """Public WebSocket stream of an exchange, normalized into candles and ticks.
+
+    The subscription messages are sent on every connection; every message is
+    parsed by the exchange adapter and the resulting updates are passed to the
+    handler in order. After a reconnection, the reconnect handler runs in a
+    worker thread to backfill the missed bars, and the connection errors are
+    reported to the `on_error` handlers.
+    """
+
+    def __init__(
+        self,
+        url: str,
+        parser: MessageParser,
+        handler: UpdateHandler,
+        *,
+        subscriptions: list[dict] | None = None,
+        name: str = "market",
+        ping_message: str | dict | None = None,
+        on_reconnect: ReconnectHandler | None = None,
+    ) -> None:
+        """Initialize the market stream.
+
+        Args:
+            url (str): WebSocket URL.
+            parser (MessageParser): Parser of the raw messages, usually `MetaExchange.parse_message`.
+            handler (UpdateHandler): Receiver of the candles and ticks.
+            subscriptions (list[dict] | None): Messages sent once connected.
+            name (str): Name of the stream in the logs.
+            ping_message (str | dict | None): Application-level ping of the exchange, if required.
+            on_reconnect (ReconnectHandler | None): Backfill of the data missed while disconnected.
+        """
+        super().__init__(url, name=name, ping_message=ping_message)
+        self.parser: MessageParser = parser
+        self.handler: UpdateHandler = handler
+        self.subscriptions = list(subscriptions or [])
+        self.reconnect_handler: ReconnectHandler | None = on_reconnect
+
+    async def on_reconnect(self) -> None:
+        """Backfill the data missed while the stream was disconnected."""
+        if self.reconnect_handler is not None:
+            await asyncio.to_thread(self.reconnect_handler)
+
+    async def on_error(self, error: Exception) -> None:
+        """Report a connection error to the `on_error` handlers."""
+        EventType.ON_ERROR.emit(error)
+
+    async def on_message(self, message: str | bytes) -> None:
+        """Parse a message and pass its updates to the handler."""
+        try:
+            updates = self.parser(message)
+        except (KeyError, IndexError, TypeError, ValueError) as e:
+            self.logger.warning("Unexpected stream message: %s (%s)", message, e)
+            return
+
+        for update in updates:
+            self.handler(update)
@@ -0,0 +1,123 @@
+"""Private user data stream."""
+
+import asyncio
+from collections.abc import Callable
+
+from metaexpert.config import USER_STREAM_KEEPALIVE_INTERVAL
+from metaexpert.core import (
+    AuthenticationError,
+    Balance,
+    EventType,
+    Fill,
+    MetaExpertError,
+    Order,
+    Position,
+)
+from metaexpert.exchanges import MetaExchange
+from metaexpert.websocket import WebSocketClient
+
+# Receives every normalized account update
+UserUpdateHandler = Callable[[Order | Fill | Position | Balance], None]
+
+
+class UserStream(WebSocketClient):
+    """Private WebSocket stream of an account, normalized into account updates.
+
+    On every connection the login frame of the exchange, if any, is sent and
+    its response checked before the subscriptions; a rejected login stops the
+    stream. The URL is requested again before every reconnection, since it
+    may embed expiring credentials (e.g. the Binance listen key), and the
+    credentials are kept alive every `keepalive_interval` seconds.
+    """
+
+    def __init__(
+        self,
+        exchange: MetaExchange,
+        handler: UserUpdateHandler,
+        *,
+        url: str | None = None,
+        name: str = "user",
+        keepalive_interval: float = USER_STREAM_KEEPALIVE_INTERVAL,
+    ) -> None:
+        """Initialize the user data stream.
+
+        Args:
+            exchange (MetaExchange): Exchange adapter authenticating and parsing the stream.
+            handler (UserUpdateHandler): Receiver of the order, fill, position and balance updates.
+            url (str | None): WebSocket URL, requested from the exchange if omitted.
+            name (str): Name of the stream in the logs.
+            keepalive_interval (float): Interval between the credential renewals, in seconds.
+        """
+        stream_url = url or exchange.get_user_stream_url()
+        if stream_url is None:
+            raise ValueError(f"{exchange.exchange} has no private stream.")
+
+        super().__init__(
+            stream_url, name=name, ping_message=exchange.get_ping_message()
+        )
+        self.exchange: MetaExchange = exchange
+        self.handler: UserUpdateHandler = handler
+        self.keepalive_interval: float = keepalive_interval
+        self.subscriptions = exchange.get_user_stream_subscriptions()
+
+    async def connect(self) -> None:
+        keepalive = asyncio.create_task(self._keepalive())
+        try:
+            await super().connect()
+        finally:
+            keepalive.cancel()
+
+    async def on_open(self) -> None:
+        """Authenticate the connection before the subscriptions are sent."""
+        login = self.exchange.get_user_stream_login()
+        if login is None or self.ws is None:
+            return
+
+        await self.send(login)
+        response = await asyncio.wait_for(self.ws.recv(), self.stale_timeout)
+        try:
+            self.exchange.check_user_stream_login(response)
+        except AuthenticationError as e:
+            self.logger.error("Private stream login rejected: %s", e)
+            EventType.ON_ERROR.emit(e)
+            self.running = False
+            await self.ws.close()
+
+    async def on_message(self, message: str | bytes) -> None:
+        """Parse a message and pass its updates to the handler."""
+        try:
+            updates = self.exchange.parse_user_message(message)
+        except (KeyError, IndexError, TypeError, ValueError) as e:
+            self.logger.warning("Unexpected user stream message: %s (%s)", message, e)
+            return
+
+        for update in updates:
+            self.handler(update)
+
+    async def on_error(self, error: Exception) -> None:
+        """Report a connection error to the `on_error` handlers."""
+        EventType.ON_ERROR.emit(error)
+
+    async def on_close(self) -> None:
+        """Request a fresh URL before reconnecting."""
+        if not self.running:
+            return
+
+        try:
+            url = await asyncio.to_thread(self.exchange.get_user_stream_url)
+        except (MetaExpertError, OSError, RuntimeError, ValueError) as e:
+            self.logger.warning("Failed to renew the private stream URL: %s", e)
+            EventType.ON_ERROR.emit(e)
+            return
+        if url is not None:
+            self.url = url
+
+    async def _keepalive(self) -> None:
+        """Renew the private stream credentials periodically."""
+        while True:
+            await asyncio.sleep(self.keepalive_interval)
+            try:
+                await asyncio.to_thread(self.exchange.keep_user_stream_alive)
+            except (MetaExpertError, OSError, RuntimeError) as e:
+                self.logger.warning("Failed to keep the private stream alive: %s", e)
+                EventType.ON_ERROR.emit(e)
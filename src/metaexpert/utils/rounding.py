@@ -0,0 +1,38 @@
+"""Rounding helpers."""
+
+from decimal import ROUND_DOWN, ROUND_HALF_UP, Decimal
+
+
+def round_down(value: float, step: float) -> float:
+    """Round a value down to a multiple of a step (e.g. a quantity to the lot step).
+
+    Args:
+        value: Value to round.
+        step: Positive increment, e.g. 0.001.
+
+    Returns:
+        The largest multiple of the step not above the value.
+    """
+    return _round(value, step, ROUND_DOWN)
+
+
+def round_nearest(value: float, step: float) -> float:
+    """Round a value to the nearest multiple of a step (e.g. a price to the tick size).
+
+    Args:
+        value: Value to round.
+        step: Positive increment, e.g. 0.01.
+
+    Returns:
+        The nearest multiple of the step.
+    """
+    return _round(value, step, ROUND_HALF_UP)
+
+
+def _round(value: float, step: float, rounding: str) -> float:
+    """Round with decimals, so that 0.3 stays 0.3 with a 0.1 step."""
+    if step <= 0:
+        raise ValueError(f"Step must be positive, got {step}")
+    decimal_step = Decimal(str(step))
+    steps = (Decimal(str(value)) / decimal_step).quantize(Decimal(1), rounding=rounding)
+    return float(steps * decimal_step)
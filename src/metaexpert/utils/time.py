@@ -0,0 +1,52 @@
+"""Time helpers."""
+
+import asyncio
+from datetime import UTC, datetime
+
+
+def to_utc(value: str | int | float | datetime) -> datetime:
+    """Convert a date string, a Unix timestamp or a datetime into an aware UTC datetime.
+
+    Naive datetimes and date strings are interpreted as UTC. Numeric values are
+    treated as milliseconds, microseconds or nanoseconds when they are too
+    large to be seconds.
+
+    Args:
+        value: ISO date string ('YYYY-MM-DD' or full ISO 8601), Unix timestamp or datetime.
+
+    Returns:
+        The timezone-aware UTC datetime.
+    """
+    if isinstance(value, datetime):
+        dt = value
+    elif isinstance(value, (int, float)):
+        seconds = value
+        while abs(seconds) > 1e11:
+            seconds /= 1000
+        return datetime.fromtimestamp(seconds, UTC)
+    else:
+        dt = datetime.fromisoformat(value.strip())
+
+    if dt.tzinfo is None:
+        return dt.replace(tzinfo=UTC)
+    return dt.astimezone(UTC)
+
+
+def to_milliseconds(value: datetime) -> int:
+    """Convert a datetime into a Unix timestamp in milliseconds."""
+    return int(to_utc(value).timestamp() * 1000)
+
+
+async def sleep_until(time: datetime, max_sleep: float) -> None:
+    """Sleep until an (aware) time, following the wall clock.
+
+    The remaining time is measured again after every sleep of at most
+    `max_sleep` seconds, so that oversleeping or an adjustment of the system
+    clock does not shift the wake-up time.
+
+    Args:
+        time: Time to wake up at.
+        max_sleep: Longest single sleep, in seconds.
+    """
+    while (remaining := (time - datetime.now(UTC)).total_seconds()) > 0:
+        await asyncio.sleep(min(remaining, max_sleep))
@@ -2,47 +2,163 @@
 
 import asyncio
 import json
+import random
 
 import websockets
+from websockets.exceptions import WebSocketException
 
+from metaexpert.config import (
+    WS_MAX_RECONNECT_DELAY,
+    WS_PING_INTERVAL,
+    WS_PING_TIMEOUT,
+    WS_RECONNECT_DELAY,
+    WS_STALE_TIMEOUT,
+)
 from metaexpert.logger import MetaLogger as Logger, get_logger
 
 
 class WebSocketClient:
+    """WebSocket connection kept open until it is stopped.
+
+    A lost connection is reopened after an exponential backoff with jitter,
+    capped at `max_reconnect_delay`, and the recorded subscriptions are sent
+    again. A connection that receives nothing for `stale_timeout` seconds is
+    considered dead and reopened as well. Some exchanges expect an
+    application-level ping, sent every `ping_interval` seconds when
+    `ping_message` is set.
+
+    Subclasses override the `on_*` hooks: `on_reconnect` is called once the
+    subscriptions are restored after a reconnection (e.g. to backfill the
+    missed data) and `on_error` on every connection or handler error.
+    """
+
     def __init__(
         self,
         url: str,
         name: str = "ws",
-        reconnect_delay: int = 5,
+        reconnect_delay: float = WS_RECONNECT_DELAY,
+        *,
+        max_reconnect_delay: float = WS_MAX_RECONNECT_DELAY,
+        stale_timeout: float = WS_STALE_TIMEOUT,
+        ping_interval: float = WS_PING_INTERVAL,
+        ping_message: str | dict | None = None,
     ):
         self.url = url
         self.name = name
         self.reconnect_delay = reconnect_delay
+        self.max_reconnect_delay = max_reconnect_delay
+        self.stale_timeout = stale_timeout
+        self.ping_interval = ping_interval
+        self.ping_message = ping_message
+        self.subscriptions: list[dict] = []
         self.ws = None
         self.running = False
-        self.logger: Logger = get_logger(__name__).bind(
-            component="WebSocketClient", ws_name=name, ws_url=url
-        )
+        self.attempts = 0
+        self._loop: asyncio.AbstractEventLoop | None = None
+        self.logger: Logger = get_logger(f"WebSocketClient.{name}")
 
     async def connect(self):
-        while True:
+        self.running = True
+        self._loop = asyncio.get_running_loop()
+        connected = False
+
+        while self.running:
             try:
                 async with websockets.connect(
-                    self.url, ping_interval=20, ping_timeout=10
+                    self.url,
+                    ping_interval=self.ping_interval,
+                    ping_timeout=WS_PING_TIMEOUT,
                 ) as ws:
                     self.ws = ws
-                    self.logger.info("Connected to {url}", url=self.url, name=self.name)
+                    self.logger.info("Connected to %s", self.url)
                     await self.on_open()
-                    async for message in ws:
-                        await self.on_message(message)
-            except Exception as e:
-                self.logger.error("Connection error", error=str(e), name=self.name)
-                await self.on_close()
-                await asyncio.sleep(self.reconnect_delay)
-
-    async def send(self, message: dict):
+                    for message in self.subscriptions:
+                        await self.send(message)
+                    if connected:
+                        await self.on_reconnect()
+                    connected = True
+                    await self._receive(ws)
+            except (OSError, TimeoutError, WebSocketException) as e:
+                # Closing the connection in stop() also ends up here
+                if self.running:
+                    self.logger.error("Connection error: %s", e)
+                    await self.on_error(e)
+            finally:
+                self.ws = None
+
+            await self.on_close()
+            if self.running:
+                delay = self.get_reconnect_delay(self.attempts)
+                self.attempts += 1
+                self.logger.info(
+                    "Reconnecting in %.1f seconds (attempt %d)", delay, self.attempts
+                )
+                await asyncio.sleep(delay)
+
+    def run(self) -> None:
+        """Connect and process messages in a new event loop (blocking)."""
+        asyncio.run(self.connect())
+
+    def stop(self) -> None:
+        """Close the connection without reconnecting, from any thread."""
+        self.running = False
+        if self.ws is not None and self._loop is not None and self._loop.is_running():
+            asyncio.run_coroutine_threadsafe(self.ws.close(), self._loop)
+
+    def get_reconnect_delay(self, attempt: int) -> float:
+        """Backoff before a reconnection attempt: exponential, capped, with jitter."""
+        delay = min(self.max_reconnect_delay, self.reconnect_delay * 2**attempt)
+        return delay / 2 + random.uniform(0, delay / 2)
+
+    async def send(self, message: str | dict):
         if self.ws:
-            await self.ws.send(json.dumps(message))
+            await self.ws.send(
+                message if isinstance(message, str) else json.dumps(message)
+            )
+
+    async def subscribe(self, message: dict) -> None:
+        """Send a subscription request, sent again after every reconnection."""
+        if message not in self.subscriptions:
+            self.subscriptions.append(message)
+        await self.send(message)
+
+    async def unsubscribe(self, message: dict, request: dict | None = None) -> None:
+        """Forget a subscription and send its unsubscription request, if any."""
+        if message in self.subscriptions:
+            self.subscriptions.remove(message)
+        if request is not None:
+            await self.send(request)
+
+    async def _receive(self, ws) -> None:
+        """Handle the messages until the connection closes or becomes stale."""
+        heartbeat = (
+            asyncio.create_task(self._heartbeat()) if self.ping_message else None
+        )
+        try:
+            while self.running:
+                try:
+                    message = await asyncio.wait_for(ws.recv(), self.stale_timeout)
+                except TimeoutError:
+                    raise TimeoutError(
+                        f"No message received for {self.stale_timeout:g} seconds"
+                    ) from None
+
+                # The backoff restarts once the connection delivers data
+                self.attempts = 0
+                try:
+                    await self.on_message(message)
+                except Exception as e:
+                    self.logger.exception("Failed to handle a message: %s", e)
+                    await self.on_error(e)
+        finally:
+            if heartbeat is not None:
+                heartbeat.cancel()
+
+    async def _heartbeat(self) -> None:
+        """Send the application-level ping of the exchange."""
+        while True:
+            await asyncio.sleep(self.ping_interval)
+            await self.send(self.ping_message)
 
     async def on_open(self):
         """Override in subclass."""
@@ -52,6 +168,14 @@ class WebSocketClient:
         """Override in subclass."""
         pass
 
+    async def on_reconnect(self):
+        """Override in subclass."""
+        pass
+
+    async def on_error(self, error: Exception):
+        """Override in subclass."""
+        pass
+
     async def on_close(self):
         """Override in subclass."""
         pass
@@ -0,0 +1,90 @@
+"""Unit tests for the backtesting engine."""
+
+import pytest
+from conftest import START
+
+from metaexpert.backtest import BacktestEngine, SimulatedBroker
+from metaexpert.core import Candle, EventType, Timeframe
+
+
+class TestBacktestEngine:
+    """Tests for the backtesting engine."""
+
+    def test_run_replays_bars_through_handlers(self, make_candle):
+        """Test that bars reach the handlers and the fitness comes from on_backtest."""
+        broker = SimulatedBroker(1000.0)
+        seen: list[float] = []
+
+        def on_bar(rates: dict) -> None:
+            seen.append(rates["close"])
+            if len(seen) == 1:
+                broker.place_order("BTCUSDT", "buy", "market", 1.0)
+
+        def on_backtest() -> float:
+            return 42.0
+
+        bars = EventType.ON_BAR.value["callback"]
+        fitness = EventType.ON_BACKTEST.value["callback"]
+        bars.append(on_bar)
+        fitness.append(on_backtest)
+        try:
+            candles = [
+                make_candle(2, 104, 106, 103, 105),
+                make_candle(0, 100, 101, 99, 100),
+                make_candle(1, 101, 104, 100, 103),
+            ]
+            result = BacktestEngine(broker, candles).run()
+        finally:
+            bars.remove(on_bar)
+            fitness.remove(on_backtest)
+
+        assert seen == [100, 103, 105]
+        assert result.bars == 3
+        assert len(result.trades) == 1
+        assert result.net_profit == pytest.approx(4.0)
+        assert result.fitness == 42.0
+        assert result.equity_curve[-1][1] == pytest.approx(1004.0)
+
+    def test_run_replays_several_timeframes(self, make_candle):
+        """Test that longer timeframes reach their on_bar handlers after the execution bars."""
+        broker = SimulatedBroker(1000.0)
+        seen: list[tuple[str, str]] = []
+
+        def on_tick(rates: dict) -> None:
+            seen.append(("tick", rates["timeframe"]))
+
+        def on_bar(rates: dict) -> None:
+            seen.append(("bar", rates["timeframe"]))
+
+        ticks = EventType.ON_TICK.value["callback"]
+        bars = EventType.ON_BAR.value["callback"]
+        ticks.append(on_tick)
+        bars.append(on_bar)
+        try:
+            candles = [
+                Candle(
+                    symbol="BTCUSDT",
+                    timeframe=Timeframe.H2,
+                    open_time=START,
+                    open=100,
+                    high=104,
+                    low=99,
+                    close=103,
+                ),
+                make_candle(0, 100, 101, 99, 100),
+                make_candle(1, 101, 104, 100, 103),
+            ]
+            result = BacktestEngine(broker, candles, timeframe=Timeframe.H1).run()
+        finally:
+            ticks.remove(on_tick)
+            bars.remove(on_bar)
+
+        assert seen == [
+            ("tick", "1h"),
+            ("bar", "1h"),
+            ("tick", "1h"),
+            ("bar", "1h"),
+            ("bar", "2h"),
+        ]
+        assert result.bars == 2
+        assert len(result.equity_curve) == 2
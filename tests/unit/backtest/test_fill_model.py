@@ -0,0 +1,119 @@
+"""Unit tests for the fill model of the simulated broker."""
+
+from datetime import timedelta
+
+import pytest
+
+from metaexpert.backtest import (
+    FeeSchedule,
+    FillModel,
+    FixedSlippage,
+    SimulatedBroker,
+    SpreadSlippage,
+    VolatilitySlippage,
+)
+from metaexpert.core import IntrabarPath, OrderStatus
+
+
+def make_broker(make_candle, **kwargs) -> SimulatedBroker:
+    """Broker that already saw the first candle."""
+    broker = SimulatedBroker(10000.0, fill_model=FillModel(**kwargs))
+    broker.process_candle(make_candle(0, 100, 101, 99, 100))
+    return broker
+
+
+class TestFillModel:
+    """Tests for FillModel."""
+
+    @pytest.mark.parametrize(
+        ("path", "exit_price"),
+        [(IntrabarPath.NEAREST, 95.0), (IntrabarPath.OHLC, 110.0)],
+    )
+    def test_intrabar_path_decides_between_stop_and_take_profit(
+        self, make_candle, path, exit_price
+    ):
+        """Test that the exit reached first on the intrabar path closes the position."""
+        broker = make_broker(make_candle, path=path)
+        broker.place_order("BTCUSDT", "buy", "market", 1.0)
+        broker.process_candle(make_candle(1, 100, 101, 99, 100))
+
+        stop = broker.place_order(
+            "BTCUSDT", "sell", "stop", 1.0, stop_price=95.0, reduce_only=True
+        )
+        take_profit = broker.place_order(
+            "BTCUSDT", "sell", "take_profit", 1.0, stop_price=110.0, reduce_only=True
+        )
+        # Nearest: the low is closer to the open, so the stop is reached first
+        broker.process_candle(make_candle(2, 100, 112, 94, 105))
+
+        filled = [order for order in (stop, take_profit) if order.is_filled]
+        assert [order.average_price for order in filled] == [exit_price]
+        assert not stop.is_open and not take_profit.is_open
+        assert broker.get_positions() == []
+
+    def test_maker_and_taker_fees_and_slippage(self, make_candle):
+        """Test that resting limits pay the maker fee and market fills the slippage and taker fee."""
+        broker = make_broker(
+            make_candle,
+            slippage=VolatilitySlippage(0.1), fees=FeeSchedule(maker=0.001, taker=0.002)
+        )
+        limit = broker.place_order("BTCUSDT", "buy", "limit", 1.0, price=96.0)
+        market = broker.place_order("BTCUSDT", "buy", "market", 1.0)
+
+        broker.process_candle(make_candle(1, 100, 105, 95, 100))
+
+        assert limit.average_price == 96.0
+        assert market.average_price == pytest.approx(101.0)
+        fees = [trade["fee"] for trade in broker.trades]
+        assert fees == pytest.approx([101.0 * 0.002, 96.0 * 0.001])
+
+    def test_partial_fills_against_the_volume(self, make_candle):
+        """Test that a candle fills at most its share of the volume, IOC and FOK orders not resting."""
+        broker = make_broker(make_candle, volume_pct=50)
+        order = broker.place_order("BTCUSDT", "buy", "market", 1.5)
+        ioc = broker.place_order(
+            "BTCUSDT", "buy", "limit", 1.0, price=100.0, time_in_force="ioc"
+        )
+        fok = broker.place_order(
+            "BTCUSDT", "buy", "limit", 2.0, price=100.0, time_in_force="fok"
+        )
+
+        broker.process_candle(make_candle(1, 100, 101, 99, 100, volume=2.0))
+        assert order.status is OrderStatus.PARTIALLY_FILLED
+        assert order.filled_quantity == pytest.approx(1.0)
+        assert ioc.status is OrderStatus.CANCELED and ioc.filled_quantity == 0.0
+        assert fok.status is OrderStatus.CANCELED
+
+        broker.process_candle(make_candle(2, 100, 101, 99, 100, volume=2.0))
+        assert order.status is OrderStatus.FILLED
+
+    def test_latency_shifts_the_fill_into_the_candle(self, make_candle):
+        """Test that an order arriving within a candle fills at the price of its arrival."""
+        broker = make_broker(make_candle, latency=timedelta(minutes=10))
+        order = broker.place_order("BTCUSDT", "buy", "market", 1.0)
+
+        # The path takes 20 minutes from the open to the high, the nearest extreme
+        broker.process_candle(make_candle(1, 100, 102, 90, 95))
+
+        assert order.average_price == pytest.approx(101.0)
+
+    def test_parse(self):
+        """Test that the options override the defaults and invalid ones are rejected."""
+        model = FillModel.parse(
+            "path=olhc, slippage=spread:0.2, taker=0.0007, volume_pct=10, latency_ms=250",
+            slippage=FixedSlippage(0.1),
+            fees=FeeSchedule(0.0002, 0.0005),
+        )
+
+        assert model.path is IntrabarPath.OLHC
+        assert isinstance(model.slippage, SpreadSlippage)
+        assert model.fees == FeeSchedule(0.0002, 0.0007)
+        assert model.volume_pct == 10.0
+        assert model.latency == timedelta(milliseconds=250)
+        assert FillModel.parse("").path is IntrabarPath.NEAREST
+        assert FillModel.parse("slippage=volatility").slippage.factor == 0.1
+        assert FeeSchedule.get_fee_schedule("Binance", "futures").taker == 0.0005
+        assert FeeSchedule.get_fee_schedule("unknown", "spot") == FeeSchedule()
+        for text in ("path", "path=circle", "slippage=magic:1", "speed=1"):
+            with pytest.raises(ValueError):
+                FillModel.parse(text)
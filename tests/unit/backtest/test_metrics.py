@@ -0,0 +1,175 @@
+"""Unit tests for the backtest metrics and reports."""
+
+import csv
+import json
+from datetime import UTC, datetime, timedelta
+
+import pytest
+
+from metaexpert.backtest import (
+    BacktestEngine,
+    BacktestResult,
+    Metrics,
+    SimulatedBroker,
+    get_round_trips,
+    write_report,
+)
+from metaexpert.core import EventType
+
+START = datetime(2024, 1, 1, tzinfo=UTC)
+
+
+def make_fill(hours: int, side: str, quantity: float, price: float, pnl: float = 0.0):
+    """Create a fill as recorded by the simulated broker, without fee."""
+    return {
+        "order_id": str(hours),
+        "symbol": "BTCUSDT",
+        "side": side,
+        "quantity": quantity,
+        "price": price,
+        "fee": 0.0,
+        "realized_pnl": pnl,
+        "time": START + timedelta(hours=hours),
+    }
+
+
+def make_result() -> BacktestResult:
+    """Result of a winning long, a losing short and a drawdown in between."""
+    equities = [1000.0, 1010.0, 1020.0, 1005.0, 990.0, 1000.0, 1015.0, 1015.0]
+    return BacktestResult(
+        initial_capital=1000.0,
+        final_equity=equities[-1],
+        start=START,
+        end=START + timedelta(hours=len(equities)),
+        bars=len(equities),
+        trades=[
+            make_fill(0, "buy", 1.0, 100.0),
+            make_fill(2, "sell", 1.0, 120.0, pnl=20.0),
+            make_fill(3, "sell", 2.0, 100.0),
+            make_fill(4, "buy", 2.0, 102.5, pnl=-5.0),
+        ],
+        equity_curve=[
+            (START + timedelta(hours=index + 1), equity)
+            for index, equity in enumerate(equities)
+        ],
+    )
+
+
+class TestRoundTrips:
+    """Tests for get_round_trips."""
+
+    def test_reversal_closes_and_opens_a_round_trip(self):
+        """Test that a fill reversing the position ends a round trip and starts the next."""
+        fills = [
+            make_fill(0, "buy", 1.0, 100.0),
+            make_fill(1, "buy", 1.0, 110.0),
+            make_fill(2, "sell", 3.0, 120.0, pnl=30.0),
+            make_fill(3, "buy", 1.0, 115.0, pnl=5.0),
+        ]
+
+        trips = get_round_trips(fills)
+
+        assert [trip.side for trip in trips] == ["long", "short"]
+        assert trips[0].quantity == 2.0
+        assert trips[0].entry_price == pytest.approx(105.0)
+        assert trips[0].pnl == pytest.approx(30.0)
+        assert trips[0].duration == timedelta(hours=2)
+        assert trips[1].entry_price == 120.0 and trips[1].exit_price == 115.0
+        assert trips[1].return_pct == pytest.approx(5 / 120 * 100)
+
+    def test_hedged_sides_are_separate_round_trips(self):
+        """Test that the long and short positions of a hedged symbol are not netted."""
+        fills = [
+            make_fill(0, "buy", 1.0, 100.0) | {"position_side": "long"},
+            make_fill(1, "sell", 1.0, 110.0) | {"position_side": "short"},
+            make_fill(2, "sell", 1.0, 120.0, pnl=20.0) | {"position_side": "long"},
+            make_fill(3, "buy", 1.0, 105.0, pnl=5.0) | {"position_side": "short"},
+        ]
+
+        trips = get_round_trips(fills)
+
+        assert [trip.side for trip in trips] == ["long", "short"]
+        assert (trips[0].entry_price, trips[0].exit_price) == (100.0, 120.0)
+        assert (trips[1].entry_price, trips[1].exit_price) == (110.0, 105.0)
+        assert [trip.pnl for trip in trips] == [20.0, 5.0]
+
+
+class TestMetrics:
+    """Tests for the metrics of a backtest result."""
+
+    def test_metrics_of_a_pass(self):
+        """Test the trade statistics and the drawdown of a pass."""
+        metrics = make_result().metrics
+
+        assert metrics.net_profit == pytest.approx(15.0)
+        assert metrics.trades == 2
+        assert metrics.win_rate_pct == pytest.approx(50.0)
+        assert metrics.profit_factor == pytest.approx(4.0)
+        assert metrics.expectancy == pytest.approx(7.5)
+        assert metrics.max_drawdown == pytest.approx(30.0)
+        assert metrics.max_drawdown_pct == pytest.approx(30 / 1020 * 100)
+        assert metrics.max_drawdown_days == pytest.approx(5 / 24)
+        assert metrics.exposure_pct == pytest.approx(3 / 8 * 100)
+        assert metrics.sharpe > 0 and metrics.sortino > metrics.sharpe
+        assert metrics.cagr_pct > 0 and metrics.calmar > 0
+
+    def test_metric_lookup(self):
+        """Test that metrics are selected by name and unknown names are rejected."""
+        metrics = Metrics(sharpe=1.5, trades=3)
+
+        assert metrics.get("sharpe") == 1.5
+        assert metrics.get("trades") == 3.0
+        with pytest.raises(ValueError, match="Unknown metric"):
+            metrics.get("alpha")
+
+    def test_on_backtest_selects_the_fitness_metric(self):
+        """Test that the pass result is set for on_backtest_pass and on_backtest names the fitness."""
+        engine = BacktestEngine(SimulatedBroker(1000.0), [])
+        seen: list[BacktestResult | None] = []
+
+        def on_backtest_pass() -> None:
+            seen.append(engine.result)
+
+        def on_backtest() -> str:
+            return "max_drawdown_days"
+
+        passes = EventType.ON_BACKTEST_PASS.value["callback"]
+        fitness = EventType.ON_BACKTEST.value["callback"]
+        passes.append(on_backtest_pass)
+        fitness.append(on_backtest)
+        try:
+            result = engine.run()
+        finally:
+            passes.remove(on_backtest_pass)
+            fitness.remove(on_backtest)
+
+        assert seen == [result]
+        assert result.fitness == 0.0
+
+
+class TestReport:
+    """Tests for write_report."""
+
+    def test_json_report(self, tmp_path):
+        """Test that the JSON report holds the metrics, round trips and equity curve."""
+        path = write_report(make_result(), tmp_path / "report.json")
+
+        content = json.loads(path.read_text(encoding="utf-8"))
+        assert content["summary"]["trades"] == 2
+        assert content["summary"]["fills"] == 4
+        assert len(content["round_trips"]) == 2
+        assert len(content["equity_curve"]) == 8
+
+    def test_csv_and_html_reports(self, tmp_path):
+        """Test that the CSV report has its trade list and the HTML report is self-contained."""
+        result = make_result()
+        write_report(result, tmp_path / "report.csv")
+        html = write_report(result, tmp_path / "report.html").read_text(
+            encoding="utf-8"
+        )
+
+        with (tmp_path / "report-trades.csv").open(encoding="utf-8") as file:
+            trades = list(csv.DictReader(file))
+        assert [row["side"] for row in trades] == ["long", "short"]
+        assert (tmp_path / "report-equity.csv").exists()
+        assert "<svg" in html and "<script" not in html and "sharpe" in html
@@ -0,0 +1,132 @@
+"""Unit tests for the parameter optimizer."""
+
+import json
+import math
+
+import pytest
+
+from metaexpert.backtest import (
+    OptimizationPass,
+    Optimizer,
+    Parameter,
+    run_backtest,
+    write_optimization_report,
+)
+from metaexpert.core import OptimizationMethod
+
+# Expert stand-in: writes a report whose fitness peaks at x=3, y="b"; y="flawless"
+# writes the infinite profit factor of a pass without losing trades
+SCRIPT = """
+import json, os
+
+parameters = json.loads(os.environ["METAEXPERT_PARAMETERS"])
+if parameters.get("x") == 0:
+    raise SystemExit("x must be positive")
+fitness = -((parameters.get("x", 3) - 3) ** 2) + (parameters.get("y") == "b")
+if parameters.get("y") == "flawless":
+    fitness = "inf"
+with open(os.environ["METAEXPERT_REPORT_FILE"], "w") as file:
+    json.dump(
+        {
+            "summary": {"fitness": fitness, "trades": 1},
+            "equity_curve": [["2024-01-01 00:00:00+00:00", 1000.0 + float(fitness)]],
+        },
+        file,
+    )
+"""
+
+
+class FakeOptimizer(Optimizer):
+    """Optimizer scoring the combinations without running the expert."""
+
+    def run_pass(self, parameters: dict) -> OptimizationPass:
+        """Score the combination with a known optimum."""
+        fitness = -((parameters["x"] - 3) ** 2) + (parameters["y"] == "b")
+        return OptimizationPass(parameters, fitness=fitness)
+
+
+PARAMETERS = [Parameter.parse("x=1:10:1"), Parameter.parse("y=a|b|c")]
+
+
+class TestParameter:
+    """Tests for Parameter.parse."""
+
+    def test_ranges_and_lists(self):
+        """Test that ranges include their stop and values are converted."""
+        assert Parameter.parse("x=1:3:1").values == (1, 2, 3)
+        assert Parameter.parse("stop_loss_pct=1:2:0.5").values == (1.0, 1.5, 2.0)
+        assert Parameter.parse("flag=true|false").values == (True, False)
+        assert Parameter.parse("period=5|9.5|slow").values == (5, 9.5, "slow")
+
+        for text in ("x", "x=1:2", "x=3:1:1", "x=1:3:0"):
+            with pytest.raises(ValueError):
+                Parameter.parse(text)
+
+
+class TestOptimizer:
+    """Tests for Optimizer."""
+
+    def test_grid_search_ranks_every_combination(self):
+        """Test that the grid search tries every combination, best fitness first."""
+        results = FakeOptimizer("main.py", PARAMETERS, workers=2).run()
+
+        assert len(results) == 30
+        assert results[0].parameters == {"x": 3, "y": "b"}
+        fitnesses = [item.fitness for item in results]
+        assert fitnesses == sorted(fitnesses, reverse=True)
+
+    def test_random_and_genetic_searches_respect_the_budget(self):
+        """Test that the random and genetic searches try at most `passes` distinct combinations."""
+        random_search = FakeOptimizer(
+            "main.py", PARAMETERS, method=OptimizationMethod.RANDOM, passes=12, seed=1
+        ).run()
+        genetic_search = FakeOptimizer(
+            "main.py",
+            PARAMETERS,
+            method=OptimizationMethod.GENETIC,
+            passes=24,
+            population=6,
+            seed=1,
+        ).run()
+
+        assert len(random_search) == 12
+        assert len({tuple(item.parameters.items()) for item in random_search}) == 12
+        assert 6 < len(genetic_search) <= 24
+        assert genetic_search[0].fitness >= random_search[-1].fitness
+
+    def test_passes_run_the_script(self, tmp_path):
+        """Test that a pass runs the script with its inputs and reads the fitness of its report."""
+        script = tmp_path / "main.py"
+        script.write_text(SCRIPT, encoding="utf-8")
+
+        results = Optimizer(
+            script, [Parameter.parse("x=0|3"), Parameter.parse("y=b")], workers=2
+        ).run()
+
+        assert results[0].parameters == {"x": 3, "y": "b"}
+        assert results[0].fitness == 1.0 and results[0].metrics == {"trades": 1}
+        assert results[1].fitness is None
+        assert results[1].error == "x must be positive"
+        assert run_backtest(script).fitness == 0.0
+        assert run_backtest(script, details=True).equity_curve[0][1] == 1000.0
+
+        report = write_optimization_report(results, tmp_path / "results.json")
+        rows = json.loads(report.read_text(encoding="utf-8"))
+        assert [row["rank"] for row in rows] == [1, 2]
+
+    def test_infinite_fitness_ranks_first(self, tmp_path):
+        """Test that a pass without losing trades keeps its infinite fitness and ranks first."""
+        script = tmp_path / "main.py"
+        script.write_text(SCRIPT, encoding="utf-8")
+
+        parameters = [Parameter.parse("x=0|3"), Parameter.parse("y=b|flawless")]
+        results = Optimizer(script, parameters, workers=2).run()
+
+        assert results[0].parameters == {"x": 3, "y": "flawless"}
+        assert results[0].fitness == math.inf and results[0].error is None
+        assert results[1].fitness == 1.0
+        assert all(item.error is not None for item in results[2:])
+
+        report = write_optimization_report(results, tmp_path / "results.json")
+        rows = json.loads(report.read_text(encoding="utf-8"))
+        assert rows[0]["fitness"] == "inf"
@@ -0,0 +1,177 @@
+"""Unit tests for the simulated broker."""
+
+from datetime import timedelta
+
+import pytest
+from conftest import START
+
+from metaexpert.backtest import SimulatedBroker
+from metaexpert.core import (
+    ContractType,
+    FundingRate,
+    InsufficientFundsError,
+    MarginMode,
+    OrderStatus,
+)
+from metaexpert.data import FundingSource
+
+HOUR = timedelta(hours=1)
+
+
+class FakeFundingSource(FundingSource):
+    """Funding source serving a fixed list of funding rates."""
+
+    def __init__(self, rates: list[FundingRate]) -> None:
+        self.rates = rates
+
+    def load(self, symbol, start, end=None) -> list[FundingRate]:
+        """Funding rates of the symbol within [start, end)."""
+        return [
+            rate
+            for rate in self.rates
+            if rate.symbol == symbol
+            and start <= rate.time
+            and (end is None or rate.time < end)
+        ]
+
+
+def open_long(make_candle, broker: SimulatedBroker, quantity: float = 1.0) -> None:
+    """Buy at 100 on the second candle."""
+    broker.process_candle(make_candle(0, 100, 101, 99, 100))
+    broker.place_order("BTCUSDT", "buy", "market", quantity)
+    broker.process_candle(make_candle(1, 100, 101, 99, 100))
+
+
+class TestSimulatedBroker:
+    """Tests for the simulated broker."""
+
+    def test_market_order_fills_at_next_open(self, make_candle):
+        """Test that a market order fills at the open of the next candle."""
+        broker = SimulatedBroker(1000.0, fee=0.001)
+        broker.process_candle(make_candle(0, 100, 101, 99, 100))
+
+        order = broker.place_order("BTCUSDT", "buy", "market", 1.0)
+        assert order.status is OrderStatus.NEW
+
+        broker.process_candle(make_candle(1, 102, 110, 101, 108))
+
+        assert order.status is OrderStatus.FILLED
+        assert order.average_price == 102
+        assert broker.balance == pytest.approx(1000.0 - 0.102)
+        assert broker.equity == pytest.approx(1000.0 - 0.102 + 6.0)
+
+    def test_limit_and_stop_orders(self, make_candle):
+        """Test that limit and stop orders fill only when the range reaches them."""
+        broker = SimulatedBroker(1000.0)
+        limit = broker.place_order("BTCUSDT", "buy", "limit", 1.0, price=95.0)
+        stop = broker.place_order("BTCUSDT", "sell", "stop", 1.0, stop_price=90.0)
+
+        broker.process_candle(make_candle(0, 100, 101, 96, 100))
+        assert limit.is_open and stop.is_open
+
+        broker.process_candle(make_candle(1, 97, 98, 89, 91))
+        assert limit.average_price == 95.0
+        assert stop.average_price == 90.0
+        assert broker.balance == pytest.approx(995.0)
+        assert broker.get_positions() == []
+
+    def test_reduce_only_without_position_is_canceled(self, make_candle):
+        """Test that a reduce-only order without a position is canceled."""
+        broker = SimulatedBroker(1000.0)
+        order = broker.place_order("BTCUSDT", "sell", "market", 1.0, reduce_only=True)
+
+        broker.process_candle(make_candle(0, 100, 101, 99, 100))
+
+        assert order.status is OrderStatus.CANCELED
+
+    def test_insufficient_funds(self, make_candle):
+        """Test that an order exceeding the free margin is rejected."""
+        broker = SimulatedBroker(100.0)
+        broker.process_candle(make_candle(0, 100, 101, 99, 100))
+
+        with pytest.raises(InsufficientFundsError):
+            broker.place_order("BTCUSDT", "buy", "market", 2.0)
+
+
+class TestFunding:
+    """Tests for the funding payments."""
+
+    def test_funding_is_paid_at_the_funding_timestamps(self, make_candle):
+        """Test that a long pays positive rates and receives negative ones."""
+        rates = [
+            FundingRate("BTCUSDT", START, 0.01),
+            FundingRate("BTCUSDT", START + 2 * HOUR, 0.001),
+            FundingRate("BTCUSDT", START + 3 * HOUR, -0.001, mark_price=110.0),
+        ]
+        broker = SimulatedBroker(1000.0, funding_source=FakeFundingSource(rates))
+        open_long(make_candle, broker)
+
+        broker.process_candle(make_candle(2, 100, 101, 99, 100))
+        broker.process_candle(make_candle(3, 100, 101, 99, 100))
+
+        # No position yet at the first timestamp
+        payments = [payment["payment"] for payment in broker.funding_payments]
+        assert payments == pytest.approx([-0.1, 0.11])
+        assert broker.funding == pytest.approx(0.01)
+        assert broker.balance == pytest.approx(1000.01)
+
+    def test_inverse_account_settles_in_the_coin(self, make_candle):
+        """Test that the PnL, fees and funding of inverse contracts are in the coin."""
+        rates = [FundingRate("BTCUSDT", START + 2 * HOUR, 0.001)]
+        broker = SimulatedBroker(
+            20.0,
+            fee=0.001,
+            contract_type=ContractType.INVERSE,
+            currency="BTC",
+            funding_source=FakeFundingSource(rates),
+        )
+        open_long(make_candle, broker, 1000.0)
+        broker.place_order("BTCUSDT", "sell", "market", 1000.0, reduce_only=True)
+
+        broker.process_candle(make_candle(2, 125, 126, 124, 125))
+
+        # PnL 1000 * (1/100 - 1/125), funding and fees on 10 and 8 coins
+        assert broker.balance == pytest.approx(20.0 + 2.0 - 0.008 - 0.018)
+
+
+class TestLiquidation:
+    """Tests for the liquidation of the positions."""
+
+    def test_isolated_position_is_liquidated(self, make_candle):
+        """Test that an isolated position reaching its liquidation price loses its margin."""
+        broker = SimulatedBroker(1000.0, leverage=10, maintenance_margin_rate=0.005)
+        open_long(make_candle, broker)
+        price = broker.get_positions()[0].liquidation_price
+        assert price == pytest.approx(90 / 0.995)
+
+        broker.process_candle(make_candle(2, 100, 101, 85, 88))
+
+        assert broker.get_positions() == []
+        trade = broker.trades[-1]
+        assert trade["liquidation"] and trade["price"] == pytest.approx(price)
+        assert broker.balance == pytest.approx(990.0)
+
+    @pytest.mark.parametrize(
+        ("margin_mode", "stop_price", "balance"),
+        [(MarginMode.CROSS, None, 988.0), (MarginMode.ISOLATED, 95.0, 995.0)],
+    )
+    def test_cross_margin_and_earlier_stops_avoid_liquidation(
+        self, make_candle, margin_mode, stop_price, balance
+    ):
+        """Test that the balance backs a cross position and a stop reached first closes it."""
+        broker = SimulatedBroker(
+            1000.0,
+            leverage=10,
+            margin_mode=margin_mode,
+            maintenance_margin_rate=0.005,
+        )
+        open_long(make_candle, broker)
+        if stop_price is not None:
+            broker.place_order(
+                "BTCUSDT", "sell", "stop", 1.0, stop_price=stop_price, reduce_only=True
+            )
+
+        broker.process_candle(make_candle(2, 100, 101, 85, 88))
+
+        assert not any(trade["liquidation"] for trade in broker.trades)
+        assert broker.equity == pytest.approx(balance)
@@ -0,0 +1,150 @@
+"""Unit tests for the walk-forward analysis."""
+
+import json
+from datetime import UTC, datetime, timedelta
+
+import pytest
+
+from metaexpert.backtest import (
+    OptimizationPass,
+    Optimizer,
+    Parameter,
+    RoundTrip,
+    WalkForward,
+    WalkForwardWindow,
+    get_windows,
+    write_walk_forward_report,
+)
+
+START = datetime(2024, 1, 1, tzinfo=UTC)
+DAY = timedelta(days=1)
+
+
+class FakeOptimizer(Optimizer):
+    """Optimizer whose best x is 4 once the in-sample period reaches 2024-01-06."""
+
+    def run_pass(self, parameters: dict) -> OptimizationPass:
+        """Score the combination with an optimum depending on the period."""
+        best = 3 if self.end < "2024-01-06" else 4
+        return OptimizationPass(parameters, fitness=-((parameters["x"] - best) ** 2))
+
+
+class FakeWalkForward(WalkForward):
+    """Walk-forward analysis earning 10% in every out-of-sample period."""
+
+    def get_optimizer(self, window: WalkForwardWindow) -> Optimizer:
+        """Optimizer of the in-sample period, without running the expert."""
+        return FakeOptimizer(
+            self.script,
+            self.parameters,
+            start=window.in_sample_start.isoformat(),
+            end=window.in_sample_end.isoformat(),
+        )
+
+    def run_pass(self, parameters: dict, window: WalkForwardWindow) -> OptimizationPass:
+        """Out-of-sample pass with one winning round trip."""
+        trip = RoundTrip(
+            symbol="BTCUSDT",
+            side="long",
+            quantity=1.0,
+            entry_time=window.out_of_sample_start,
+            exit_time=window.out_of_sample_end,
+            entry_price=1000.0,
+            exit_price=1100.0,
+            pnl=100.0,
+        )
+        return OptimizationPass(
+            parameters,
+            fitness=100.0,
+            metrics={"initial_capital": 1000.0, "final_equity": 1100.0},
+            equity_curve=[(window.out_of_sample_end, 1100.0)],
+            round_trips=[trip],
+        )
+
+
+def make_analysis(**kwargs) -> FakeWalkForward:
+    """Analysis of three windows over six days, one day out of sample each."""
+    return FakeWalkForward(
+        "main.py",
+        [Parameter.parse("x=1:5:1")],
+        start=START,
+        end=START + 6 * DAY,
+        windows=3,
+        out_of_sample_pct=25.0,
+        **kwargs,
+    )
+
+
+class TestWindows:
+    """Tests for get_windows."""
+
+    def test_rolling_and_anchored_windows(self):
+        """Test that the out-of-sample periods follow each other up to the end of the range."""
+        rolling = get_windows(START, START + 6 * DAY, windows=3, out_of_sample_pct=25)
+        anchored = get_windows(
+            "2024-01-01", "2024-01-07", windows=3, out_of_sample_pct=25, anchored=True
+        )
+
+        assert [item.in_sample_start for item in rolling] == [
+            START,
+            START + DAY,
+            START + 2 * DAY,
+        ]
+        assert [item.out_of_sample_start for item in rolling] == [
+            START + 3 * DAY,
+            START + 4 * DAY,
+            START + 5 * DAY,
+        ]
+        assert rolling[-1].out_of_sample_end == START + 6 * DAY
+        assert all(item.in_sample_start == START for item in anchored)
+        assert [item.out_of_sample_end for item in anchored] == [
+            item.out_of_sample_end for item in rolling
+        ]
+
+    def test_invalid_splits(self):
+        """Test that empty ranges, window counts and shares are rejected."""
+        splits = ({"windows": 0}, {"out_of_sample_pct": 0}, {"out_of_sample_pct": 100})
+        for split in splits:
+            with pytest.raises(ValueError):
+                get_windows(START, START + 6 * DAY, **split)
+        with pytest.raises(ValueError):
+            get_windows(START, START)
+
+
+class TestWalkForward:
+    """Tests for WalkForward."""
+
+    def test_stitched_equity_and_parameter_stability(self):
+        """Test that each out-of-sample period compounds on the previous one."""
+        result = make_analysis(initial_capital=1000.0).run()
+
+        assert [step.parameters["x"] for step in result.steps] == [3, 3, 4]
+        assert [equity for _, equity in result.equity_curve] == pytest.approx(
+            [1100.0, 1210.0, 1331.0]
+        )
+        assert [trip.pnl for trip in result.round_trips] == pytest.approx(
+            [100.0, 110.0, 121.0]
+        )
+        assert result.metrics.return_pct == pytest.approx(33.1)
+        assert result.metrics.trades == 3
+
+        stability = result.stability[0]
+        assert stability.values == (3, 3, 4)
+        assert stability.mode == 3
+        assert stability.mode_pct == pytest.approx(200 / 3)
+        assert stability.mean == pytest.approx(10 / 3)
+
+    def test_report(self, tmp_path):
+        """Test that the report holds the summary, the windows and the stability."""
+        result = make_analysis().run()
+
+        path = write_walk_forward_report(result, tmp_path / "walk_forward.json")
+        html = write_walk_forward_report(result, tmp_path / "walk_forward.html")
+
+        content = json.loads(path.read_text(encoding="utf-8"))
+        assert content["summary"]["initial_capital"] == 1000.0
+        assert content["summary"]["windows"] == 3
+        assert [row["x"] for row in content["windows"]] == [3, 3, 4]
+        assert content["stability"][0]["mode"] == 3
+        assert len(content["equity_curve"]) == 3
+        assert "Parameter stability" in html.read_text(encoding="utf-8")
@@ -0,0 +1,195 @@
+"""Unit tests for the `run` and `backtest` CLI commands."""
+
+from pathlib import Path
+
+import pytest
+import typer
+from typer.testing import CliRunner
+
+from metaexpert.cli.commands import backtest, run
+from metaexpert.config import (
+    ENV_BACKTEST_END,
+    ENV_BACKTEST_START,
+    ENV_FILL_MODEL,
+    ENV_INITIAL_CAPITAL,
+    ENV_REPORT_FILE,
+    ENV_TRADE_MODE,
+    REPORT_DIRECTORY,
+    TRADE_MODE_BACKTEST,
+)
+
+app = typer.Typer()
+app.command(name="run")(run.cmd_run)
+app.command(name="backtest")(backtest.cmd_backtest)
+
+runner = CliRunner()
+
+
+class FakeRunScript:
+    """Stand-in of `run_script` recording its calls instead of running the expert."""
+
+    def __init__(self, code: int = 0, *, writes_report: bool = True) -> None:
+        self.code = code
+        self.writes_report = writes_report
+        self.calls: list[tuple[Path, dict[str, str]]] = []
+
+    def __call__(self, script: Path, env: dict[str, str]) -> int:
+        self.calls.append((script, env))
+        if self.writes_report and ENV_REPORT_FILE in env:
+            report = Path(env[ENV_REPORT_FILE])
+            report.parent.mkdir(parents=True, exist_ok=True)
+            report.write_text("{}", encoding="utf-8")
+        return self.code
+
+
+@pytest.fixture
+def project(tmp_path: Path) -> Path:
+    """Create an expert project with a script and an environment file."""
+    (tmp_path / "main.py").write_text("", encoding="utf-8")
+    (tmp_path / ".env").write_text("API_KEY=abc\n", encoding="utf-8")
+    return tmp_path
+
+
+@pytest.fixture
+def run_script(monkeypatch) -> FakeRunScript:
+    """Replace `run_script` in both commands by a recording stand-in."""
+    fake = FakeRunScript()
+    monkeypatch.setattr(run, "run_script", fake)
+    monkeypatch.setattr(backtest, "run_script", fake)
+    return fake
+
+
+class TestRunCommand:
+    """Tests for the `run` command."""
+
+    def test_runs_the_script_with_the_project_env(self, project, run_script):
+        """Test that the project script runs with its `.env` file over the environment."""
+        result = runner.invoke(app, ["run", str(project)])
+
+        assert result.exit_code == 0, result.output
+        [(script, env)] = run_script.calls
+        assert script == (project / "main.py").resolve()
+        assert env["API_KEY"] == "abc"
+
+    def test_env_file_replaces_the_project_env(self, project, run_script):
+        """Test that `--env-file` is read instead of the project `.env` file."""
+        env_file = project / "live.env"
+        env_file.write_text("API_KEY=live\n", encoding="utf-8")
+
+        result = runner.invoke(app, ["run", str(project), "--env-file", str(env_file)])
+
+        assert result.exit_code == 0, result.output
+        assert run_script.calls[0][1]["API_KEY"] == "live"
+
+    @pytest.mark.parametrize(
+        ("arguments", "message"),
+        [
+            (["--script", "missing.py"], "Expert script not found"),
+            (["--env-file", "missing.env"], "Environment file not found"),
+        ],
+    )
+    def test_missing_files_exit_with_an_error(
+        self, project, run_script, arguments, message
+    ):
+        """Test that a missing script or env file exits with code 1 before running."""
+        result = runner.invoke(app, ["run", str(project), *arguments])
+
+        assert result.exit_code == 1
+        assert message in result.output
+        assert run_script.calls == []
+
+    def test_exit_code_of_the_expert_is_returned(self, project, run_script):
+        """Test that a failing expert exits the command with its code."""
+        run_script.code = 3
+
+        result = runner.invoke(app, ["run", str(project)])
+
+        assert result.exit_code == 3
+        assert "Expert exited with code 3" in result.output
+
+
+class TestBacktestCommand:
+    """Tests for the `backtest` command."""
+
+    def test_backtest_env(self, project, run_script):
+        """Test that the backtest runs the script with its mode, dates, capital and report."""
+        result = runner.invoke(
+            app,
+            [
+                "backtest",
+                str(project / "main.py"),
+                "--start-date",
+                "2024-01-01",
+                "--end-date",
+                "2024-02-01",
+                "--capital",
+                "5000",
+                "--fill-model",
+                "path=ohlc",
+                "--report-format",
+                "json",
+            ],
+        )
+
+        assert result.exit_code == 0, result.output
+        [(script, env)] = run_script.calls
+        assert script == (project / "main.py").resolve()
+        assert env[ENV_TRADE_MODE] == TRADE_MODE_BACKTEST
+        assert env[ENV_BACKTEST_START] == "2024-01-01"
+        assert env[ENV_BACKTEST_END] == "2024-02-01"
+        assert env[ENV_INITIAL_CAPITAL] == "5000.0"
+        assert env[ENV_FILL_MODEL] == "path=ohlc"
+        assert env["API_KEY"] == "abc"
+        report = Path(env[ENV_REPORT_FILE])
+        assert report.parent == project.resolve() / REPORT_DIRECTORY
+        assert report.suffix == ".json"
+
+    @pytest.mark.parametrize(
+        ("arguments", "message"),
+        [
+            (["--report-format", "pdf"], "Unsupported report format: pdf"),
+            (["--optimize", "--compare"], "cannot be combined"),
+            (["other.py"], "Several experts can only be backtested with --compare"),
+            (["--optimize"], "--optimize requires --optimize-params"),
+            (
+                ["--walk-forward", "--optimize-params", "x=1:2:1"],
+                "--walk-forward requires --start-date and --end-date",
+            ),
+            (["--fill-model", "slippage=unknown"], "Unknown slippage model"),
+            (["--optimize-params", "x=3:1:1"], "Invalid range of x"),
+        ],
+    )
+    def test_invalid_options_exit_with_an_error(
+        self, project, run_script, arguments, message
+    ):
+        """Test that invalid options exit with code 1 before running the expert."""
+        result = runner.invoke(app, ["backtest", str(project / "main.py"), *arguments])
+
+        assert result.exit_code == 1
+        assert message in result.output
+        assert run_script.calls == []
+
+    def test_missing_script_exits_with_an_error(self, tmp_path, run_script):
+        """Test that a missing expert script exits with code 1."""
+        result = runner.invoke(app, ["backtest", str(tmp_path / "main.py")])
+
+        assert result.exit_code == 1
+        assert "Expert script not found" in result.output
+
+    def test_failed_backtest_exits_with_its_code(self, project, run_script):
+        """Test that a failing backtest exits the command with the expert exit code."""
+        run_script.code = 2
+
+        result = runner.invoke(app, ["backtest", str(project / "main.py")])
+
+        assert result.exit_code == 2
+        assert "Backtest exited with code 2" in result.output
+
+    def test_missing_report_exits_with_an_error(self, project, run_script):
+        """Test that a backtest without a report exits with code 1."""
+        run_script.writes_report = False
+
+        result = runner.invoke(app, ["backtest", str(project / "main.py")])
+
+        assert result.exit_code == 1
+        assert "did not produce a backtest report" in result.output
@@ -0,0 +1,79 @@
+"""Unit tests for the CLI project templates and process helpers."""
+
+import pytest
+
+from metaexpert.cli.core.process import load_env_file
+from metaexpert.cli.core.templates import STRATEGIES, create_project, render_project
+
+
+class TestTemplates:
+    """Tests for the expert project templates."""
+
+    def test_render_project_fills_parameters(self):
+        """Test that the project files are rendered with the project settings."""
+        files = render_project(
+            "My Bot", strategy="rsi", exchange="OKX", market_type="spot"
+        )
+
+        main = files["main.py"]
+        assert 'exchange="okx",' in main
+        assert 'market_type="spot",' in main
+        assert 'strategy_name="My Bot",' in main
+        assert 'os.getenv("OKX_API_PASSPHRASE")' in main
+        assert "ALLOW_SHORT = False" in main
+        assert "def rsi(" in main
+        assert 'OKX_API_PASSPHRASE="' in files[".env.example"]
+        assert 'name = "my-bot"' in files["pyproject.toml"]
+        assert files["README.md"].startswith("# My Bot")
+
+    def test_every_strategy_renders_valid_python(self):
+        """Test that every strategy template produces a valid script."""
+        for strategy in STRATEGIES:
+            files = render_project("bot", strategy=strategy)
+            compile(files["main.py"], "main.py", "exec")
+
+    def test_render_project_rejects_unknown_settings(self):
+        """Test that unknown strategies and exchanges are rejected."""
+        with pytest.raises(ValueError):
+            render_project("bot", strategy="unknown")
+        with pytest.raises(ValueError):
+            render_project("bot", exchange="unknown")
+
+    def test_create_project_refuses_overwrite(self, tmp_path):
+        """Test that an existing project is only replaced with force."""
+        project = create_project("bot", tmp_path, strategy="ema")
+        for file_name in ("main.py", "pyproject.toml", ".env.example", "README.md"):
+            assert (project / file_name).is_file()
+
+        with pytest.raises(FileExistsError):
+            create_project("bot", tmp_path)
+
+        (project / ".env").write_text("API_KEY=abc")
+        (project / "main.py").write_text("edited")
+        create_project("bot", tmp_path, force=True)
+        assert (project / ".env").read_text() == "API_KEY=abc"
+        assert (project / "main.py").read_text() != "edited"
+
+    @pytest.mark.parametrize("name", [".", "..", "../bot", "a/b", "/", ""])
+    def test_create_project_rejects_paths(self, tmp_path, name):
+        """Test that a name that is not a plain directory name is rejected."""
+        with pytest.raises(ValueError):
+            create_project(name, tmp_path / "projects", force=True)
+        assert not (tmp_path / "projects").exists()
+
+
+class TestProcess:
+    """Tests for the expert process helpers."""
+
+    def test_load_env_file(self, tmp_path):
+        """Test that comments are skipped and quotes are stripped."""
+        env_file = tmp_path / ".env"
+        env_file.write_text(
+            "# comment\n\nAPI_KEY='abc'\nexport LOG_LEVEL=INFO\nURL=http://x?a=b\n"
+        )
+
+        assert load_env_file(env_file) == {
+            "API_KEY": "abc",
+            "LOG_LEVEL": "INFO",
+            "URL": "http://x?a=b",
+        }
@@ -0,0 +1,36 @@
+"""Shared fixtures of the unit tests."""
+
+from collections.abc import Callable
+from datetime import UTC, datetime, timedelta
+
+import pytest
+
+from metaexpert.core import Candle, Timeframe
+
+START = datetime(2024, 1, 1, tzinfo=UTC)
+
+
+@pytest.fixture
+def make_candle() -> Callable[..., Candle]:
+    """Factory of one-hour BTCUSDT candles, the index counting hours from 2024-01-01."""
+
+    def make(
+        index: int,
+        open_: float,
+        high: float,
+        low: float,
+        close: float,
+        volume: float = 1.0,
+    ) -> Candle:
+        return Candle(
+            symbol="BTCUSDT",
+            timeframe=Timeframe.H1,
+            open_time=START + timedelta(hours=index),
+            open=open_,
+            high=high,
+            low=low,
+            close=close,
+            volume=volume,
+        )
+
+    return make
@@ -0,0 +1,110 @@
+"""Unit tests for the candle-aligned bar scheduler."""
+
+import asyncio
+from datetime import UTC, datetime, timedelta
+
+from metaexpert.core import Bar, EventType, Timeframe
+from metaexpert.core import _bar
+
+START = datetime(2024, 1, 1, 0, 0, 30, tzinfo=UTC)
+
+
+class FakeClock:
+    """Wall clock advanced by the scheduler sleeps, with optional lateness."""
+
+    def __init__(self, now: datetime, lateness: list[float] | None = None) -> None:
+        self.now = now
+        self.lateness = lateness or []
+        self.wake_ups: list[datetime] = []
+
+    async def sleep_until(self, time: datetime, max_sleep: float) -> None:
+        late = self.lateness.pop(0) if self.lateness else 0.0
+        self.now = time + timedelta(seconds=late)
+        self.wake_ups.append(self.now)
+
+    def install(self, monkeypatch) -> None:
+        clock = self
+
+        class FakeDatetime(datetime):
+            @classmethod
+            def now(cls, tz=None):
+                return clock.now
+
+        monkeypatch.setattr(_bar, "datetime", FakeDatetime)
+        monkeypatch.setattr(_bar, "sleep_until", self.sleep_until)
+
+
+def run_bar(bar: Bar, closed: list[datetime], count: int) -> None:
+    """Run a scheduler until it reported `count` closed candles."""
+
+    def on_close(open_time: datetime) -> None:
+        closed.append(open_time)
+        if len(closed) == count:
+            bar.stop()
+
+    bar._func = on_close
+    asyncio.run(bar.start())
+
+
+class TestBar:
+    """Tests for the bar scheduler."""
+
+    def test_wakes_up_after_every_candle_close(self, monkeypatch):
+        """Test that the callback runs once per candle, after the close delay."""
+        clock = FakeClock(START)
+        clock.install(monkeypatch)
+        closed: list[datetime] = []
+
+        run_bar(Bar("1m", delay=2.0), closed, 3)
+
+        assert closed == [
+            START.replace(second=0) + timedelta(minutes=index) for index in range(3)
+        ]
+        assert clock.wake_ups[0] == datetime(2024, 1, 1, 0, 1, 2, tzinfo=UTC)
+        assert clock.wake_ups[2] == datetime(2024, 1, 1, 0, 3, 2, tzinfo=UTC)
+
+    def test_late_wake_up_reports_the_last_closed_candle(self, monkeypatch):
+        """Test that a wake-up late by several candles reports the last one and realigns."""
+        clock = FakeClock(START, lateness=[150.0])
+        clock.install(monkeypatch)
+        closed: list[datetime] = []
+
+        run_bar(Bar("1m", delay=0.0), closed, 2)
+
+        assert closed == [
+            datetime(2024, 1, 1, 0, 2, tzinfo=UTC),
+            datetime(2024, 1, 1, 0, 3, tzinfo=UTC),
+        ]
+        assert clock.wake_ups[1] == datetime(2024, 1, 1, 0, 4, tzinfo=UTC)
+
+    def test_failing_callback_does_not_stop_the_scheduler(self, monkeypatch):
+        """Test that a callback error is reported and the next candle still scheduled."""
+        FakeClock(START).install(monkeypatch)
+        errors: list[Exception] = []
+        closed: list[datetime] = []
+        bar = Bar("1m", delay=0.0)
+
+        def on_close(open_time: datetime) -> None:
+            closed.append(open_time)
+            if len(closed) == 1:
+                raise RuntimeError("klines unavailable")
+            bar.stop()
+
+        bar._func = on_close
+        EventType.ON_ERROR.value["callback"].append(errors.append)
+        try:
+            asyncio.run(bar.start())
+        finally:
+            EventType.ON_ERROR.value["callback"].remove(errors.append)
+
+        assert len(closed) == 2
+        assert [str(error) for error in errors] == ["klines unavailable"]
+
+    def test_next_candle_time_is_utc(self):
+        """Test that the next candle time is an aware UTC boundary."""
+        next_time = Timeframe.H4.get_next_candle_time(START)
+
+        assert next_time == datetime(2024, 1, 1, 4, tzinfo=UTC)
+        assert Timeframe.W1.get_next_candle_time(START) == datetime(
+            2024, 1, 8, tzinfo=UTC
+        )
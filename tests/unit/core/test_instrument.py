@@ -0,0 +1,89 @@
+"""Unit tests for the instruments and their registry."""
+
+import pytest
+
+from metaexpert.core import (
+    Instrument,
+    InstrumentRegistry,
+    InstrumentStatus,
+    InvalidOrderError,
+    Trade,
+    UnsupportedPairError,
+)
+
+BTCUSDT = Instrument(
+    symbol="BTCUSDT",
+    base_asset="BTC",
+    quote_asset="USDT",
+    settlement_asset="USDT",
+    tick_size=0.1,
+    lot_step=0.001,
+    min_quantity=0.001,
+    max_quantity=100.0,
+    min_notional=5.0,
+    max_leverage=125,
+)
+
+
+class TestInstrument:
+    """Tests for Instrument."""
+
+    def test_order_is_rounded(self):
+        """Test that prices are rounded to the tick and quantities down to the lot step."""
+        order = Trade.create_order("BTCUSDT", "buy", "limit", 0.0129, price=42000.06)
+        BTCUSDT.prepare_order(order)
+        assert order.quantity == pytest.approx(0.012)
+        assert order.price == pytest.approx(42000.1)
+
+    def test_order_breaking_the_rules_is_rejected(self):
+        """Test that orders below the minimums or on a halted symbol are rejected."""
+        with pytest.raises(InvalidOrderError):
+            BTCUSDT.prepare_order(
+                Trade.create_order("BTCUSDT", "buy", "limit", 0.0001, price=42000.0)
+            )
+        with pytest.raises(InvalidOrderError):
+            BTCUSDT.prepare_order(
+                Trade.create_order("BTCUSDT", "buy", "limit", 0.001, price=1000.0)
+            )
+        # Reduce-only orders are accepted below the minimum notional
+        BTCUSDT.prepare_order(
+            Trade.create_order(
+                "BTCUSDT", "sell", "limit", 0.001, price=1000.0, reduce_only=True
+            )
+        )
+
+        halted = Instrument(
+            "ETHUSDT", "ETH", "USDT", "USDT", status=InstrumentStatus.HALTED
+        )
+        with pytest.raises(InvalidOrderError):
+            halted.prepare_order(Trade.create_order("ETHUSDT", "buy", "market", 1.0))
+
+
+class TestInstrumentRegistry:
+    """Tests for InstrumentRegistry."""
+
+    def test_instruments_are_cached(self):
+        """Test that the instruments are loaded once and unknown symbols are rejected."""
+        calls = []
+
+        def loader():
+            calls.append(1)
+            return [BTCUSDT]
+
+        registry = InstrumentRegistry(loader)
+        assert registry.get("btcusdt") is BTCUSDT
+        assert "BTCUSDT" in registry
+        with pytest.raises(UnsupportedPairError):
+            registry.get("XYZUSDT")
+        assert len(calls) == 1
+
+        registry.ttl = -1.0
+        registry.get("BTCUSDT")
+        assert len(calls) == 2
+
+    def test_unsupported_exchange_skips_the_checks(self):
+        """Test that orders pass unchanged when the exchange does not publish instruments."""
+        registry = InstrumentRegistry(lambda: None)
+        order = Trade.create_order("XYZUSDT", "buy", "market", 0.12345)
+        assert registry.get("XYZUSDT") is None
+        assert registry.prepare_order(order).quantity == 0.12345
@@ -0,0 +1,106 @@
+"""Unit tests for the order model."""
+
+import pytest
+
+from metaexpert.core import (
+    InvalidOrderError,
+    Order,
+    OrderSide,
+    OrderStatus,
+    OrderType,
+    TimeInForce,
+    Trade,
+)
+
+
+class TestCreateOrder:
+    """Tests for Trade.create_order."""
+
+    def test_create_order_from_strings(self):
+        """Test that string parameters are converted into enumerations."""
+        order = Trade.create_order(
+            "BTCUSDT", "buy", "limit", 0.5, price=30000, time_in_force="ioc"
+        )
+
+        assert order.side is OrderSide.BUY
+        assert order.type is OrderType.LIMIT
+        assert order.time_in_force is TimeInForce.IOC
+        assert order.status is OrderStatus.NEW
+        assert order.is_open
+
+    def test_create_order_unknown_side(self):
+        """Test that an unknown side is rejected instead of defaulted."""
+        with pytest.raises(ValueError):
+            Trade.create_order("BTCUSDT", "long", "market", 1)
+
+    def test_create_order_unknown_type_and_time_in_force(self):
+        """Test that unknown order types and times in force raise instead of defaulting."""
+        order = Trade.create_order(
+            "BTCUSDT", "sell", "stop-limit", 1.0, price=95, stop_price=96
+        )
+        assert order.type is OrderType.STOP_LIMIT
+
+        with pytest.raises(ValueError):
+            Trade.create_order("BTCUSDT", "buy", "stop_lmit", 1.0, price=100)
+        with pytest.raises(ValueError):
+            Trade.create_order(
+                "BTCUSDT", "buy", "limit", 1.0, price=100, time_in_force="day"
+            )
+
+    def test_create_order_limit_requires_price(self):
+        """Test that limit orders require a price."""
+        with pytest.raises(InvalidOrderError):
+            Trade.create_order("BTCUSDT", "buy", "limit", 1)
+
+    def test_create_order_stop_requires_stop_price(self):
+        """Test that stop orders require a stop price."""
+        with pytest.raises(InvalidOrderError):
+            Trade.create_order("BTCUSDT", "sell", "stop", 1)
+
+    def test_create_order_post_only_market(self):
+        """Test that post-only is rejected for market orders."""
+        with pytest.raises(InvalidOrderError):
+            Trade.create_order("BTCUSDT", "buy", "market", 1, post_only=True)
+
+    def test_create_order_non_positive_quantity(self):
+        """Test that the quantity must be positive."""
+        with pytest.raises(InvalidOrderError):
+            Trade.create_order("BTCUSDT", "buy", "market", 0)
+
+
+class TestOrder:
+    """Tests for the Order dataclass."""
+
+    def test_remaining_quantity(self):
+        """Test that the remaining quantity accounts for partial fills."""
+        order = Order(
+            symbol="BTCUSDT",
+            side=OrderSide.SELL,
+            type=OrderType.MARKET,
+            quantity=2.0,
+            status=OrderStatus.PARTIALLY_FILLED,
+            filled_quantity=0.5,
+        )
+
+        assert order.remaining_quantity == 1.5
+        assert order.is_open
+        assert not order.is_filled
+
+    def test_to_dict(self):
+        """Test the dictionary representation used in event payloads."""
+        order = Order(
+            symbol="ETHUSDT",
+            side=OrderSide.BUY,
+            type=OrderType.STOP_LIMIT,
+            quantity=1.0,
+            price=2000.0,
+            stop_price=1990.0,
+        )
+
+        data = order.to_dict()
+
+        assert data["side"] == "buy"
+        assert data["type"] == "stop_limit"
+        assert data["status"] == "new"
+        assert data["price"] == 2000.0
+        assert data["stop_price"] == 1990.0
@@ -0,0 +1,99 @@
+"""Unit tests for the local order book."""
+
+import zlib
+from datetime import UTC, datetime
+
+import pytest
+
+from metaexpert.core import LocalOrderBook, OrderBookOutOfSyncError, OrderBookUpdate
+from metaexpert.exchanges.okx import Adapter as OKXAdapter
+
+TIME = datetime(2024, 1, 1, tzinfo=UTC)
+
+
+def make_update(
+    bids: list[tuple[str, str]] | None = None,
+    asks: list[tuple[str, str]] | None = None,
+    **kwargs,
+) -> OrderBookUpdate:
+    """Create a BTCUSDT book update."""
+    return OrderBookUpdate(
+        symbol="BTCUSDT",
+        bids=tuple(bids or []),
+        asks=tuple(asks or []),
+        time=TIME,
+        **kwargs,
+    )
+
+
+def make_snapshot(last_id: int = 100) -> OrderBookUpdate:
+    """Create a snapshot with two levels per side."""
+    return make_update(
+        [("99.5", "2"), ("99.0", "1")],
+        [("100.5", "1"), ("101.0", "3")],
+        is_snapshot=True,
+        last_id=last_id,
+    )
+
+
+class TestLocalOrderBook:
+    """Tests for the local order book."""
+
+    def test_diffs_update_the_top_levels(self):
+        """Test that diffs replace and remove levels and the spread follows."""
+        book = LocalOrderBook("BTCUSDT")
+        book.apply(make_snapshot())
+        book.apply(
+            make_update(
+                [("99.5", "0"), ("99.8", "4")],
+                [("100.5", "0")],
+                first_id=99,
+                last_id=101,
+            )
+        )
+
+        order_book = book.get_order_book(depth=1)
+
+        assert order_book.bids == ((99.8, 4.0),)
+        assert order_book.asks == ((101.0, 3.0),)
+        assert order_book.spread == pytest.approx(1.2)
+        assert order_book.spread_pct == pytest.approx(1.2 / 100.4 * 100)
+        assert order_book.sequence == 101
+
+    def test_old_diffs_are_ignored_and_gaps_raise(self):
+        """Test that diffs within the snapshot are skipped and a gap is detected."""
+        book = LocalOrderBook("BTCUSDT")
+        book.apply(make_snapshot())
+
+        assert not book.apply(make_update([("99.5", "9")], first_id=90, last_id=100))
+        assert book.apply(make_update(first_id=101, last_id=105))
+        with pytest.raises(OrderBookOutOfSyncError):
+            book.apply(make_update(first_id=107, last_id=110))
+        assert book.get_order_book().bids[0] == (99.5, 2.0)
+
+    def test_previous_id_is_checked_after_the_first_diff(self):
+        """Test that diffs carrying the previous update id must chain exactly."""
+        book = LocalOrderBook("BTCUSDT")
+        book.apply(make_snapshot())
+        book.apply(make_update(first_id=95, last_id=103, prev_id=94))
+        book.apply(make_update(first_id=104, last_id=106, prev_id=103))
+
+        with pytest.raises(OrderBookOutOfSyncError):
+            book.apply(make_update(first_id=108, last_id=109, prev_id=107))
+
+    def test_checksum_mismatch_raises(self):
+        """Test that the OKX checksum of the top levels is verified."""
+        adapter = OKXAdapter.__new__(OKXAdapter)
+        book = LocalOrderBook("BTCUSDT", adapter.compute_book_checksum)
+        bids = [("99.5", "2")]
+        asks = [("100.5", "1")]
+        checksum = adapter.compute_book_checksum(bids, asks)
+        assert checksum == zlib.crc32(b"99.5:2:100.5:1")
+
+        book.apply(
+            make_update(bids, asks, is_snapshot=True, last_id=1, checksum=checksum)
+        )
+        with pytest.raises(OrderBookOutOfSyncError):
+            book.apply(
+                make_update([("99.6", "1")], last_id=2, prev_id=1, checksum=checksum)
+            )
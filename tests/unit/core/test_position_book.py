@@ -0,0 +1,155 @@
+"""Unit tests for the position book."""
+
+import pytest
+
+from metaexpert.core import (
+    ContractType,
+    OrderSide,
+    Position,
+    PositionBook,
+    PositionMode,
+    PositionSide,
+)
+
+
+class TestOneWayPositionBook:
+    """Tests for the position book in one-way mode."""
+
+    def test_apply_fill_opens_and_averages(self):
+        """Test that fills in the same direction average the entry price."""
+        book = PositionBook(PositionMode.ONEWAY)
+
+        book.apply_fill("BTCUSDT", OrderSide.BUY, 1.0, 100.0)
+        position, realized = book.apply_fill("BTCUSDT", OrderSide.BUY, 1.0, 200.0)
+
+        assert position.side is PositionSide.LONG
+        assert position.size == 2.0
+        assert position.entry_price == 150.0
+        assert realized == 0.0
+
+    def test_apply_fill_reduces_and_realizes(self):
+        """Test that an opposite fill reduces the position and realizes PnL."""
+        book = PositionBook(PositionMode.ONEWAY)
+        book.apply_fill("BTCUSDT", OrderSide.BUY, 2.0, 100.0)
+
+        position, realized = book.apply_fill("BTCUSDT", OrderSide.SELL, 1.0, 110.0)
+
+        assert position.size == 1.0
+        assert realized == 10.0
+        assert position.realized_pnl == 10.0
+
+    def test_apply_fill_flips_position(self):
+        """Test that an oversized opposite fill flips the net position."""
+        book = PositionBook(PositionMode.ONEWAY)
+        book.apply_fill("BTCUSDT", OrderSide.BUY, 1.0, 100.0)
+
+        position, realized = book.apply_fill("BTCUSDT", OrderSide.SELL, 3.0, 90.0)
+
+        assert realized == -10.0
+        assert position.side is PositionSide.SHORT
+        assert position.size == 2.0
+        assert position.entry_price == 90.0
+        assert book.get_net_size("BTCUSDT") == -2.0
+        assert len(book.get_positions("BTCUSDT")) == 1
+
+    def test_flat_sync_clears_the_opposite_side(self):
+        """Test that a flat exchange position closes the net position of the other side."""
+        book = PositionBook(PositionMode.ONEWAY)
+        book.apply_fill("BTCUSDT", OrderSide.SELL, 1.0, 100.0)
+
+        book.sync(Position(symbol="BTCUSDT", side=PositionSide.LONG, size=0.0))
+
+        assert not book.get_positions("BTCUSDT")
+        assert book.get_net_size("BTCUSDT") == 0.0
+
+    def test_apply_fill_reduce_only_without_position(self):
+        """Test that a reduce-only fill without a position is rejected."""
+        book = PositionBook(PositionMode.ONEWAY)
+
+        with pytest.raises(ValueError):
+            book.apply_fill("BTCUSDT", OrderSide.SELL, 1.0, 100.0, reduce_only=True)
+
+
+class TestHedgePositionBook:
+    """Tests for the position book in hedge mode."""
+
+    def test_long_and_short_are_independent(self):
+        """Test that long and short positions coexist in hedge mode."""
+        book = PositionBook(PositionMode.HEDGE)
+
+        book.apply_fill("ETHUSDT", OrderSide.BUY, 2.0, 100.0)
+        book.apply_fill("ETHUSDT", OrderSide.SELL, 1.0, 100.0)
+
+        assert book.get_position("ETHUSDT", PositionSide.LONG).size == 2.0
+        assert book.get_position("ETHUSDT", PositionSide.SHORT).size == 1.0
+        assert book.get_net_size("ETHUSDT") == 1.0
+
+    def test_reduce_only_closes_matching_side(self):
+        """Test that a reduce-only sell closes the long position."""
+        book = PositionBook(PositionMode.HEDGE)
+        book.apply_fill("ETHUSDT", OrderSide.BUY, 2.0, 100.0)
+
+        position, realized = book.apply_fill(
+            "ETHUSDT", OrderSide.SELL, 2.0, 120.0, reduce_only=True
+        )
+
+        assert position.side is PositionSide.LONG
+        assert not position.is_open
+        assert realized == 40.0
+        assert book.get_positions() == []
+
+    def test_exposure_uses_mark_price(self):
+        """Test that exposure follows the mark price."""
+        book = PositionBook(PositionMode.HEDGE)
+        book.apply_fill("ETHUSDT", OrderSide.BUY, 1.0, 100.0)
+        book.apply_fill("BTCUSDT", OrderSide.SELL, 1.0, 50.0)
+
+        book.update_mark_price("ETHUSDT", 110.0)
+
+        assert book.get_exposure("ETHUSDT") == 110.0
+        assert book.get_exposure() == 60.0
+        assert book.get_position("ETHUSDT").unrealized_pnl == 10.0
+
+
+class TestInversePositionBook:
+    """Tests for the position book of inverse contracts."""
+
+    def test_pnl_is_coin_margined(self):
+        """Test that inverse fills average harmonically and realize PnL in the coin."""
+        book = PositionBook(PositionMode.ONEWAY, ContractType.INVERSE)
+        book.apply_fill("BTCUSD", OrderSide.BUY, 1000.0, 20000.0)
+        position, _ = book.apply_fill("BTCUSD", OrderSide.BUY, 1000.0, 40000.0)
+
+        assert position.entry_price == pytest.approx(80000 / 3)
+        assert position.notional == 2000.0
+        assert position.margin == pytest.approx(0.075)
+
+        _, realized = book.apply_fill("BTCUSD", OrderSide.SELL, 2000.0, 40000.0)
+
+        assert realized == pytest.approx(0.025)
+
+    def test_liquidation_price(self):
+        """Test that the liquidation price leaves the maintenance margin of the position."""
+        long = Position("BTCUSDT", PositionSide.LONG, 1.0, 100.0, leverage=10)
+        short = Position("BTCUSDT", PositionSide.SHORT, 1.0, 100.0, leverage=10)
+        inverse = Position(
+            "BTCUSD",
+            PositionSide.LONG,
+            1000.0,
+            20000.0,
+            leverage=10,
+            contract_type=ContractType.INVERSE,
+        )
+
+        assert long.get_liquidation_price(long.margin, 0.005) == pytest.approx(
+            90 / 0.995
+        )
+        assert short.get_liquidation_price(short.margin, 0.005) == pytest.approx(
+            110 / 1.005
+        )
+        price = inverse.get_liquidation_price(inverse.margin, 0.005)
+        assert inverse.margin + inverse.get_pnl(price) == pytest.approx(
+            0.005 * inverse.get_value(price)
+        )
+        # Fully collateralized positions are never liquidated
+        assert long.get_liquidation_price(100.0, 0.005) is None
@@ -0,0 +1,56 @@
+"""Unit tests for the funding rate sources."""
+
+from datetime import UTC, datetime
+
+import pytest
+
+from metaexpert.core import InvalidDataError, MissingDataError
+from metaexpert.data import CSVFundingSource
+
+
+def write_csv(path, lines):
+    """Write CSV lines to a file."""
+    path.write_text("\n".join(lines) + "\n", encoding="utf-8")
+
+
+class TestCSVFundingSource:
+    """Tests for the CSV funding source."""
+
+    def test_load_range(self, tmp_path):
+        """Test that the funding rates of the range are loaded, with their optional mark price."""
+        write_csv(
+            tmp_path / "BTCUSDT.csv",
+            [
+                "time,rate,mark_price",
+                "1704067200.0,0.0001,42000",
+                "1704096000000,-0.0002,",
+                "2024-01-01 16:00,0.0003,42500",
+            ],
+        )
+        source = CSVFundingSource(tmp_path / "{symbol}.csv")
+
+        rates = source.load("btcusdt", "2024-01-01T08:00:00", "2024-01-02")
+
+        assert [rate.time for rate in rates] == [
+            datetime(2024, 1, 1, 8, tzinfo=UTC),
+            datetime(2024, 1, 1, 16, tzinfo=UTC),
+        ]
+        assert [rate.rate for rate in rates] == [-0.0002, 0.0003]
+        assert [rate.mark_price for rate in rates] == [None, 42500.0]
+        assert rates[0].symbol == "BTCUSDT"
+        assert len(source.load("BTCUSDT", "2024-01-01")) == 3
+
+    def test_invalid_files(self, tmp_path):
+        """Test that missing files and columns and unordered rows are rejected."""
+        write_csv(tmp_path / "NORATE.csv", ["time,value", "2024-01-01,0.0001"])
+        write_csv(
+            tmp_path / "UNORDERED.csv",
+            ["time,rate", "2024-01-01T08:00,0.0001", "2024-01-01T00:00,0.0001"],
+        )
+        source = CSVFundingSource(tmp_path / "{symbol}.csv")
+
+        for symbol in ("MISSING", "NORATE"):
+            with pytest.raises(MissingDataError):
+                source.load(symbol, "2024-01-01")
+        with pytest.raises(InvalidDataError):
+            source.load("UNORDERED", "2024-01-01")
@@ -0,0 +1,81 @@
+"""Unit tests for the historical data loader and its cache."""
+
+from datetime import UTC, datetime, timedelta
+from types import SimpleNamespace
+
+from metaexpert.core import Candle, MarketType, Timeframe
+from metaexpert.data import CandleCache, DataLoader
+
+START = datetime(2024, 1, 1, tzinfo=UTC)
+
+
+class FakeExchange:
+    """Exchange returning a continuous series of hourly candles."""
+
+    exchange = "binance"
+    market_type = MarketType.FUTURES
+    kline_limit = 10
+
+    def __init__(self):
+        self.requests: list[tuple[datetime, datetime]] = []
+
+    def get_klines(self, symbol, timeframe, start, end, limit):
+        self.requests.append((start, end))
+        candles = []
+        time = start
+        while time < end and len(candles) < limit:
+            candles.append(
+                Candle(symbol, timeframe, time, 1.0, 2.0, 0.5, 1.5, volume=10.0)
+            )
+            time += timeframe.get_delta()
+        return candles
+
+
+class TestDataLoader:
+    """Tests for the data loader."""
+
+    def test_load_pages_through_limit(self, tmp_path):
+        """Test that a range larger than the klines limit is fetched in pages."""
+        exchange = FakeExchange()
+        loader = DataLoader(exchange, CandleCache(tmp_path))
+
+        candles = loader.load("btcusdt", "1h", START, START + timedelta(hours=25))
+
+        assert len(candles) == 25
+        assert len(exchange.requests) == 3
+        assert candles[0].open_time == START
+        assert candles[-1].open_time == START + timedelta(hours=24)
+
+    def test_load_fetches_only_missing_ranges(self, tmp_path):
+        """Test that cached ranges are read from disk and only the rest is fetched."""
+        exchange = FakeExchange()
+        DataLoader(exchange, CandleCache(tmp_path)).load(
+            "BTCUSDT", Timeframe.H1, START, START + timedelta(hours=5)
+        )
+
+        exchange.requests.clear()
+        loader = DataLoader(exchange, CandleCache(tmp_path))
+        candles = loader.load(
+            "BTCUSDT", Timeframe.H1, START, START + timedelta(hours=8)
+        )
+
+        assert len(candles) == 8
+        assert exchange.requests == [
+            (START + timedelta(hours=5), START + timedelta(hours=8))
+        ]
+        assert (tmp_path / "binance" / "futures" / "BTCUSDT" / "1h.csv").exists()
+
+    def test_load_works_offline_for_cached_range(self, tmp_path):
+        """Test that a fully cached range does not hit the exchange."""
+        DataLoader(FakeExchange(), CandleCache(tmp_path)).load(
+            "BTCUSDT", Timeframe.H1, START, START + timedelta(hours=5)
+        )
+        offline = SimpleNamespace(
+            exchange="binance", market_type=MarketType.FUTURES, kline_limit=10
+        )
+
+        candles = DataLoader(offline, CandleCache(tmp_path)).load_last(
+            "BTCUSDT", Timeframe.H1, 3, START + timedelta(hours=5)
+        )
+
+        assert [candle.open_time.hour for candle in candles] == [2, 3, 4]
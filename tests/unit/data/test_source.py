@@ -0,0 +1,126 @@
+"""Unit tests for the file bar sources."""
+
+from datetime import UTC, datetime
+
+import pytest
+
+from metaexpert.core import InvalidDataError, MissingDataError, Timeframe
+from metaexpert.data import CSVSource
+
+
+def write_csv(path, lines):
+    """Write CSV lines to a file."""
+    path.write_text("\n".join(lines) + "\n", encoding="utf-8")
+
+
+class TestCSVSource:
+    """Tests for the CSV bar source."""
+
+    def test_load_with_column_mapping_and_timezone(self, tmp_path):
+        """Test that mapped columns and local timestamps are converted to UTC candles."""
+        write_csv(
+            tmp_path / "BTCUSDT_1h.csv",
+            [
+                "Date;O;H;L;C;Vol",
+                "2024-01-01 03:00;100;110;95;105;12",
+                "2024-01-01 04:00;105;108;101;102;8",
+            ],
+        )
+        source = CSVSource(
+            tmp_path / "{symbol}_{timeframe}.csv",
+            delimiter=";",
+            columns={
+                "time": "Date",
+                "open": "O",
+                "high": "H",
+                "low": "L",
+                "close": "C",
+                "volume": "Vol",
+            },
+            timezone="Europe/Moscow",
+        )
+
+        candles = source.load("btcusdt", "1h", "2024-01-01")
+
+        assert len(candles) == 2
+        assert candles[0].open_time == datetime(2024, 1, 1, 0, tzinfo=UTC)
+        assert candles[0].timeframe is Timeframe.H1
+        assert candles[1].close == 102.0
+        assert candles[1].volume == 8.0
+
+    def test_load_filters_range_and_reads_timestamps(self, tmp_path):
+        """Test that millisecond timestamps are accepted and the range is applied."""
+        write_csv(
+            tmp_path / "data.csv",
+            [
+                "time,open,high,low,close,volume",
+                "1704067200000,1,2,1,2,1",
+                "1704070800000,2,3,2,3,1",
+                "1704074400000,3,4,3,4,1",
+            ],
+        )
+        source = CSVSource(tmp_path / "data.csv")
+
+        candles = source.load(
+            "BTCUSDT", Timeframe.H1, "2024-01-01T01:00", "2024-01-01T02:00"
+        )
+
+        assert [candle.close for candle in candles] == [3.0]
+
+    def test_load_reads_timestamps_of_any_unit(self, tmp_path):
+        """Test that decimal seconds, microseconds and nanoseconds are accepted."""
+        write_csv(
+            tmp_path / "data.csv",
+            [
+                "time,open,high,low,close,volume",
+                "1704067200.0,1,2,1,2,1",
+                "1704070800000000,2,3,2,3,1",
+                "1704074400000000000,3,4,3,4,1",
+            ],
+        )
+        source = CSVSource(tmp_path / "data.csv")
+
+        candles = source.load("BTCUSDT", Timeframe.H1, "2024-01-01")
+
+        assert [candle.open_time.hour for candle in candles] == [0, 1, 2]
+        assert candles[0].open_time == datetime(2024, 1, 1, tzinfo=UTC)
+
+    def test_gap_raises_missing_data(self, tmp_path):
+        """Test that a missing candle raises MissingDataError unless gaps are allowed."""
+        lines = [
+            "time,open,high,low,close",
+            "2024-01-01T00:00:00,1,2,1,2",
+            "2024-01-01T02:00:00,2,3,2,3",
+        ]
+        write_csv(tmp_path / "data.csv", lines)
+
+        with pytest.raises(MissingDataError):
+            CSVSource(tmp_path / "data.csv").load("BTCUSDT", "1h", "2024-01-01")
+
+        source = CSVSource(tmp_path / "data.csv", allow_gaps=True)
+        assert len(source.load("BTCUSDT", "1h", "2024-01-01")) == 2
+
+    @pytest.mark.parametrize(
+        "second_time",
+        ["2024-01-01T00:00:00", "2023-12-31T23:00:00", "2024-01-01T01:30:00"],
+    )
+    def test_invalid_rows_raise_invalid_data(self, tmp_path, second_time):
+        """Test that duplicate, out-of-order and misaligned rows raise InvalidDataError."""
+        write_csv(
+            tmp_path / "data.csv",
+            [
+                "time,open,high,low,close",
+                "2024-01-01T00:00:00,1,2,1,2",
+                f"{second_time},2,3,2,3",
+            ],
+        )
+
+        with pytest.raises(InvalidDataError):
+            CSVSource(tmp_path / "data.csv").load("BTCUSDT", "1h", "2023-01-01")
+
+    def test_missing_column_raises_missing_data(self, tmp_path):
+        """Test that a missing required column raises MissingDataError."""
+        write_csv(tmp_path / "data.csv", ["time,open,high,low", "2024-01-01,1,2,1"])
+
+        with pytest.raises(MissingDataError):
+            CSVSource(tmp_path / "data.csv").load("BTCUSDT", "1h", "2024-01-01")
@@ -0,0 +1,119 @@
+"""Unit tests for the order requests of the Binance adapter."""
+
+from types import SimpleNamespace
+
+import pytest
+
+from metaexpert.core import ContractType, MarketType, PositionMode
+from metaexpert.exchanges.binance import Adapter as BinanceAdapter
+
+EXCHANGE_INFO = {
+    "symbols": [
+        {
+            "symbol": "BTCUSDT",
+            "status": "TRADING",
+            "baseAsset": "BTC",
+            "quoteAsset": "USDT",
+            "marginAsset": "USDT",
+            "filters": [
+                {"filterType": "PRICE_FILTER", "tickSize": "0.10"},
+                {"filterType": "LOT_SIZE", "stepSize": "0.001", "minQty": "0.001"},
+            ],
+        }
+    ]
+}
+
+
+def make_adapter(position_mode: PositionMode, requests: list[dict]) -> BinanceAdapter:
+    """Create a futures adapter whose client records the order requests."""
+
+    def new_order(**params):
+        requests.append(params)
+        return {"orderId": 1, "status": "NEW", "type": params["type"]}
+
+    adapter = BinanceAdapter.__new__(BinanceAdapter)
+    adapter.exchange = "binance-orders-test"
+    adapter.market_type = MarketType.FUTURES
+    adapter.contract_type = ContractType.LINEAR
+    adapter.position_mode = position_mode
+    adapter.api_key = None
+    adapter.client = SimpleNamespace(
+        exchange_info=lambda: EXCHANGE_INFO, new_order=new_order
+    )
+    return adapter
+
+
+class TestBinancePlaceOrder:
+    """Tests for the futures order parameters of the Binance adapter."""
+
+    @pytest.mark.parametrize(
+        ("side", "reduce_only", "position_side"),
+        [
+            ("buy", False, "LONG"),
+            ("sell", False, "SHORT"),
+            ("sell", True, "LONG"),
+            ("buy", True, "SHORT"),
+        ],
+    )
+    def test_hedge_mode_sends_position_side(self, side, reduce_only, position_side):
+        """Test that hedge mode sends the position side instead of reduceOnly."""
+        requests: list[dict] = []
+        adapter = make_adapter(PositionMode.HEDGE, requests)
+
+        adapter.place_order("BTCUSDT", side, "market", 0.01, reduce_only=reduce_only)
+
+        assert requests[0]["positionSide"] == position_side
+        assert "reduceOnly" not in requests[0]
+
+    def test_oneway_mode_sends_reduce_only(self):
+        """Test that one-way mode sends reduceOnly and no position side."""
+        requests: list[dict] = []
+        adapter = make_adapter(PositionMode.ONEWAY, requests)
+
+        adapter.place_order("BTCUSDT", "sell", "market", 0.01, reduce_only=True)
+        adapter.place_order("BTCUSDT", "buy", "market", 0.01)
+
+        assert requests[0]["reduceOnly"] == "true"
+        assert "reduceOnly" not in requests[1]
+        assert all("positionSide" not in request for request in requests)
+
+
+class TestBinanceAccount:
+    """Tests for the account balances of the Binance adapter."""
+
+    @pytest.mark.parametrize(
+        ("market_type", "account", "balance"),
+        [
+            (
+                MarketType.SPOT,
+                {
+                    "balances": [
+                        {"asset": "USDT", "free": "90.5", "locked": "9.5"},
+                        {"asset": "BTC", "free": "0.00000000", "locked": "0"},
+                    ]
+                },
+                {"USDT": 100.0},
+            ),
+            (
+                MarketType.FUTURES,
+                {
+                    "assets": [
+                        {
+                            "asset": "USDT",
+                            "walletBalance": "250.0",
+                            "unrealizedProfit": "-10.0",
+                        },
+                        {"asset": "BNB", "walletBalance": "0.0"},
+                    ]
+                },
+                {"USDT": 250.0},
+            ),
+        ],
+    )
+    def test_balance_is_the_wallet_balance(self, market_type, account, balance):
+        """Test that the balance holds the wallet amount of every asset held."""
+        adapter = BinanceAdapter.__new__(BinanceAdapter)
+        adapter.market_type = market_type
+        adapter.client = SimpleNamespace(account=lambda: account)
+
+        assert adapter.get_balance() == balance
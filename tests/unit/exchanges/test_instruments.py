@@ -0,0 +1,78 @@
+"""Unit tests for the instrument loaders of the exchange adapters."""
+
+from types import SimpleNamespace
+
+import pytest
+
+from metaexpert.core import InstrumentStatus, MarketType, UnsupportedPairError
+from metaexpert.exchanges.binance import Adapter as BinanceAdapter
+
+EXCHANGE_INFO = {
+    "symbols": [
+        {
+            "symbol": "BTCUSDT",
+            "status": "TRADING",
+            "baseAsset": "BTC",
+            "quoteAsset": "USDT",
+            "marginAsset": "USDT",
+            "filters": [
+                {"filterType": "PRICE_FILTER", "tickSize": "0.10"},
+                {
+                    "filterType": "LOT_SIZE",
+                    "stepSize": "0.001",
+                    "minQty": "0.001",
+                    "maxQty": "1000",
+                },
+                {"filterType": "MIN_NOTIONAL", "notional": "100"},
+            ],
+        },
+        {
+            "symbol": "ETHUSDT",
+            "status": "SETTLING",
+            "baseAsset": "ETH",
+            "quoteAsset": "USDT",
+            "marginAsset": "USDT",
+            "filters": [],
+        },
+    ]
+}
+
+
+class TestBinanceInstruments:
+    """Tests for the Binance exchange info parser."""
+
+    def test_futures_exchange_info(self):
+        """Test that the filters, margin asset and leverage brackets are normalized."""
+        adapter = BinanceAdapter.__new__(BinanceAdapter)
+        adapter.market_type = MarketType.FUTURES
+        adapter.api_key = "key"
+        adapter.client = SimpleNamespace(
+            exchange_info=lambda: EXCHANGE_INFO,
+            leverage_brackets=lambda: [
+                {
+                    "symbol": "BTCUSDT",
+                    "brackets": [{"initialLeverage": 125}, {"initialLeverage": 100}],
+                }
+            ],
+        )
+
+        btc, eth = adapter.get_instruments()
+
+        assert (btc.tick_size, btc.lot_step, btc.min_quantity) == (0.1, 0.001, 0.001)
+        assert (btc.min_notional, btc.max_leverage) == (100.0, 125)
+        assert btc.settlement_asset == "USDT" and not btc.is_inverse
+        assert btc.status is InstrumentStatus.TRADING
+        assert eth.status is InstrumentStatus.DELISTED
+        assert eth.max_leverage == 0
+
+    def test_unknown_symbol_is_unsupported(self):
+        """Test that orders on a symbol missing from the exchange info are rejected."""
+        adapter = BinanceAdapter.__new__(BinanceAdapter)
+        adapter.exchange = "binance-test"
+        adapter.market_type = MarketType.SPOT
+        adapter.contract_type = None
+        adapter.api_key = None
+        adapter.client = SimpleNamespace(exchange_info=lambda: EXCHANGE_INFO)
+
+        with pytest.raises(UnsupportedPairError):
+            adapter.place_order("XYZUSDT", "buy", "market", 1.0)
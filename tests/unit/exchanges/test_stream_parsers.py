@@ -0,0 +1,319 @@
+"""Unit tests for the stream message parsers of the exchange adapters."""
+
+import json
+from datetime import UTC, datetime
+
+import pytest
+
+from metaexpert.core import (
+    AuthenticationError,
+    Balance,
+    Candle,
+    Fill,
+    MarketType,
+    Order,
+    OrderSide,
+    OrderStatus,
+    PositionSide,
+    Tick,
+    Timeframe,
+)
+from metaexpert.exchanges.binance import Adapter as BinanceAdapter
+from metaexpert.exchanges.bybit import Adapter as BybitAdapter
+from metaexpert.exchanges.kraken import Adapter as KrakenAdapter
+from metaexpert.exchanges.okx import Adapter as OKXAdapter
+
+OPEN_TIME = datetime(2024, 1, 1, tzinfo=UTC)
+OPEN_MS = int(OPEN_TIME.timestamp() * 1000)
+
+
+def make_adapter(cls, market_type=MarketType.SPOT):
+    """Create an adapter without connecting a client."""
+    adapter = cls.__new__(cls)
+    adapter.market_type = market_type
+    adapter.testnet = False
+    return adapter
+
+
+class TestStreamParsers:
+    """Tests for the stream message parsers."""
+
+    def test_binance_kline_and_trade(self):
+        """Test that Binance kline and trade events are normalized."""
+        adapter = make_adapter(BinanceAdapter)
+        kline = {
+            "e": "kline",
+            "s": "BTCUSDT",
+            "k": {
+                "t": OPEN_MS,
+                "s": "BTCUSDT",
+                "i": "1h",
+                "o": "100.0",
+                "h": "110.0",
+                "l": "95.0",
+                "c": "105.0",
+                "v": "12.5",
+                "x": True,
+            },
+        }
+        trade = {
+            "e": "trade",
+            "s": "BTCUSDT",
+            "p": "105.5",
+            "q": "0.1",
+            "T": OPEN_MS,
+            "m": True,
+        }
+
+        [candle] = adapter.parse_message(json.dumps({"stream": "s", "data": kline}))
+        [tick] = adapter.parse_message(json.dumps(trade))
+
+        assert candle == Candle(
+            "BTCUSDT", Timeframe.H1, OPEN_TIME, 100.0, 110.0, 95.0, 105.0, 12.5
+        )
+        assert tick == Tick("BTCUSDT", OPEN_TIME, 105.5, 0.1, OrderSide.SELL)
+        assert adapter.parse_message('{"result": null, "id": 1}') == []
+
+    def test_bybit_kline(self):
+        """Test that Bybit kline topics are subscribed and normalized."""
+        adapter = make_adapter(BybitAdapter)
+        message = {
+            "topic": "kline.60.BTCUSDT",
+            "type": "snapshot",
+            "data": [
+                {
+                    "start": OPEN_MS,
+                    "interval": "60",
+                    "open": "100",
+                    "high": "110",
+                    "low": "95",
+                    "close": "105",
+                    "volume": "3",
+                    "confirm": False,
+                }
+            ],
+        }
+
+        [candle] = adapter.parse_message(json.dumps(message))
+
+        assert adapter.get_subscribe_messages("BTCUSDT", "1h") == [
+            {"op": "subscribe", "args": ["kline.60.BTCUSDT"]}
+        ]
+        assert candle.timeframe is Timeframe.H1
+        assert candle.close == 105.0
+        assert not candle.is_closed
+        assert adapter.parse_message('{"success": true, "op": "subscribe"}') == []
+
+    def test_okx_candle(self):
+        """Test that OKX candlestick channels use instrument ids and the confirm flag."""
+        adapter = make_adapter(OKXAdapter, MarketType.FUTURES)
+        message = {
+            "arg": {"channel": "candle1H", "instId": "BTC-USDT-SWAP"},
+            "data": [[str(OPEN_MS), "100", "110", "95", "105", "7", "0", "0", "1"]],
+        }
+
+        [candle] = adapter.parse_message(json.dumps(message))
+
+        assert adapter.get_inst_id("BTCUSDT") == "BTC-USDT-SWAP"
+        assert candle.symbol == "BTCUSDT"
+        assert candle.open_time == OPEN_TIME
+        assert candle.is_closed
+
+    def test_kraken_candle_closes_on_next_interval(self):
+        """Test that a Kraken candle is reported closed when the next one starts."""
+        adapter = KrakenAdapter()
+
+        def update(begin: str, close: str) -> str:
+            item = {
+                "symbol": "BTC/USD",
+                "open": "100",
+                "high": "110",
+                "low": "95",
+                "close": close,
+                "volume": "1",
+                "interval_begin": begin,
+                "interval": 60,
+            }
+            return json.dumps({"channel": "ohlc", "type": "update", "data": [item]})
+
+        first = adapter.parse_message(update("2024-01-01T00:00:00.000000000Z", "101"))
+        second = adapter.parse_message(update("2024-01-01T00:00:00.000000000Z", "102"))
+        third = adapter.parse_message(update("2024-01-01T01:00:00.000000000Z", "103"))
+
+        assert [candle.is_closed for candle in first + second] == [False, False]
+        assert [(candle.close, candle.is_closed) for candle in third] == [
+            (102.0, True),
+            (103.0, False),
+        ]
+        assert third[0].symbol == "BTCUSD"
+
+    def test_depth_updates(self):
+        """Test that Binance and OKX depth messages keep their update ids."""
+        binance = make_adapter(BinanceAdapter, MarketType.FUTURES)
+        okx = make_adapter(OKXAdapter, MarketType.FUTURES)
+        depth = {
+            "e": "depthUpdate",
+            "E": OPEN_MS,
+            "s": "BTCUSDT",
+            "U": 157,
+            "u": 160,
+            "pu": 149,
+            "b": [["0.0024", "10"]],
+            "a": [["0.0026", "0"]],
+        }
+        books = {
+            "arg": {"channel": "books", "instId": "BTC-USDT-SWAP"},
+            "action": "update",
+            "data": [
+                {
+                    "bids": [["100.1", "5", "0", "2"]],
+                    "asks": [],
+                    "ts": str(OPEN_MS),
+                    "checksum": -855196043,
+                    "seqId": 124,
+                    "prevSeqId": 123,
+                }
+            ],
+        }
+
+        [diff] = binance.parse_book_message(json.dumps(depth))
+        [update] = okx.parse_book_message(json.dumps(books))
+
+        assert diff.bids == (("0.0024", "10"),)
+        assert (diff.first_id, diff.last_id, diff.prev_id) == (157, 160, 149)
+        assert not diff.is_snapshot
+        assert update.symbol == "BTCUSDT"
+        assert update.bids == (("100.1", "5"),)
+        assert (update.last_id, update.prev_id) == (124, 123)
+        assert update.checksum == -855196043
+        assert okx.parse_book_message(json.dumps(depth)) == []
+
+
+class TestUserStreamParsers:
+    """Tests for the private stream message parsers."""
+
+    def test_binance_execution_report_with_fill(self):
+        """Test that a Binance trade execution yields the order, then its fill."""
+        adapter = make_adapter(BinanceAdapter)
+        event = {
+            "e": "executionReport",
+            "s": "BTCUSDT",
+            "S": "BUY",
+            "o": "LIMIT",
+            "f": "GTC",
+            "q": "1.0",
+            "p": "100.0",
+            "P": "0.0",
+            "x": "TRADE",
+            "X": "PARTIALLY_FILLED",
+            "i": 42,
+            "c": "client-1",
+            "l": "0.4",
+            "z": "0.4",
+            "L": "99.5",
+            "Z": "39.8",
+            "n": "0.04",
+            "N": "USDT",
+            "T": OPEN_MS,
+            "t": 7,
+        }
+
+        order, fill = adapter.parse_user_message(json.dumps(event))
+
+        assert isinstance(order, Order)
+        assert order.id == "42"
+        assert order.status is OrderStatus.PARTIALLY_FILLED
+        assert order.filled_quantity == 0.4
+        assert fill == Fill(
+            order_id="42",
+            symbol="BTCUSDT",
+            side=OrderSide.BUY,
+            quantity=0.4,
+            price=99.5,
+            time=OPEN_TIME,
+            fee=0.04,
+            fee_asset="USDT",
+            trade_id="7",
+        )
+
+    def test_bybit_login_and_wallet(self):
+        """Test that the Bybit login is signed and checked, and wallets become balances."""
+        adapter = make_adapter(BybitAdapter)
+        adapter.api_key = "key"
+        adapter.api_secret = "secret"
+        message = {
+            "topic": "wallet",
+            "creationTime": OPEN_MS,
+            "data": [
+                {"coin": [{"coin": "USDT", "walletBalance": "150", "locked": "50"}]}
+            ],
+        }
+
+        login = adapter.get_user_stream_login()
+        [balance] = adapter.parse_user_message(json.dumps(message))
+
+        assert login["op"] == "auth"
+        assert login["args"][0] == "key"
+        adapter.check_user_stream_login('{"op": "auth", "success": true}')
+        with pytest.raises(AuthenticationError):
+            adapter.check_user_stream_login('{"op": "auth", "success": false}')
+        assert balance == Balance("USDT", 100.0, 50.0, OPEN_TIME)
+        assert balance.total == 150.0
+
+    def test_okx_order_fill_and_position(self):
+        """Test that OKX orders with a fill and positions are normalized."""
+        adapter = make_adapter(OKXAdapter, MarketType.FUTURES)
+        order_message = {
+            "arg": {"channel": "orders", "instType": "ANY"},
+            "data": [
+                {
+                    "instId": "BTC-USDT-SWAP",
+                    "ordId": "9",
+                    "clOrdId": "",
+                    "side": "sell",
+                    "ordType": "market",
+                    "sz": "2",
+                    "px": "",
+                    "state": "filled",
+                    "accFillSz": "2",
+                    "avgPx": "101",
+                    "fillSz": "2",
+                    "fillPx": "101",
+                    "fillFee": "-0.1",
+                    "fillFeeCcy": "USDT",
+                    "fillPnl": "3",
+                    "fillTime": str(OPEN_MS),
+                    "tradeId": "5",
+                    "uTime": str(OPEN_MS),
+                }
+            ],
+        }
+        position_message = {
+            "arg": {"channel": "positions", "instType": "ANY"},
+            "data": [
+                {
+                    "instId": "BTC-USDT-SWAP",
+                    "posSide": "net",
+                    "pos": "-2",
+                    "avgPx": "101",
+                    "lever": "5",
+                    "mgnMode": "cross",
+                    "uTime": str(OPEN_MS),
+                }
+            ],
+        }
+
+        order, fill = adapter.parse_user_message(json.dumps(order_message))
+        [position] = adapter.parse_user_message(json.dumps(position_message))
+
+        assert order.symbol == "BTCUSDT"
+        assert order.status is OrderStatus.FILLED
+        assert (fill.order_id, fill.quantity, fill.fee, fill.realized_pnl) == (
+            "9",
+            2.0,
+            0.1,
+            3.0,
+        )
+        assert position.side is PositionSide.SHORT
+        assert (position.size, position.leverage) == (2.0, 5)
+        assert adapter.parse_user_message('{"event": "subscribe"}') == []
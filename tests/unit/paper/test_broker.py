@@ -0,0 +1,103 @@
+"""Unit tests for the paper trading broker."""
+
+from datetime import UTC, datetime, timedelta
+from types import SimpleNamespace
+
+import pytest
+
+from metaexpert.backtest.fill_model import FeeSchedule, FillModel
+from metaexpert.core import (
+    ContractType,
+    MarginMode,
+    OrderStatus,
+    PositionMode,
+    PositionSide,
+)
+from metaexpert.paper import PaperBroker
+
+
+@pytest.fixture
+def broker():
+    """Create a paper broker on top of a fake exchange."""
+    exchange = SimpleNamespace(
+        fee=0.001,
+        position_mode=PositionMode.ONEWAY,
+        margin_mode=MarginMode.ISOLATED,
+        contract_type=ContractType.LINEAR,
+        instruments=None,
+    )
+    return PaperBroker(exchange, 1000.0, slippage_pct=0.1)
+
+
+class TestPaperBroker:
+    """Tests for the paper trading broker."""
+
+    def test_market_order_fills_at_last_price_with_slippage(self, broker):
+        """Test that a market order fills immediately at the last price plus slippage."""
+        broker.process_price("BTCUSDT", 100.0)
+
+        order = broker.place_order("BTCUSDT", "buy", "market", 1.0)
+
+        assert order.status is OrderStatus.FILLED
+        assert order.average_price == pytest.approx(100.1)
+        assert broker.balance == pytest.approx(1000.0 - 0.1001)
+        assert broker.get_position("BTCUSDT").side is PositionSide.LONG
+
+    def test_market_order_waits_for_first_price(self, broker):
+        """Test that a market order placed before any price fills on the next update."""
+        order = broker.place_order("BTCUSDT", "sell", "market", 1.0)
+        assert order.is_open
+
+        broker.process_price("BTCUSDT", 200.0)
+
+        assert order.average_price == pytest.approx(199.8)
+
+    def test_limit_order_fills_when_price_crosses(self, broker):
+        """Test that a limit order rests until the live price crosses it, without slippage."""
+        broker.process_price("BTCUSDT", 100.0)
+        order = broker.place_order("BTCUSDT", "buy", "limit", 1.0, price=95.0)
+
+        broker.process_price("BTCUSDT", 96.0)
+        assert order.is_open
+
+        broker.process_price("BTCUSDT", 94.0)
+        assert order.status is OrderStatus.FILLED
+        assert order.average_price == 94.0
+
+    def test_fills_are_stamped_with_the_update_time(self, broker):
+        """Test that the fills of a price update happen at its time, not a minute later."""
+        time = datetime(2024, 1, 1, tzinfo=UTC)
+        order = broker.place_order("BTCUSDT", "buy", "market", 1.0)
+
+        broker.process_price("BTCUSDT", 100.0, time)
+
+        assert broker.trades[0]["time"] == time
+        assert order.updated_at == time
+        broker.process_price("BTCUSDT", 100.0, time + timedelta(seconds=1))
+        assert broker.now == time + timedelta(seconds=1)
+
+    @pytest.mark.parametrize(
+        ("limit_price", "fee"),
+        [
+            (95.0, 0.0945),  # Below the last price, resting: maker
+            (105.0, 0.945),  # Above the last price, marketable: taker
+        ],
+    )
+    def test_resting_limit_order_pays_the_maker_fee(self, limit_price, fee):
+        """Test that a limit order pays the maker fee only if it rested on the book."""
+        exchange = SimpleNamespace(
+            fee=0.001,
+            position_mode=PositionMode.ONEWAY,
+            margin_mode=MarginMode.ISOLATED,
+            contract_type=ContractType.LINEAR,
+            instruments=None,
+        )
+        broker = PaperBroker(
+            exchange, 1000.0, fill_model=FillModel(fees=FeeSchedule(0.001, 0.01))
+        )
+        broker.process_price("BTCUSDT", 100.0)
+        broker.place_order("BTCUSDT", "buy", "limit", 1.0, price=limit_price)
+
+        broker.process_price("BTCUSDT", 94.5)
+
+        assert broker.trades[0]["fee"] == pytest.approx(fee)
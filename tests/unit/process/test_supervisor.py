@@ -0,0 +1,139 @@
+"""Unit tests for the expert process supervisor."""
+
+import os
+import sys
+import textwrap
+import time
+from datetime import UTC, datetime
+
+import pytest
+
+from metaexpert.config import (
+    PROCESS_STATUS_DEAD,
+    PROCESS_STATUS_RUNNING,
+    PROCESS_STATUS_STOPPED,
+)
+from metaexpert.core import ProcessError
+from metaexpert.process import ExpertState, StateStore, Supervisor
+from metaexpert.process.state import get_process_start_time
+
+# Minimal expert: records its state, then waits for SIGTERM like MetaExpert.run()
+EXPERT_SCRIPT = """
+import signal
+import threading
+
+from metaexpert.process import ProcessMonitor
+
+monitor = ProcessMonitor()
+stopped = threading.Event()
+signal.signal(signal.SIGTERM, lambda signum, frame: stopped.set())
+monitor.update(status="running", trade_mode="paper", symbol="BTCUSDT")
+stopped.wait(30)
+monitor.update(status="stopped", stop_reason=monitor.get_stop_reason())
+"""
+
+
+@pytest.fixture
+def supervisor(tmp_path):
+    """Create a supervisor storing its files in a temporary directory."""
+    return Supervisor(StateStore(tmp_path / "experts"))
+
+
+@pytest.fixture
+def script(tmp_path):
+    """Create an expert script in a project directory."""
+    project = tmp_path / "my-bot"
+    project.mkdir()
+    path = project / "main.py"
+    path.write_text(textwrap.dedent(EXPERT_SCRIPT), encoding="utf-8")
+    return path
+
+
+def wait_for_status(supervisor, name, status, timeout=10.0):
+    """Poll the state of an expert until it reaches a status."""
+    deadline = time.monotonic() + timeout
+    state = supervisor.get_state(name)
+    while state.status != status and time.monotonic() < deadline:
+        time.sleep(0.05)
+        state = supervisor.get_state(name)
+    return state
+
+
+@pytest.mark.skipif(sys.platform == "win32", reason="POSIX signals")
+class TestSupervisor:
+    """Tests for the expert process supervisor."""
+
+    def test_start_and_stop_with_reason(self, supervisor, script):
+        """Test that a detached expert reports its state and receives the stop reason."""
+        env = dict(os.environ, PYTHONPATH=os.pathsep.join(sys.path))
+        started = supervisor.start(script, env)
+        assert started.name == "my-bot"
+
+        state = wait_for_status(supervisor, "my-bot", PROCESS_STATUS_RUNNING)
+        assert state.pid == started.pid
+        assert state.trade_mode == "paper"
+        assert state.symbol == "BTCUSDT"
+        assert state.heartbeat is not None
+        assert state.uptime is not None
+        assert [item.name for item in supervisor.list()] == ["my-bot"]
+
+        with pytest.raises(ProcessError):
+            supervisor.start(script, env)
+
+        stopped = supervisor.stop("my-bot", "maintenance", timeout=10.0)
+        assert stopped.status == PROCESS_STATUS_STOPPED
+        assert stopped.stop_reason == "maintenance"
+        assert stopped.uptime is None
+
+        supervisor.remove("my-bot")
+        assert supervisor.list() == []
+
+    def test_exited_process_is_reported_dead(self, supervisor):
+        """Test that an expert that exited without shutting down is reported dead."""
+        supervisor.store.save(
+            ExpertState(
+                name="ghost",
+                pid=2**22 + 1,
+                script="main.py",
+                status=PROCESS_STATUS_RUNNING,
+                started_at=datetime.now(UTC),
+            )
+        )
+
+        assert supervisor.get_state("ghost").status == PROCESS_STATUS_DEAD
+        with pytest.raises(ProcessError):
+            supervisor.stop("ghost")
+
+    def test_reused_pid_is_reported_dead(self, supervisor):
+        """Test that a running pid started after the expert is not taken for it."""
+        for name, process_start in (
+            ("current", get_process_start_time(os.getpid())),
+            ("reused", "0"),
+        ):
+            supervisor.store.save(
+                ExpertState(
+                    name=name,
+                    pid=os.getpid(),
+                    script="main.py",
+                    process_start=process_start,
+                    status=PROCESS_STATUS_RUNNING,
+                )
+            )
+
+        assert supervisor.get_state("current").status == PROCESS_STATUS_RUNNING
+        assert supervisor.get_state("reused").status == PROCESS_STATUS_DEAD
+        with pytest.raises(ProcessError):
+            supervisor.stop("reused")
+
+    def test_state_round_trip(self, tmp_path):
+        """Test that a state file is read back identically."""
+        state = ExpertState(
+            name="bot",
+            pid=123,
+            script="main.py",
+            started_at=datetime(2024, 1, 1, tzinfo=UTC),
+            exchange="binance",
+        )
+        state.save(tmp_path / "bot.json")
+
+        assert ExpertState.load(tmp_path / "bot.json") == state
@@ -0,0 +1,90 @@
+"""Unit tests for the entry filters."""
+
+from datetime import UTC, datetime
+
+from metaexpert.backtest import SimulatedBroker
+from metaexpert.core import OrderBook, OrderSide
+from metaexpert.risk import (
+    EntryContext,
+    EntryFilters,
+    allowed_days_filter,
+    max_spread_filter,
+    min_volume_filter,
+    trade_hours_filter,
+)
+
+# Saturday
+TIME = datetime(2024, 1, 6, 22, 30, tzinfo=UTC)
+
+
+def make_context(**kwargs) -> EntryContext:
+    """Create a BTCUSDT buy entry on Saturday at 22:30 UTC."""
+    return EntryContext("BTCUSDT", OrderSide.BUY, 1.0, TIME, **kwargs)
+
+
+class TestEntryFilters:
+    """Tests for the entry filter pipeline."""
+
+    def test_hours_and_days_are_utc(self):
+        """Test that trade hours and allowed days block entries outside them."""
+        assert trade_hours_filter({22, 23})(make_context()) is None
+        assert "trade hours" in trade_hours_filter({8, 9})(make_context())
+        assert allowed_days_filter({6, 7})(make_context()) is None
+        assert "day 6" in allowed_days_filter({1, 2, 3, 4, 5})(make_context())
+
+    def test_spread_and_volume_pass_when_unknown(self):
+        """Test that market data filters block on wide spreads and low volume only."""
+        book = OrderBook("BTCUSDT", ((99.0, 1.0),), ((101.0, 1.0),), TIME)
+        volumes: list[float] = []
+
+        def load_volume() -> float:
+            volumes.append(5e5)
+            return 5e5
+
+        context = make_context(order_book=lambda: book, volume_24h=load_volume)
+
+        assert max_spread_filter(0.1)(make_context()) is None
+        assert "spread 2.0000%" in max_spread_filter(0.1)(context)
+        assert max_spread_filter(5.0)(context) is None
+        assert min_volume_filter(1e6)(make_context()) is None
+        assert "below" in min_volume_filter(1e6)(context)
+        assert min_volume_filter(1e5)(context) is None
+        assert len(volumes) == 1
+
+    def test_first_blocking_filter_wins_and_names_replace(self):
+        """Test that filters run in order and a name registers one filter only."""
+        filters = EntryFilters()
+        calls: list[str] = []
+
+        def first(context: EntryContext) -> str | None:
+            calls.append("first")
+            return "blocked"
+
+        def second(context: EntryContext) -> str | None:
+            calls.append("second")
+            return None
+
+        filters.add(second)
+        filters.add(first)
+        filters.add(lambda context: None, "first")
+
+        assert filters.check(make_context()) is None
+        assert "first" in filters and len(filters) == 2
+        filters.add(first, "third")
+        assert filters.check(make_context()) == "blocked"
+        assert calls == ["second", "second", "first"]
+
+    def test_blocked_entry_places_no_order(self):
+        """Test that the broker places no entry order when the check blocks it."""
+        broker = SimulatedBroker(1000.0)
+        checked: list[tuple] = []
+
+        def check(symbol: str, side: OrderSide, quantity: float) -> str | None:
+            checked.append((symbol, side, quantity))
+            return "closed market"
+
+        broker.entry_check = check
+
+        assert broker.open_position("BTCUSDT", "short", 2.0) is None
+        assert checked == [("BTCUSDT", OrderSide.SELL, 2.0)]
+        assert broker.get_open_orders("BTCUSDT") == []
@@ -0,0 +1,62 @@
+"""Unit tests for the risk manager."""
+
+from datetime import UTC, datetime, timedelta
+
+from metaexpert.core import RiskLimitError
+from metaexpert.risk import RiskManager
+
+MORNING = datetime(2024, 1, 2, 9, tzinfo=UTC)
+
+
+class TestRiskManager:
+    """Tests for RiskManager."""
+
+    def test_drawdown_is_measured_from_the_peak(self):
+        """Test that the drawdown breach is reported once and kept until reset."""
+        manager = RiskManager(max_drawdown_pct=0.2)
+
+        assert manager.update(1000.0, MORNING) is None
+        assert manager.update(1500.0, MORNING) is None
+        assert manager.update(1250.0, MORNING) is None
+        breach = manager.update(1200.0, MORNING)
+
+        assert isinstance(breach, RiskLimitError)
+        assert breach.limit == "max_drawdown_pct"
+        assert manager.update(1100.0, MORNING + timedelta(days=1)) is None
+        assert manager.breach is not None
+
+        manager.reset()
+        assert manager.breach is None
+        assert manager.update(1100.0, MORNING + timedelta(days=1)) is None
+
+    def test_daily_loss_resets_on_the_next_day(self):
+        """Test that the daily loss counts realized and unrealized losses of the UTC day."""
+        manager = RiskManager(daily_loss_limit=100.0)
+
+        manager.update(1000.0, MORNING)
+        breach = manager.update(900.0, MORNING + timedelta(hours=5))
+
+        assert breach is not None and breach.limit == "daily_loss_limit"
+        assert manager.breach is not None
+        assert manager.update(880.0, MORNING + timedelta(days=1)) is None
+        assert manager.breach is None
+        assert manager.state is not None and manager.state.day_start_equity == 880.0
+
+    def test_state_survives_a_restart(self, tmp_path):
+        """Test that a restart the same day keeps the daily loss counter."""
+        state_file = tmp_path / "risk.json"
+        RiskManager(daily_loss_limit=100.0, state_file=state_file).update(
+            1000.0, MORNING
+        )
+        first = RiskManager(daily_loss_limit=100.0, state_file=state_file)
+        first.update(950.0, MORNING + timedelta(hours=1))
+
+        restarted = RiskManager(daily_loss_limit=100.0, state_file=state_file)
+        breach = restarted.update(890.0, MORNING + timedelta(hours=2))
+
+        assert breach is not None
+        assert restarted.state is not None
+        assert restarted.state.day_start_equity == 1000.0
+        assert RiskManager(state_file=state_file).update(
+            890.0, MORNING + timedelta(hours=3)
+        ) is None
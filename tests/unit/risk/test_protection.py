@@ -0,0 +1,147 @@
+"""Unit tests for the protective orders of the positions."""
+
+import pytest
+
+from metaexpert.backtest import SimulatedBroker
+from metaexpert.core import OrderStatus, OrderType
+from metaexpert.risk import ProtectionManager
+
+
+class RejectingBroker(SimulatedBroker):
+    """Simulated broker rejecting the protective orders while `is_rejecting`."""
+
+    is_rejecting: bool = False
+
+    def place_order(self, symbol, side, order_type, quantity, **kwargs):
+        if self.is_rejecting and order_type in (OrderType.STOP, OrderType.TAKE_PROFIT):
+            raise RuntimeError("Protective order rejected")
+        return super().place_order(symbol, side, order_type, quantity, **kwargs)
+
+
+def open_long(make_candle, broker: SimulatedBroker, manager: ProtectionManager) -> None:
+    """Buy one BTCUSDT at 100 and let the manager protect the position."""
+    broker.process_candle(make_candle(0, 100, 100, 100, 100))
+    broker.open_position("BTCUSDT", "long", 1.0)
+    broker.process_candle(make_candle(1, 100, 101, 99, 100))
+    manager.update("BTCUSDT", 100.0)
+
+
+class TestProtectionManager:
+    """Tests for ProtectionManager."""
+
+    def test_native_orders_are_attached_and_released(self, make_candle):
+        """Test that the take-profit closes the position and cancels the stop-loss."""
+        broker = SimulatedBroker(1000.0)
+        manager = ProtectionManager(broker, stop_loss_pct=2.0, take_profit_pct=4.0)
+        open_long(make_candle, broker, manager)
+
+        protection = manager.protections[("BTCUSDT", broker.get_positions()[0].side)]
+        assert protection.stop_order_id is not None
+        assert protection.take_profit_order_id is not None
+        stop = broker.get_order("BTCUSDT", protection.stop_order_id)
+        take_profit = broker.get_order("BTCUSDT", protection.take_profit_order_id)
+        assert stop.type is OrderType.STOP and stop.reduce_only
+        assert stop.stop_price == pytest.approx(98.0)
+        assert take_profit.stop_price == pytest.approx(104.0)
+
+        broker.process_candle(make_candle(2, 101, 105, 100, 104))
+        manager.update("BTCUSDT", 104.0)
+
+        assert take_profit.status is OrderStatus.FILLED
+        assert not broker.get_positions()
+        assert not broker.get_open_orders("BTCUSDT")
+        assert not manager.protections
+
+    def test_trailing_stop_and_breakeven(self, make_candle):
+        """Test that the stop moves to the entry, then trails the best price only upwards."""
+        broker = SimulatedBroker(1000.0)
+        manager = ProtectionManager(
+            broker,
+            stop_loss_pct=2.0,
+            trailing_stop_pct=1.0,
+            trailing_activation_pct=3.0,
+            breakeven_pct=1.5,
+        )
+        open_long(make_candle, broker, manager)
+        protection = next(iter(manager.protections.values()))
+
+        manager.update("BTCUSDT", 102.0)
+        assert protection.is_breakeven and not protection.is_trailing
+        assert protection.stop_price == pytest.approx(100.0)
+
+        manager.update("BTCUSDT", 105.0)
+        assert protection.is_trailing
+        assert protection.stop_price == pytest.approx(103.95)
+
+        manager.update("BTCUSDT", 104.5)
+        assert protection.stop_price == pytest.approx(103.95)
+        assert protection.stop_order_id is not None
+        stop = broker.get_order("BTCUSDT", protection.stop_order_id)
+        assert stop.stop_price == pytest.approx(103.95)
+
+    def test_trade_sets_the_levels(self, make_candle):
+        """Test that the distances given to `trade()` protect the next positions."""
+        broker = SimulatedBroker(1000.0)
+        manager = ProtectionManager(broker, stop_loss_pct=2.0)
+        broker.protection_update = manager.set_levels
+
+        broker.trade(take_profit=5.0)
+        open_long(make_candle, broker, manager)
+
+        protection = next(iter(manager.protections.values()))
+        assert protection.stop_price == pytest.approx(98.0)
+        assert protection.take_profit_price == pytest.approx(105.0)
+
+    def test_simulated_stop_closes_the_position(self, make_candle):
+        """Test that without native orders the position is closed at market on a stop hit."""
+        broker = SimulatedBroker(1000.0)
+        broker.supports_protective_orders = False
+        manager = ProtectionManager(broker, stop_loss_pct=2.0)
+        open_long(make_candle, broker, manager)
+        assert not broker.get_open_orders("BTCUSDT")
+
+        manager.update("BTCUSDT", 99.0)
+        assert not broker.get_open_orders("BTCUSDT")
+
+        manager.update("BTCUSDT", 97.5)
+        manager.update("BTCUSDT", 97.0)
+        closing = broker.get_open_orders("BTCUSDT")
+        assert len(closing) == 1 and closing[0].reduce_only
+
+        broker.process_candle(make_candle(2, 97, 98, 96, 97))
+        manager.update("BTCUSDT", 97.0)
+        assert not broker.get_positions()
+        assert not manager.protections
+
+    def test_rejected_native_orders_are_watched_locally(self, make_candle):
+        """Test that a level without a native order closes the position at market."""
+        broker = RejectingBroker(1000.0)
+        broker.is_rejecting = True
+        manager = ProtectionManager(broker, stop_loss_pct=2.0, take_profit_pct=4.0)
+        open_long(make_candle, broker, manager)
+        protection = next(iter(manager.protections.values()))
+        assert protection.stop_order_id is None
+        assert protection.take_profit_order_id is None
+        assert not broker.get_open_orders("BTCUSDT")
+
+        manager.update("BTCUSDT", 97.5)
+        closing = broker.get_open_orders("BTCUSDT")
+        assert len(closing) == 1
+        assert closing[0].type is OrderType.MARKET and closing[0].reduce_only
+
+    def test_rejected_native_orders_are_retried(self, make_candle):
+        """Test that the rejected protective orders are placed on a later update."""
+        broker = RejectingBroker(1000.0)
+        broker.is_rejecting = True
+        manager = ProtectionManager(broker, stop_loss_pct=2.0, take_profit_pct=4.0)
+        open_long(make_candle, broker, manager)
+        protection = next(iter(manager.protections.values()))
+
+        broker.is_rejecting = False
+        manager.update("BTCUSDT", 100.5)
+
+        assert protection.stop_order_id is not None
+        assert protection.take_profit_order_id is not None
+        stop = broker.get_order("BTCUSDT", protection.stop_order_id)
+        assert stop.stop_price == pytest.approx(98.0)
+        assert len(broker.get_open_orders("BTCUSDT")) == 2
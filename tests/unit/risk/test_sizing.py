@@ -0,0 +1,77 @@
+"""Unit tests for the position sizer."""
+
+import pytest
+
+from metaexpert.core import ContractType, SizeType
+from metaexpert.risk import PositionSizer
+
+
+class TestPositionSizer:
+    """Tests for PositionSizer."""
+
+    def test_size_types(self):
+        """Test that each size type computes the expected quantity."""
+        assert PositionSizer(SizeType.FIXED_BASE, 0.5).get_quantity(
+            10000.0, 100.0
+        ) == pytest.approx(0.5)
+        assert PositionSizer(SizeType.FIXED_QUOTE, 500.0).get_quantity(
+            10000.0, 100.0
+        ) == pytest.approx(5.0)
+        assert PositionSizer(SizeType.PERCENT_EQUITY, 10.0, leverage=3).get_quantity(
+            10000.0, 100.0
+        ) == pytest.approx(30.0)
+        # 1% of 10000 risked over a 2% stop: 100 / 2
+        sizer = PositionSizer(SizeType.RISK_BASED, 1.0, stop_loss_pct=2.0)
+        assert sizer.get_quantity(10000.0, 100.0) == pytest.approx(50.0)
+        assert sizer.get_quantity(
+            10000.0, 100.0, stop_price=95.0
+        ) == pytest.approx(20.0)
+
+    def test_risk_based_requires_a_stop(self):
+        """Test that risk-based sizing without a stop distance is rejected."""
+        with pytest.raises(ValueError):
+            PositionSizer(SizeType.RISK_BASED, 1.0).get_quantity(10000.0, 100.0)
+
+    def test_notional_is_capped(self):
+        """Test that the notional is capped by the buying power and the maximum size."""
+        assert PositionSizer(SizeType.FIXED_BASE, 500.0).get_quantity(
+            10000.0, 100.0
+        ) == pytest.approx(100.0)
+        assert PositionSizer(
+            SizeType.FIXED_BASE, 500.0, max_position_size_quote=2000.0
+        ).get_quantity(10000.0, 100.0) == pytest.approx(20.0)
+
+    def test_lot_step_and_minimums(self):
+        """Test that quantities are rounded down to the lot step and zeroed below the minimums."""
+        sizer = PositionSizer(SizeType.FIXED_QUOTE, 100.0)
+        assert sizer.get_quantity(10000.0, 30.0, lot_step=0.01) == pytest.approx(3.33)
+        assert sizer.get_quantity(10000.0, 30.0, lot_step=5.0) == 0.0
+        assert sizer.get_quantity(10000.0, 30.0, min_notional=150.0) == 0.0
+        assert sizer.get_quantity(10000.0, 30.0, min_quantity=4.0) == 0.0
+
+    def test_quantity_is_in_contracts(self):
+        """Test that the quantity counts contracts of the contract size, linear or inverse."""
+        sizer = PositionSizer(SizeType.PERCENT_EQUITY, 10.0, leverage=2)
+        # 2000 USDT of 0.01 BTC contracts at 100
+        assert sizer.get_quantity(
+            10000.0, 100.0, contract_size=0.01
+        ) == pytest.approx(2000.0)
+        # 1 BTC of equity is worth 100 USD: 20 USD of 10 USD contracts
+        assert sizer.get_quantity(
+            1.0, 100.0, contract_size=10.0, contract_type=ContractType.INVERSE
+        ) == pytest.approx(2.0)
+        risk_sizer = PositionSizer(SizeType.RISK_BASED, 1.0, stop_loss_pct=2.0)
+        assert risk_sizer.get_quantity(
+            1.0, 100.0, contract_size=10.0, contract_type=ContractType.INVERSE
+        ) == pytest.approx(5.0)
+
+    def test_min_notional_of_contracts(self):
+        """Test that the minimum notional is checked on the value of the contracts."""
+        sizer = PositionSizer(SizeType.FIXED_QUOTE, 100.0)
+
+        assert sizer.get_quantity(
+            10000.0, 100.0, contract_size=0.1, min_notional=100.0
+        ) == pytest.approx(10.0)
+        assert sizer.get_quantity(
+            10000.0, 100.0, contract_size=0.1, min_notional=150.0
+        ) == 0.0
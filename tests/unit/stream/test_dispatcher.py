@@ -0,0 +1,281 @@
+"""Unit tests for the market data stream dispatcher."""
+
+import asyncio
+from dataclasses import replace
+from datetime import UTC, datetime
+from types import SimpleNamespace
+
+from metaexpert.core import (
+    Balance,
+    Candle,
+    ContractType,
+    EventType,
+    Fill,
+    MarginMode,
+    Order,
+    OrderBookUpdate,
+    OrderSide,
+    OrderType,
+    PositionMode,
+    Tick,
+    Timeframe,
+)
+from metaexpert.paper import PaperBroker
+from metaexpert.stream import BookStream, MarketStream, StreamDispatcher
+
+START = datetime(2024, 1, 1, tzinfo=UTC)
+
+
+def make_candle(
+    close: float, is_closed: bool, timeframe: Timeframe = Timeframe.H1
+) -> Candle:
+    """Create a BTCUSDT candle, one-hour by default."""
+    return Candle(
+        symbol="BTCUSDT",
+        timeframe=timeframe,
+        open_time=START,
+        open=100.0,
+        high=max(100.0, close),
+        low=min(100.0, close),
+        close=close,
+        is_closed=is_closed,
+    )
+
+
+class Recorder:
+    """Register tick and bar handlers recording their rates."""
+
+    def __init__(self) -> None:
+        self.ticks: list[dict] = []
+        self.bars: list[dict] = []
+
+    def __enter__(self) -> "Recorder":
+        EventType.ON_TICK.value["callback"].append(self.ticks.append)
+        EventType.ON_BAR.value["callback"].append(self.bars.append)
+        return self
+
+    def __exit__(self, *args: object) -> None:
+        EventType.ON_TICK.value["callback"].remove(self.ticks.append)
+        EventType.ON_BAR.value["callback"].remove(self.bars.append)
+
+
+class TestStreamDispatcher:
+    """Tests for the stream dispatcher."""
+
+    def test_bar_is_dispatched_once_on_close(self):
+        """Test that every update reaches on_tick and only the close reaches on_bar."""
+        dispatcher = StreamDispatcher(SimpleNamespace())
+
+        with Recorder() as recorder:
+            dispatcher.dispatch(make_candle(101.0, is_closed=False))
+            dispatcher.dispatch(make_candle(102.0, is_closed=True))
+            dispatcher.dispatch(make_candle(102.0, is_closed=True))
+
+        assert [rates["close"] for rates in recorder.ticks] == [101.0, 102.0, 102.0]
+        assert [rates["close"] for rates in recorder.bars] == [102.0]
+        assert recorder.bars[0]["timeframe"] == "1h"
+
+    def test_secondary_timeframe_only_reaches_on_bar(self):
+        """Test that the candles of a secondary timeframe only call on_bar on close."""
+        dispatcher = StreamDispatcher(SimpleNamespace(), Timeframe.H1)
+
+        with Recorder() as recorder:
+            dispatcher.dispatch(make_candle(101.0, False, Timeframe.H4))
+            dispatcher.dispatch(make_candle(102.0, True, Timeframe.H4))
+            dispatcher.dispatch(make_candle(103.0, False))
+
+        assert [rates["close"] for rates in recorder.ticks] == [103.0]
+        assert [rates["timeframe"] for rates in recorder.bars] == ["4h"]
+
+    def test_missed_bar_is_fetched_once(self):
+        """Test that a closed candle missed by the stream is fetched from the history."""
+        requests: list = []
+
+        def load(symbol, timeframe, start, end):
+            requests.append((symbol, start, end))
+            return [make_candle(104.0, is_closed=True)]
+
+        dispatcher = StreamDispatcher(
+            SimpleNamespace(), Timeframe.H1, SimpleNamespace(load=load)
+        )
+
+        with Recorder() as recorder:
+            dispatcher.check_bars(["BTCUSDT"], Timeframe.H1, START)
+            dispatcher.check_bars(["BTCUSDT"], Timeframe.H1, START)
+            dispatcher.dispatch(make_candle(104.0, is_closed=True))
+
+        assert requests == [("BTCUSDT", START, START + Timeframe.H1.get_delta())]
+        assert [rates["close"] for rates in recorder.bars] == [104.0]
+
+    def test_failed_fetch_is_reported_and_retried(self):
+        """Test that a history failure is reported and the bar fetched on the next close."""
+        errors: list[Exception] = []
+        responses: list = [RuntimeError("klines unavailable")]
+
+        def load(symbol, timeframe, start, end):
+            response = responses.pop(0) if responses else []
+            if isinstance(response, Exception):
+                raise response
+            return [make_candle(104.0, is_closed=True)]
+
+        dispatcher = StreamDispatcher(
+            SimpleNamespace(), Timeframe.H1, SimpleNamespace(load=load)
+        )
+
+        EventType.ON_ERROR.value["callback"].append(errors.append)
+        try:
+            with Recorder() as recorder:
+                dispatcher.check_bars(["BTCUSDT"], Timeframe.H1, START)
+                assert recorder.bars == []
+                dispatcher.check_bars(["BTCUSDT"], Timeframe.H1, START)
+        finally:
+            EventType.ON_ERROR.value["callback"].remove(errors.append)
+
+        assert [str(error) for error in errors] == ["klines unavailable"]
+        assert [rates["close"] for rates in recorder.bars] == [104.0]
+
+    def test_paper_broker_is_fed_before_handlers(self):
+        """Test that the paper broker receives the prices of the stream."""
+        exchange = SimpleNamespace(
+            fee=0.0,
+            position_mode=PositionMode.ONEWAY,
+            margin_mode=MarginMode.ISOLATED,
+            contract_type=ContractType.LINEAR,
+            instruments=None,
+        )
+        broker = PaperBroker(exchange, 1000.0)
+        order = broker.place_order("BTCUSDT", "buy", "market", 1.0)
+        dispatcher = StreamDispatcher(broker)
+
+        with Recorder() as recorder:
+            dispatcher.dispatch(
+                Tick(symbol="BTCUSDT", time=START, price=105.0, volume=0.5)
+            )
+
+        assert not order.is_open
+        assert order.average_price == 105.0
+        assert recorder.ticks[0]["price"] == 105.0
+        assert recorder.bars == []
+
+    def test_market_stream_skips_unexpected_messages(self):
+        """Test that the stream keeps its subscriptions and ignores unparsable messages."""
+        updates: list = []
+
+        def parse(message):
+            if message == "bad":
+                raise KeyError("k")
+            return [make_candle(101.0, is_closed=False)]
+
+        stream = MarketStream(
+            "wss://example", parse, updates.append, subscriptions=[{"op": "sub"}]
+        )
+
+        asyncio.run(stream.on_message("bad"))
+        asyncio.run(stream.on_message("good"))
+
+        assert stream.subscriptions == [{"op": "sub"}]
+        assert len(updates) == 1
+
+    def test_gap_in_stream_is_backfilled(self):
+        """Test that the bars missing between two closed candles are fetched in order."""
+        delta = Timeframe.H1.get_delta()
+
+        def load(symbol, timeframe, start, end):
+            return [
+                replace(make_candle(110.0 + i, True), open_time=START + i * delta)
+                for i in range(1, 3)
+                if start <= START + i * delta < end
+            ]
+
+        dispatcher = StreamDispatcher(
+            SimpleNamespace(), Timeframe.H1, SimpleNamespace(load=load)
+        )
+
+        with Recorder() as recorder:
+            dispatcher.dispatch(make_candle(110.0, is_closed=True))
+            dispatcher.dispatch(
+                replace(make_candle(113.0, True), open_time=START + 3 * delta)
+            )
+
+        closes = [rates["close"] for rates in recorder.bars]
+        assert closes == [110.0, 111.0, 112.0, 113.0]
+
+    def test_user_updates_reach_their_handlers(self):
+        """Test that orders, fills and balances call their handlers, fills with their order."""
+        dispatcher = StreamDispatcher(SimpleNamespace())
+        order = Order(
+            symbol="BTCUSDT",
+            side=OrderSide.BUY,
+            type=OrderType.MARKET,
+            quantity=1.0,
+            id="42",
+        )
+        fill = Fill("42", "BTCUSDT", OrderSide.BUY, 1.0, 100.0, START, fee=0.1)
+        orders: list[dict] = []
+        transactions: list[tuple[dict, dict]] = []
+        accounts: list[dict] = []
+
+        def on_transaction(request: dict, result: dict) -> None:
+            transactions.append((request, result))
+
+        handlers = [
+            (EventType.ON_ORDER, orders.append),
+            (EventType.ON_TRANSACTION, on_transaction),
+            (EventType.ON_ACCOUNT, accounts.append),
+        ]
+        for event, handler in handlers:
+            event.value["callback"].append(handler)
+        try:
+            dispatcher.dispatch_user(order)
+            dispatcher.dispatch_user(fill)
+            dispatcher.dispatch_user(Balance("USDT", 900.0, 100.0, START))
+        finally:
+            for event, handler in handlers:
+                event.value["callback"].remove(handler)
+
+        assert [item["id"] for item in orders] == ["42"]
+        [(request, result)] = transactions
+        assert request == orders[0]
+        assert result["order_id"] == "42"
+        assert (result["price"], result["fee"]) == (100.0, 0.1)
+        assert accounts[0]["total"] == 1000.0
+
+    def test_book_stream_fetches_a_new_snapshot_after_a_gap(self):
+        """Test that diffs wait for a REST snapshot, fetched again after a gap."""
+
+        def diff(first_id: int, last_id: int, bid: str) -> OrderBookUpdate:
+            return OrderBookUpdate(
+                "BTCUSDT",
+                ((bid, "1"),),
+                (("101", "1"),),
+                START,
+                first_id=first_id,
+                last_id=last_id,
+            )
+
+        snapshots = [
+            OrderBookUpdate("BTCUSDT", (), (), START, is_snapshot=True, last_id=10),
+            OrderBookUpdate("BTCUSDT", (), (), START, is_snapshot=True, last_id=20),
+        ]
+        exchange = SimpleNamespace(
+            get_ping_message=lambda: None,
+            parse_book_message=lambda message: [message],
+            get_book_snapshot=lambda symbol: snapshots.pop(0),
+            get_book_subscribe_messages=lambda symbol: [],
+            get_book_unsubscribe_messages=lambda symbol: [],
+            compute_book_checksum=None,
+        )
+        dispatcher = StreamDispatcher(SimpleNamespace())
+        stream = BookStream("wss://book", exchange, dispatcher.dispatch_book)
+        books: list = []
+        EventType.ON_BOOK.value["callback"].append(books.append)
+        try:
+            for update in [diff(9, 11, "99"), diff(15, 16, "98"), diff(20, 21, "97")]:
+                asyncio.run(stream.on_message(update))
+        finally:
+            EventType.ON_BOOK.value["callback"].remove(books.append)
+
+        assert [book.best_bid for book in books] == [99.0, 97.0]
+        assert [book.sequence for book in books] == [11, 21]
+        assert dispatcher.books["BTCUSDT"] is books[-1]
+        assert snapshots == []
@@ -0,0 +1,50 @@
+"""Unit tests for the expert inputs."""
+
+import pytest
+
+from metaexpert import MetaExpert
+
+
+class TestConvertInput:
+    """Tests for the conversion of the inputs to the type of their default."""
+
+    @pytest.mark.parametrize(
+        ("value", "default", "expected"),
+        [
+            (False, True, False),
+            ("false", True, False),
+            (" TRUE ", False, True),
+            (20, 14, 20),
+            (20.0, 14, 20),
+            ("-3", 14, -3),
+            (2, 1.5, 2.0),
+            ("2.5", 1.5, 2.5),
+            ("fast", "slow", "fast"),
+            (5, "slow", "5"),
+            ([1, 2], None, [1, 2]),
+        ],
+    )
+    def test_lossless_values_are_converted(self, value, default, expected):
+        """Test that values representing the type of the default are converted."""
+        converted = MetaExpert._convert_input("period", value, default)
+
+        assert converted == expected
+        assert type(converted) is type(expected)
+
+    @pytest.mark.parametrize(
+        ("value", "default"),
+        [
+            ("no", True),
+            (1, True),
+            (2.5, 14),
+            ("2.5", 14),
+            (True, 14),
+            ("fast", 1.5),
+            (False, 1.5),
+            (True, "slow"),
+        ],
+    )
+    def test_lossy_values_are_rejected(self, value, default):
+        """Test that values that would be truncated or misread are rejected."""
+        with pytest.raises(ValueError):
+            MetaExpert._convert_input("period", value, default)
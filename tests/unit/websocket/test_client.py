@@ -0,0 +1,121 @@
+"""Unit tests for the WebSocket client."""
+
+import asyncio
+import json
+from types import SimpleNamespace
+
+import metaexpert.websocket as websocket_module
+from metaexpert.websocket import WebSocketClient
+
+
+class FakeConnection:
+    """WebSocket connection replaying messages, then closing or hanging."""
+
+    def __init__(self, messages: list[str], hang: bool = False) -> None:
+        self.messages = messages
+        self.hang = hang
+        self.sent: list = []
+        self.options: dict = {}
+
+    async def __aenter__(self) -> "FakeConnection":
+        return self
+
+    async def __aexit__(self, *args: object) -> None:
+        return None
+
+    async def send(self, message: str) -> None:
+        self.sent.append(json.loads(message))
+
+    async def recv(self) -> str:
+        if self.messages:
+            return self.messages.pop(0)
+        if self.hang:
+            await asyncio.sleep(3600)
+        raise ConnectionResetError("closed by peer")
+
+
+class RecordingClient(WebSocketClient):
+    """Client recording its hooks, stopped after a number of connections."""
+
+    def __init__(self, connections: list[FakeConnection], **kwargs) -> None:
+        super().__init__("wss://example", reconnect_delay=0.001, **kwargs)
+        self.connections = connections
+        self.received: list = []
+        self.errors: list[Exception] = []
+        self.reconnects = 0
+
+    async def on_message(self, message):
+        self.received.append(message)
+
+    async def on_reconnect(self):
+        self.reconnects += 1
+
+    async def on_error(self, error):
+        self.errors.append(error)
+        if not self.connections:
+            self.running = False
+
+
+def install(monkeypatch, client: RecordingClient) -> list[FakeConnection]:
+    """Serve the fake connections of a client, return them as they are opened."""
+    opened: list[FakeConnection] = []
+
+    def connect(url, **kwargs):
+        connection = client.connections.pop(0)
+        connection.options = kwargs
+        opened.append(connection)
+        return connection
+
+    monkeypatch.setattr(
+        websocket_module, "websockets", SimpleNamespace(connect=connect)
+    )
+    return opened
+
+
+class TestWebSocketClient:
+    """Tests for the WebSocket client."""
+
+    def test_reconnect_restores_subscriptions(self, monkeypatch):
+        """Test that subscriptions are sent again and on_reconnect runs after a drop."""
+        client = RecordingClient([FakeConnection(["a"]), FakeConnection(["b"])])
+        client.subscriptions = [{"op": "subscribe", "args": ["kline.1.BTCUSDT"]}]
+        opened = install(monkeypatch, client)
+
+        asyncio.run(client.connect())
+
+        assert client.received == ["a", "b"]
+        assert [connection.sent for connection in opened] == [
+            client.subscriptions,
+            client.subscriptions,
+        ]
+        assert client.reconnects == 1
+        assert all(isinstance(error, ConnectionResetError) for error in client.errors)
+
+    def test_ping_interval_of_the_client(self, monkeypatch):
+        """Test that the connection pings at the interval of the client."""
+        client = RecordingClient([FakeConnection(["a"])], ping_interval=5.0)
+        opened = install(monkeypatch, client)
+
+        asyncio.run(client.connect())
+
+        assert opened[0].options["ping_interval"] == 5.0
+
+    def test_stale_connection_is_reopened(self, monkeypatch):
+        """Test that a connection without messages is reported stale and dropped."""
+        client = RecordingClient([FakeConnection(["a"], hang=True)], stale_timeout=0.05)
+        install(monkeypatch, client)
+
+        asyncio.run(client.connect())
+
+        assert client.received == ["a"]
+        assert isinstance(client.errors[0], TimeoutError)
+        assert "No message received" in str(client.errors[0])
+
+    def test_reconnect_delay_backs_off_with_jitter(self):
+        """Test that the reconnection delay doubles with jitter up to the cap."""
+        client = WebSocketClient(
+            "wss://example", reconnect_delay=1.0, max_reconnect_delay=10.0
+        )
+
+        for attempt, delay in enumerate([1.0, 2.0, 4.0, 8.0, 10.0, 10.0]):
+            assert delay / 2 <= client.get_reconnect_delay(attempt) <= delay